@@ -0,0 +1,25 @@
+//! Runtime API definition for the ESG score pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_esg::{EsgBreakdown, ScoreRecord};
+
+sp_api::decl_runtime_apis! {
+	pub trait EsgApi<AccountId, BlockNumber>
+		where
+			AccountId: Codec,
+			BlockNumber: Codec,
+	{
+		/// Returns the past scores of an account, oldest first.
+		fn score_history(account: AccountId) -> Vec<ScoreRecord<AccountId, BlockNumber>>;
+
+		/// Returns the effective score of an account, averaged over its score history.
+		fn effective_score(account: AccountId) -> u16;
+
+		/// Returns the sub-scores behind the latest score of an account, if it has any.
+		fn score_breakdown(account: AccountId) -> Option<EsgBreakdown>;
+	}
+}
@@ -1,46 +1,61 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::{Pallet as Esg, *};
+use crate::{weights::WeightInfo, Pallet as Esg, *};
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
-use frame_support::WeakBoundedVec;
-use frame_system::RawOrigin;
-use scale_info::prelude::vec::Vec;
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{Currency, Get, Hooks},
+	BoundedVec, WeakBoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::{format, string::String, vec, vec::Vec};
+use sp_core::H256;
+use sp_runtime::traits::{Saturating, Zero};
 
-// const MAX_NUM_OF_SUDO_ORACLES: u32 = MaxNumOfSudoOracles::get();
-// const MAX_NUM_OF_NON_SUDO_ORACLES: u32 = Esg::<{T as
-// Esg::Config}>::MaxNumOfNonSudoOracles::get();
-const MAX_NUM_OF_SUDO_ORACLES: u32 = 5;
-const MAX_NUM_OF_NON_SUDO_ORACLES: u32 = 100;
+/// Bytes of the padding array of the `upsert_esg_scores` benchmark beyond its values, including
+/// the brackets of the input.
+const PADDING_OVERHEAD: u32 = 16;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
 
 fn create_oracle<T: Config>(string: &'static str, n: u32) -> T::AccountId {
 	let oracle = account(string, n, 0);
+	fund::<T>(&oracle);
 	oracle
 }
 
-fn register_oracles<T: Config>() {
-	for i in 0..(MAX_NUM_OF_SUDO_ORACLES - 1) {
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::OracleDeposit::get().saturating_mul(10u32.into()));
+}
+
+fn register_oracles<T: Config>() -> DispatchResult {
+	for i in 0..(T::MaxNumOfSudoOracles::get() - 1) {
 		Esg::<T>::register_an_oracle(
 			RawOrigin::Root.into(),
-			create_oracle::<T>("oracle", u32::MAX - i),
+			create_oracle::<T>("sudo_oracle", i),
 			true,
-		);
+		)?;
 	}
 
-	for j in 0..(MAX_NUM_OF_NON_SUDO_ORACLES - 1) {
+	for j in 0..(T::MaxNumOfNonSudoOracles::get() - 1) {
 		Esg::<T>::register_an_oracle(
 			RawOrigin::Root.into(),
-			create_oracle::<T>("oracle", u32::MAX - j),
+			create_oracle::<T>("oracle", j),
 			false,
-		);
+		)?;
 	}
+	Ok(())
 }
 
 benchmarks! {
 
 	register_an_oracle {
 		let oracle_account: T::AccountId = whitelisted_caller();
-		register_oracles::<T>();
+		fund::<T>(&oracle_account);
+		register_oracles::<T>()?;
 
 	}: _(RawOrigin::Root, oracle_account.clone(), true)
 	verify {
@@ -49,9 +64,9 @@ benchmarks! {
 
 	deregister_an_oracle {
 		let oracle_account: T::AccountId = whitelisted_caller();
-		let fn_mutate = |oracles: &mut Vec<<T as frame_system::Config>::AccountId>| oracles.push(oracle_account.clone());
-		<SudoOraclesStore<T>>::mutate(fn_mutate);
-		register_oracles::<T>();
+		fund::<T>(&oracle_account);
+		register_oracles::<T>()?;
+		Esg::<T>::register_an_oracle(RawOrigin::Root.into(), oracle_account.clone(), true)?;
 
 	}: _(RawOrigin::Root, oracle_account.clone(), true)
 	verify {
@@ -59,19 +74,128 @@ benchmarks! {
 	}
 
 	upsert_esg_scores {
+		let n in 1 .. T::MaxBatch::get();
+		// bytes parsed on top of `n` entries of the shortest length
+		let l in 0 .. T::MaxFileSize::get()
+			.saturating_sub(T::MaxBatch::get() * JSON_ENTRY_MIN_LEN + PADDING_OVERHEAD);
+
+		let caller1: T::AccountId = whitelisted_caller();
+		let fn_mutate = |oracles: &mut Vec<<T as frame_system::Config>::AccountId>| oracles.push(caller1.clone());
+		<SudoOraclesStore<T>>::mutate(fn_mutate);
+		register_oracles::<T>()?;
+
+		// the first entry carries an array of `l / 2` values, the most values a JSON input of its
+		// length can hold
+		let mut entries: Vec<String> = (0..n).map(|i| format!(r#"{{"account":"0x{:040x}"}}"#, i + 1)).collect();
+		entries[0] = format!(
+			r#"{{"account":"0x{:040x}","padding":[{}]}}"#,
+			1,
+			vec!["0"; (l / 2) as usize].join(",")
+		);
+		let data = format!("[{}]", entries.join(","));
+	}: _(RawOrigin::Signed(caller1.clone()), (WeakBoundedVec::try_from(data.as_bytes().to_vec())).unwrap())
+	verify {
+		assert_last_event::<T>(Event::ESGStored { caller: caller1 }.into());
+	}
+
+	upsert_esg_scores_typed {
+		let n in 0 .. T::MaxBatch::get();
 
 		let caller1: T::AccountId = whitelisted_caller();
 		let fn_mutate = |oracles: &mut Vec<<T as frame_system::Config>::AccountId>| oracles.push(caller1.clone());
 		<SudoOraclesStore<T>>::mutate(fn_mutate);
-		register_oracles::<T>();
-		let company1: T::AccountId = account("apple", 0, 0);
-		let company2: T::AccountId = account("microsoft", 1, 1);
+		register_oracles::<T>()?;
+
+		let scores: Vec<(T::AccountId, u16)> = (0..n).map(|i| (account("company", i, 0), 100)).collect();
+		let company: T::AccountId = account("company", 0, 0);
+	}: _(RawOrigin::Signed(caller1), BoundedVec::try_from(scores).unwrap())
+	verify {
+		if n > 0 {
+			assert_eq!(Esg::<T>::raw_score_of(company), 100);
+		}
+	}
+
+	slash_oracle {
+		let oracle_account: T::AccountId = whitelisted_caller();
+		fund::<T>(&oracle_account);
+		Esg::<T>::register_an_oracle(RawOrigin::Root.into(), oracle_account.clone(), false)?;
+		let deposit = T::OracleDeposit::get();
+	}: _(RawOrigin::Root, oracle_account.clone(), deposit)
+	verify {
+		assert_last_event::<T>(Event::OracleSlashed { oracle: oracle_account, amount: deposit }.into());
+	}
+
+	expire_oracles {
+		let n in 0 .. T::MaxNumOfSudoOracles::get() + T::MaxNumOfNonSudoOracles::get();
+
+		for i in 0..n {
+			Esg::<T>::register_an_oracle(
+				RawOrigin::Root.into(),
+				create_oracle::<T>("expiring", i),
+				i < T::MaxNumOfSudoOracles::get(),
+			)?;
+		}
+		let expires_at: BlockNumberFor<T> =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::OracleTerm::get());
+	}: {
+		Esg::<T>::on_initialize(expires_at);
+	}
+	verify {
+		assert!(<OracleExpiries<T>>::get(expires_at).is_empty());
+		assert_eq!(<OracleInfoOf<T>>::iter().count(), 0);
+	}
+
+	sweep_stale_score {
+		let company: T::AccountId = account("company", 0, 0);
+		let record = ScoreRecord { score: 100, block: Zero::zero(), oracle: whitelisted_caller() };
+		<ESGScoresMap<T>>::insert(&company, 100);
+		<ESGScoreHistory<T>>::insert(&company, BoundedVec::try_from(vec![record]).unwrap());
+		let now = T::ScoreValidity::get().saturating_add(T::ScoreDecayPeriod::get()).saturating_add(1u32.into());
+		frame_system::Pallet::<T>::set_block_number(now);
+		let remaining_weight = T::WeightInfo::sweep_stale_score().saturating_add(T::DbWeight::get().reads_writes(1, 1));
+	}: {
+		Esg::<T>::on_idle(now, remaining_weight);
+	}
+	verify {
+		assert_eq!(Esg::<T>::raw_score_of(company.clone()), 0);
+		assert_last_event::<T>(Event::ScoreStale { company }.into());
+	}
+
+	dispute_score {
+		let oracle: T::AccountId = whitelisted_caller();
+		fund::<T>(&oracle);
+		Esg::<T>::register_an_oracle(RawOrigin::Root.into(), oracle.clone(), true)?;
+		let company: T::AccountId = account("company", 0, 0);
+		Esg::<T>::upsert_esg_scores_typed(
+			RawOrigin::Signed(oracle).into(),
+			BoundedVec::try_from(vec![(company.clone(), 100)]).unwrap(),
+		)?;
+		let disputer: T::AccountId = account("disputer", 0, 0);
+		T::Currency::make_free_balance_be(&disputer, T::DisputeDeposit::get().saturating_mul(10u32.into()));
+		let evidence = H256::repeat_byte(1);
+	}: _(RawOrigin::Signed(disputer.clone()), company.clone(), evidence)
+	verify {
+		assert_last_event::<T>(Event::ScoreDisputed { company, disputer, evidence }.into());
+	}
 
-		let data = include_str!("data.json");
-	}: _(RawOrigin::Signed(caller1),(WeakBoundedVec::try_from(data.as_bytes().to_vec())).unwrap())
+	resolve_dispute {
+		let oracle: T::AccountId = whitelisted_caller();
+		fund::<T>(&oracle);
+		Esg::<T>::register_an_oracle(RawOrigin::Root.into(), oracle.clone(), true)?;
+		let company: T::AccountId = account("company", 0, 0);
+		for score in [50, 100] {
+			Esg::<T>::upsert_esg_scores_typed(
+				RawOrigin::Signed(oracle.clone()).into(),
+				BoundedVec::try_from(vec![(company.clone(), score)]).unwrap(),
+			)?;
+		}
+		let disputer: T::AccountId = account("disputer", 0, 0);
+		T::Currency::make_free_balance_be(&disputer, T::DisputeDeposit::get().saturating_mul(10u32.into()));
+		Esg::<T>::dispute_score(RawOrigin::Signed(disputer).into(), company.clone(), H256::repeat_byte(1))?;
+	}: _(RawOrigin::Root, company.clone(), true)
 	verify {
-		assert_eq!(Esg::<T>::get_score_of(company1), 1);
-		assert_eq!(Esg::<T>::get_score_of(company2), 100);
+		assert_eq!(Esg::<T>::raw_score_of(company.clone()), 50);
+		assert_last_event::<T>(Event::DisputeResolved { company, upheld: true }.into());
 	}
 
 	impl_benchmark_test_suite!(Esg, crate::mock::new_test_ext(), crate::tests::Test)
@@ -8,6 +8,8 @@ mod mock;
 #[cfg(test)]
 pub mod tests;
 
+pub mod migration;
+
 pub mod traits;
 
 pub mod weights;
@@ -19,36 +21,179 @@ pub trait Sustainability<AccountId> {
 	fn get_score_of(company: AccountId) -> u16;
 }
 
+pub type BalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
 #[frame_support::pallet]
 pub mod pallet {
-	use crate::{traits::ERScoresTrait, weights::WeightInfo};
+	use crate::{traits::ERScoresTrait, weights::WeightInfo, BalanceOf, NegativeImbalanceOf};
 	use core::{num::IntErrorKind, str::FromStr};
 	use fp_account::AccountId20;
 	use frame_support::{
 		pallet_prelude::{DispatchResult, *},
+		traits::{OnUnbalanced, ReservableCurrency},
 		WeakBoundedVec,
 	};
 	use frame_system::pallet_prelude::*;
 	use serde_json::Value;
-	use sp_core::H160;
+	use sp_core::{H160, H256};
+	use sp_runtime::{
+		traits::{SaturatedConversion, Saturating, Zero},
+		PerThing, Perbill,
+	};
 	use sp_std::vec::Vec;
 
 	const MAX_ESG_SCORE: u16 = 100;
 	const ACC_KEY: &str = "account";
 	const SCORE_KEY: &str = "score";
+	const ENVIRONMENTAL_KEY: &str = "environmental";
+	const SOCIAL_KEY: &str = "social";
+	const GOVERNANCE_KEY: &str = "governance";
+	const METHODOLOGY_KEY: &str = "methodology";
+	const EVIDENCE_KEY: &str = "evidence";
+	/// Maximum length of the methodology identifier of a score breakdown.
+	pub const MAX_METHODOLOGY_LEN: u32 = 32;
+	/// Maximum length of the reference to the evidence of a score breakdown, such as an IPFS
+	/// CID or the hash of the report.
+	pub const MAX_EVIDENCE_LEN: u32 = 128;
+	/// Length of the shortest JSON entry that stores a score, `{"account":"0x..."},`. Weight
+	/// of a JSON upload is charged upfront per byte of the input and as if the input were made
+	/// of such entries, up to `MaxBatch` of them, and refunded down to the number of entries it
+	/// actually has.
+	pub const JSON_ENTRY_MIN_LEN: u32 = 57;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
+	/// The deposit bonded by a registered oracle and the block its term ends at.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct OracleInfo<Balance, BlockNumber> {
+		pub deposit: Balance,
+		pub expires_at: BlockNumber,
+	}
+
+	/// A score uploaded for an account, with the block and the oracle it came from.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ScoreRecord<AccountId, BlockNumber> {
+		pub score: u16,
+		pub block: BlockNumber,
+		pub oracle: AccountId,
+	}
+
+	/// Environmental, social and governance sub-scores behind a composite score, with the
+	/// methodology they were assessed with and a reference to the supporting report.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct EsgBreakdown {
+		pub environmental: u16,
+		pub social: u16,
+		pub governance: u16,
+		pub methodology: BoundedVec<u8, ConstU32<MAX_METHODOLOGY_LEN>>,
+		pub evidence: Option<BoundedVec<u8, ConstU32<MAX_EVIDENCE_LEN>>>,
+	}
+
+	/// Relative weights of the environmental, social and governance pillars in the composite
+	/// score.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct PillarWeights {
+		pub environmental: u8,
+		pub social: u8,
+		pub governance: u8,
+	}
+
+	impl PillarWeights {
+		/// The weighted mean of the sub-scores, or their plain mean when no pillar is weighted.
+		pub fn composite(&self, breakdown: &EsgBreakdown) -> u16 {
+			let weights = match (self.environmental, self.social, self.governance) {
+				(0, 0, 0) => [1u32; 3],
+				(e, s, g) => [e.into(), s.into(), g.into()],
+			};
+			let scores = [breakdown.environmental, breakdown.social, breakdown.governance];
+			let weighted: u32 =
+				weights.iter().zip(scores).map(|(weight, score)| weight * score as u32).sum();
+			(weighted / weights.iter().sum::<u32>()) as u16
+		}
+	}
+
+	/// A pending dispute of the latest score of an account.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Dispute<AccountId, Balance, BlockNumber> {
+		pub disputer: AccountId,
+		pub deposit: Balance,
+		pub evidence: H256,
+		pub record: ScoreRecord<AccountId, BlockNumber>,
+	}
+
+	/// How the effective score of an account is derived from its score history.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum ScoreAveraging {
+		/// Each score weighs as much as the number of blocks it was the latest one.
+		TimeWeighted,
+		/// Each score moves the average towards it by the given smoothing factor.
+		Exponential(Perbill),
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		/// Maximum length of a JSON upload, in bytes. A JSON upload of `MaxBatch` entries and
+		/// this length must fit in a block.
 		#[pallet::constant]
 		type MaxFileSize: Get<u32>;
 		#[pallet::constant]
 		type MaxNumOfSudoOracles: Get<u32>;
 		#[pallet::constant]
 		type MaxNumOfNonSudoOracles: Get<u32>;
+		/// Number of past scores kept per account.
+		#[pallet::constant]
+		type MaxScoreHistory: Get<u32>;
+		/// Averaging used to compute the effective score from the score history.
+		#[pallet::constant]
+		type ScoreAveraging: Get<ScoreAveraging>;
+		/// Weighting of the pillars of a score breakdown in the composite score.
+		#[pallet::constant]
+		type PillarWeights: Get<PillarWeights>;
+		/// Maximum number of scores in a single upload.
+		#[pallet::constant]
+		type MaxBatch: Get<u32>;
+		/// Number of non-sudo oracles that must agree on a score before it is finalized.
+		#[pallet::constant]
+		type SubmissionQuorum: Get<u32>;
+		/// Distance from the median of the submissions above which a submitted score is an
+		/// outlier, which is left out of the finalized score and flagged.
+		#[pallet::constant]
+		type OutlierThreshold: Get<u16>;
+		/// Number of blocks a score keeps its full value after it was uploaded.
+		#[pallet::constant]
+		type ScoreValidity: Get<BlockNumberFor<Self>>;
+		/// Number of blocks over which an outdated score then decays linearly to zero, at which
+		/// point it is stale. With no decay period, a score is stale as soon as it is outdated.
+		#[pallet::constant]
+		type ScoreDecayPeriod: Get<BlockNumberFor<Self>>;
+		/// Currency the oracle deposits are reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Deposit reserved from an oracle for as long as it is registered.
+		#[pallet::constant]
+		type OracleDeposit: Get<BalanceOf<Self>>;
+		/// Number of blocks an oracle stays registered before it is removed.
+		#[pallet::constant]
+		type OracleTerm: Get<BlockNumberFor<Self>>;
+		/// Origin allowed to slash the deposit of an oracle.
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Handler for the slashed oracle and dispute deposits.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Deposit reserved from the disputer of a score until the dispute is resolved.
+		#[pallet::constant]
+		type DisputeDeposit: Get<BalanceOf<Self>>;
+		/// Origin allowed to resolve the disputes of scores.
+		type DisputeResolveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type WeightInfo: WeightInfo;
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 	}
@@ -63,11 +208,96 @@ pub mod pallet {
 	pub type NonSudoOraclesStore<T> =
 		StorageValue<_, Vec<<T as frame_system::Config>::AccountId>, ValueQuery>;
 
+	/// Deposit and term of every registered oracle
 	#[pallet::storage]
-	#[pallet::getter(fn get_score_of)]
+	#[pallet::getter(fn oracle_info_of)]
+	pub type OracleInfoOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		OracleInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Oracles whose term ends at a block
+	#[pallet::storage]
+	pub type OracleExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Vec<<T as frame_system::Config>::AccountId>,
+		ValueQuery,
+	>;
+
+	/// Latest score stored for the company, before any decay; `ERScoresTrait::get_score_of`
+	/// returns the effective score
+	#[pallet::storage]
+	#[pallet::getter(fn raw_score_of)]
 	pub type ESGScoresMap<T> =
 		StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, u16, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn score_history_of)]
+	pub type ESGScoreHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<
+			ScoreRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+			T::MaxScoreHistory,
+		>,
+		ValueQuery,
+	>;
+
+	/// Latest sub-scores uploaded for a company
+	#[pallet::storage]
+	#[pallet::getter(fn breakdown_of)]
+	pub type ESGBreakdownOf<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		EsgBreakdown,
+		OptionQuery,
+	>;
+
+	/// Sub-scores submitted by non-sudo oracles along with their pending score submissions
+	#[pallet::storage]
+	pub type SubmittedBreakdowns<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		EsgBreakdown,
+		OptionQuery,
+	>;
+
+	/// Pending disputes, by the company whose latest score is disputed
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_of)]
+	pub type Disputes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Dispute<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Last company checked for a stale score by the `on_idle` sweep
+	#[pallet::storage]
+	pub type StaleSweepCursor<T> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// Scores submitted by non-sudo oracles for a company, waiting for the quorum
+	#[pallet::storage]
+	#[pallet::getter(fn submissions_of)]
+	pub type ScoreSubmissions<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Vec<(<T as frame_system::Config>::AccountId, u16)>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -89,6 +319,44 @@ pub mod pallet {
 			caller: <T as frame_system::Config>::AccountId,
 			skipped_indeces: Vec<u16>,
 		},
+
+		ScoreFinalized {
+			company: <T as frame_system::Config>::AccountId,
+			score: u16,
+			submissions: u32,
+		},
+
+		OutlierFlagged {
+			oracle: <T as frame_system::Config>::AccountId,
+			company: <T as frame_system::Config>::AccountId,
+			score: u16,
+			median: u16,
+		},
+
+		OracleSlashed {
+			oracle: <T as frame_system::Config>::AccountId,
+			amount: BalanceOf<T>,
+		},
+
+		OracleExpired {
+			is_sudo: bool,
+			oracle: <T as frame_system::Config>::AccountId,
+		},
+
+		ScoreStale {
+			company: <T as frame_system::Config>::AccountId,
+		},
+
+		ScoreDisputed {
+			company: <T as frame_system::Config>::AccountId,
+			disputer: <T as frame_system::Config>::AccountId,
+			evidence: H256,
+		},
+
+		DisputeResolved {
+			company: <T as frame_system::Config>::AccountId,
+			upheld: bool,
+		},
 	}
 
 	#[pallet::error]
@@ -102,6 +370,70 @@ pub mod pallet {
 		CallerNotAnOracle,
 		OracleRegisteredAlready,
 		CallerNotRootOrSudoOracle,
+		InsufficientOracleDeposit,
+		NoScoreToDispute,
+		DisputePending,
+		DisputeNotFound,
+		InsufficientDisputeDeposit,
+		InvalidEvidence,
+		TooManyScores,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expired = <OracleExpiries<T>>::take(now);
+			let count = expired.len() as u32;
+			expired.iter().for_each(Self::expire_oracle);
+			T::WeightInfo::expire_oracles(count)
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let score_weight = T::WeightInfo::sweep_stale_score();
+			let mut consumed_weight = T::DbWeight::get().reads_writes(1, 1);
+			if !remaining_weight.all_gte(consumed_weight.saturating_add(score_weight)) {
+				return Weight::zero();
+			}
+
+			// resume after the last company checked, and start over once all were checked
+			let mut cursor = <StaleSweepCursor<T>>::get();
+			let mut histories = match cursor.clone() {
+				Some(last_key) => <ESGScoreHistory<T>>::iter_from(last_key),
+				None => <ESGScoreHistory<T>>::iter(),
+			};
+			while remaining_weight.all_gte(consumed_weight.saturating_add(score_weight)) {
+				let Some((company, history)) = histories.next() else {
+					cursor = None;
+					break;
+				};
+				consumed_weight.saturating_accrue(score_weight);
+				if history.last().map_or(false, |record| Self::is_stale(record.block)) {
+					Self::remove_stale_score(&company);
+				}
+				cursor = Some(<ESGScoreHistory<T>>::hashed_key_for(&company));
+			}
+			<StaleSweepCursor<T>>::set(cursor);
+			consumed_weight
+		}
+
+		fn integrity_test() {
+			let max_file_size = T::MaxFileSize::get();
+			assert!(
+				max_file_size <= *T::BlockLength::get().max.get(DispatchClass::Normal),
+				"a JSON upload of `MaxFileSize` bytes does not fit in a block"
+			);
+			let max_weight = T::BlockWeights::get()
+				.get(DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or(Weight::MAX);
+			assert!(
+				max_weight.all_gte(T::WeightInfo::upsert_esg_scores(
+					T::MaxBatch::get(),
+					max_file_size
+				)),
+				"a JSON upload of `MaxBatch` entries and `MaxFileSize` bytes does not fit in a block"
+			);
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -167,6 +499,48 @@ pub mod pallet {
 			}
 		}
 
+		/// Reserves the deposit of a newly registered oracle and schedules the end of its term.
+		fn bond_oracle(oracle: &<T as frame_system::Config>::AccountId) -> DispatchResult {
+			let deposit = T::OracleDeposit::get();
+			T::Currency::reserve(oracle, deposit)
+				.map_err(|_| Error::<T>::InsufficientOracleDeposit)?;
+			Self::insert_oracle_info(oracle, deposit);
+			Ok(())
+		}
+
+		pub(crate) fn insert_oracle_info(
+			oracle: &<T as frame_system::Config>::AccountId,
+			deposit: BalanceOf<T>,
+		) {
+			let expires_at =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::OracleTerm::get());
+			<OracleInfoOf<T>>::insert(oracle, OracleInfo { deposit, expires_at });
+			<OracleExpiries<T>>::append(expires_at, oracle);
+		}
+
+		/// Returns what is left of the deposit of a removed oracle.
+		fn unbond_oracle(oracle: &<T as frame_system::Config>::AccountId) {
+			if let Some(info) = <OracleInfoOf<T>>::take(oracle) {
+				T::Currency::unreserve(oracle, info.deposit);
+				<OracleExpiries<T>>::mutate_exists(info.expires_at, |expiring| {
+					if let Some(oracles) = expiring {
+						oracles.retain(|expiring_oracle| expiring_oracle != oracle);
+						if oracles.is_empty() {
+							*expiring = None;
+						}
+					}
+				});
+			}
+		}
+
+		fn expire_oracle(oracle: &<T as frame_system::Config>::AccountId) {
+			let is_sudo = Self::is_sudo_oracle(oracle);
+			if Self::un_store_oracle(oracle, is_sudo).is_ok() {
+				Self::unbond_oracle(oracle);
+				Self::deposit_event(Event::OracleExpired { is_sudo, oracle: oracle.clone() });
+			}
+		}
+
 		fn un_store_oracle(
 			oracle: &<T as frame_system::Config>::AccountId,
 			is_sudo: bool,
@@ -203,6 +577,268 @@ pub mod pallet {
 				.clamp(0, MAX_ESG_SCORE)
 		}
 
+		/// Parses the sub-scores of a JSON entry, if it has any. Evidence that is not a non-empty
+		/// string of at most `MAX_EVIDENCE_LEN` bytes is rejected.
+		fn parse_breakdown(entry: &serde_json::Value) -> Result<Option<EsgBreakdown>, Error<T>> {
+			if [ENVIRONMENTAL_KEY, SOCIAL_KEY, GOVERNANCE_KEY]
+				.iter()
+				.all(|key| entry.get(key).is_none())
+			{
+				return Ok(None);
+			}
+
+			let methodology = entry
+				.get(METHODOLOGY_KEY)
+				.and_then(Value::as_str)
+				.unwrap_or("")
+				.as_bytes()
+				.to_vec();
+			let evidence = match entry.get(EVIDENCE_KEY) {
+				None | Some(Value::Null) => None,
+				Some(Value::String(evidence)) if !evidence.is_empty() => Some(
+					BoundedVec::try_from(evidence.as_bytes().to_vec())
+						.map_err(|_| Error::<T>::InvalidEvidence)?,
+				),
+				Some(_) => return Err(Error::<T>::InvalidEvidence),
+			};
+
+			Ok(Some(EsgBreakdown {
+				environmental: Self::parse_score(entry.get(ENVIRONMENTAL_KEY)),
+				social: Self::parse_score(entry.get(SOCIAL_KEY)),
+				governance: Self::parse_score(entry.get(GOVERNANCE_KEY)),
+				methodology: BoundedVec::truncate_from(methodology),
+				evidence,
+			}))
+		}
+
+		/// Records a new score of the company, dropping its oldest score once the history is
+		/// full. The sub-scores of an earlier score are kept when the new one has none.
+		fn store_score(
+			company: &<T as frame_system::Config>::AccountId,
+			score: u16,
+			oracle: &<T as frame_system::Config>::AccountId,
+			breakdown: Option<EsgBreakdown>,
+		) {
+			<ESGScoresMap<T>>::insert(company, score);
+			if let Some(breakdown) = breakdown {
+				<ESGBreakdownOf<T>>::insert(company, breakdown);
+			}
+			<ScoreSubmissions<T>>::remove(company);
+			let _ = <SubmittedBreakdowns<T>>::clear_prefix(company, u32::MAX, None);
+			<ESGScoreHistory<T>>::mutate(company, |history| {
+				if history.is_full() {
+					history.remove(0);
+				}
+				let _ = history.try_push(ScoreRecord {
+					score,
+					block: <frame_system::Pallet<T>>::block_number(),
+					oracle: oracle.clone(),
+				});
+			});
+		}
+
+		/// Stores the score right away for a sudo oracle, or submits it towards the quorum
+		/// otherwise.
+		fn apply_score(
+			company: &<T as frame_system::Config>::AccountId,
+			score: u16,
+			oracle: &<T as frame_system::Config>::AccountId,
+			is_sudo: bool,
+			non_sudo_oracles: &[<T as frame_system::Config>::AccountId],
+			breakdown: Option<EsgBreakdown>,
+		) {
+			if is_sudo {
+				Self::store_score(company, score, oracle, breakdown);
+			} else {
+				Self::submit_score(company, score, oracle, non_sudo_oracles, breakdown);
+			}
+		}
+
+		/// Records the score submitted by a non-sudo oracle, replacing its earlier submission.
+		/// The submissions too far from their median are outliers and are left out. Once
+		/// `SubmissionQuorum` registered oracles agree, the median of their scores is stored and
+		/// the outliers are flagged. The median keeps the sub-scores of the agreeing submission
+		/// closest to it.
+		fn submit_score(
+			company: &<T as frame_system::Config>::AccountId,
+			score: u16,
+			oracle: &<T as frame_system::Config>::AccountId,
+			oracles: &[<T as frame_system::Config>::AccountId],
+			breakdown: Option<EsgBreakdown>,
+		) {
+			let mut submissions = <ScoreSubmissions<T>>::get(company);
+			submissions.retain(|(submitter, _)| submitter != oracle && oracles.contains(submitter));
+			submissions.push((oracle.clone(), score));
+			<SubmittedBreakdowns<T>>::set(company, oracle, breakdown);
+
+			let quorum = T::SubmissionQuorum::get().max(1);
+			let threshold = T::OutlierThreshold::get();
+			let all_median = Self::median(submissions.iter().map(|(_, score)| *score).collect());
+			let (agreeing, outliers): (Vec<_>, Vec<_>) = submissions
+				.iter()
+				.partition(|(_, submitted)| submitted.abs_diff(all_median) <= threshold);
+			if (agreeing.len() as u32) < quorum {
+				<ScoreSubmissions<T>>::insert(company, submissions);
+				return;
+			}
+
+			let median = Self::median(agreeing.iter().map(|(_, score)| *score).collect());
+			for (submitter, submitted) in outliers {
+				Self::deposit_event(Event::OutlierFlagged {
+					oracle: submitter.clone(),
+					company: company.clone(),
+					score: *submitted,
+					median,
+				});
+			}
+			let breakdown = agreeing
+				.iter()
+				.min_by_key(|(_, submitted)| submitted.abs_diff(median))
+				.and_then(|(submitter, _)| <SubmittedBreakdowns<T>>::get(company, submitter));
+			Self::store_score(company, median, oracle, breakdown);
+			Self::deposit_event(Event::ScoreFinalized {
+				company: company.clone(),
+				score: median,
+				submissions: agreeing.len() as u32,
+			});
+		}
+
+		/// The middle score, or the mean of the two middle scores for an even count.
+		fn median(mut scores: Vec<u16>) -> u16 {
+			scores.sort_unstable();
+			let mid = scores.len() / 2;
+			match scores.len() {
+				0 => 0,
+				len if len % 2 == 0 => ((scores[mid - 1] as u32 + scores[mid] as u32) / 2) as u16,
+				_ => scores[mid],
+			}
+		}
+
+		/// The score of the company averaged over its history, as configured by
+		/// `ScoreAveraging`. Falls back to the latest score when there is no history. A disputed
+		/// score is left out until the dispute is resolved.
+		pub fn effective_score_of(company: <T as frame_system::Config>::AccountId) -> u16 {
+			let mut history = <ESGScoreHistory<T>>::get(&company).into_inner();
+			if let Some(dispute) = <Disputes<T>>::get(&company) {
+				history.retain(|record| record != &dispute.record);
+				if history.is_empty() {
+					return 0;
+				}
+			}
+			let Some(latest) = history.last() else {
+				return <ESGScoresMap<T>>::get(&company);
+			};
+			let average = match T::ScoreAveraging::get() {
+				ScoreAveraging::TimeWeighted => Self::time_weighted_average(&history),
+				ScoreAveraging::Exponential(smoothing) =>
+					Self::exponential_average(&history, smoothing),
+			};
+			Self::decay(average, latest.block)
+		}
+
+		/// Decays a score linearly to zero over `ScoreDecayPeriod` once the latest upload is
+		/// older than `ScoreValidity`.
+		fn decay(score: u16, uploaded_at: BlockNumberFor<T>) -> u16 {
+			let age = <frame_system::Pallet<T>>::block_number().saturating_sub(uploaded_at);
+			let validity = T::ScoreValidity::get();
+			if age <= validity {
+				return score;
+			}
+			let decay_period = T::ScoreDecayPeriod::get();
+			let remaining = decay_period.saturating_sub(age - validity);
+			if remaining.is_zero() {
+				return 0;
+			}
+			(Perbill::from_rational(remaining, decay_period) * u32::from(score)) as u16
+		}
+
+		/// Whether a score uploaded at the given block has fully decayed.
+		fn is_stale(uploaded_at: BlockNumberFor<T>) -> bool {
+			let age = <frame_system::Pallet<T>>::block_number().saturating_sub(uploaded_at);
+			let validity = T::ScoreValidity::get();
+			age > validity && age - validity >= T::ScoreDecayPeriod::get()
+		}
+
+		fn remove_stale_score(company: &<T as frame_system::Config>::AccountId) {
+			<ESGScoresMap<T>>::remove(company);
+			<ESGScoreHistory<T>>::remove(company);
+			<ESGBreakdownOf<T>>::remove(company);
+			Self::deposit_event(Event::ScoreStale { company: company.clone() });
+		}
+
+		/// Restores the score the company had before the disputed one.
+		fn revert_score(
+			company: &<T as frame_system::Config>::AccountId,
+			disputed: &ScoreRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		) {
+			<ESGScoreHistory<T>>::mutate_exists(company, |maybe_history| {
+				let Some(history) = maybe_history else { return };
+				// the sub-scores only belong to the latest score
+				if history.last() == Some(disputed) {
+					<ESGBreakdownOf<T>>::remove(company);
+				}
+				history.retain(|record| record != disputed);
+				match history.last().map(|previous| previous.score) {
+					Some(previous) => <ESGScoresMap<T>>::insert(company, previous),
+					None => {
+						<ESGScoresMap<T>>::remove(company);
+						*maybe_history = None;
+					},
+				}
+			});
+		}
+
+		/// Slashes up to `amount` of the deposit of an oracle.
+		fn do_slash_oracle(
+			oracle: &<T as frame_system::Config>::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let mut info = <OracleInfoOf<T>>::get(oracle).ok_or(Error::<T>::OracleNotExist)?;
+			let amount = amount.min(info.deposit);
+			let (imbalance, _) = T::Currency::slash_reserved(oracle, amount);
+			T::Slash::on_unbalanced(imbalance);
+
+			info.deposit = info.deposit.saturating_sub(amount);
+			<OracleInfoOf<T>>::insert(oracle, info);
+			Self::deposit_event(Event::OracleSlashed { oracle: oracle.clone(), amount });
+			Ok(())
+		}
+
+		fn time_weighted_average(
+			history: &[ScoreRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>],
+		) -> u16 {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let (weighted, total) = history.iter().enumerate().fold(
+				(0u128, 0u128),
+				|(weighted, total), (i, record)| {
+					let until = history.get(i + 1).map_or(now, |next| next.block);
+					let weight: u128 = until.saturating_sub(record.block).saturated_into();
+					(
+						weighted.saturating_add(weight.saturating_mul(record.score.into())),
+						total.saturating_add(weight),
+					)
+				},
+			);
+			match total {
+				// every score was uploaded in the current block
+				0 => history.last().map_or(0, |record| record.score),
+				_ => (weighted / total) as u16,
+			}
+		}
+
+		fn exponential_average(
+			history: &[ScoreRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>],
+			smoothing: Perbill,
+		) -> u16 {
+			history.iter().skip(1).fold(
+				history.first().map_or(0, |record| record.score),
+				|average, record| {
+					let (score, average) = (u32::from(record.score), u32::from(average));
+					((smoothing * score) + (smoothing.left_from_one() * average)) as u16
+				},
+			)
+		}
+
 		pub fn try_parse_addr(
 			acc_val: Option<&serde_json::Value>,
 		) -> Option<<T as frame_system::Config>::AccountId> {
@@ -222,11 +858,14 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::upsert_esg_scores())]
+		#[pallet::weight(T::WeightInfo::upsert_esg_scores(
+			(json_str_bytes.len() as u32 / JSON_ENTRY_MIN_LEN).min(T::MaxBatch::get()),
+			json_str_bytes.len() as u32,
+		))]
 		pub fn upsert_esg_scores(
 			origin: OriginFor<T>,
 			json_str_bytes: WeakBoundedVec<u8, T::MaxFileSize>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let signer = ensure_signed(origin)?;
 
 			if !Self::is_an_oracle(&signer) {
@@ -240,29 +879,56 @@ pub mod pallet {
 				.map_or_else(|_| Err(Error::<T>::InvalidJson), Ok)?;
 
 			let esg_data = esg_info.as_array().map_or_else(|| Err(Error::<T>::InvalidJson), Ok)?;
+			ensure!(esg_data.len() as u32 <= T::MaxBatch::get(), Error::<T>::TooManyScores);
+			let actual_weight = T::WeightInfo::upsert_esg_scores(
+				esg_data.len() as u32,
+				json_str_bytes.len() as u32,
+			);
 
 			let mut skipped_indeces = Vec::<u16>::new();
+			let is_sudo = Self::is_sudo_oracle(&signer);
+			let non_sudo_oracles = <NonSudoOraclesStore<T>>::get();
 
-			esg_data.iter().enumerate().for_each(|(i, ed)| {
+			for (i, ed) in esg_data.iter().enumerate() {
 				match Self::try_parse_addr(ed.get(ACC_KEY)) {
-					Some(id) => <ESGScoresMap<T>>::mutate(&id, |v| {
-						*v = Self::parse_score(ed.get(SCORE_KEY))
-					}),
+					Some(id) => {
+						let breakdown = match Self::parse_breakdown(ed) {
+							Ok(breakdown) => breakdown,
+							// the evidence of the entry is malformed
+							Err(_) => {
+								skipped_indeces.push(i as u16);
+								continue;
+							},
+						};
+						// the composite of the sub-scores takes the place of a plain score
+						let score = breakdown.as_ref().map_or_else(
+							|| Self::parse_score(ed.get(SCORE_KEY)),
+							|breakdown| T::PillarWeights::get().composite(breakdown),
+						);
+						Self::apply_score(
+							&id,
+							score,
+							&signer,
+							is_sudo,
+							&non_sudo_oracles,
+							breakdown,
+						)
+					},
 					// acc_id is either invalid or
 					// not found in json data under current index
 					None => skipped_indeces.push(i as u16),
 				};
-			});
+			}
 
 			if !skipped_indeces.is_empty() {
 				Self::deposit_event(Event::ESGStoredWithSkip {
 					skipped_indeces,
 					caller: signer.clone(),
 				});
-				return Ok(());
+				return Ok(Some(actual_weight).into());
 			}
 			Self::deposit_event(Event::ESGStored { caller: signer.clone() });
-			Ok(())
+			Ok(Some(actual_weight).into())
 		}
 
 		#[pallet::call_index(1)]
@@ -285,7 +951,8 @@ pub mod pallet {
 			}
 
 			if is_root || Self::is_sudo_oracle(&acc_id) {
-				let _ = Self::store_oracle(&oracle, is_sudo_oracle)?;
+				Self::store_oracle(&oracle, is_sudo_oracle)?;
+				Self::bond_oracle(&oracle)?;
 			} else {
 				return Err(Error::<T>::CallerNotRootOrSudoOracle.into());
 			}
@@ -303,10 +970,11 @@ pub mod pallet {
 			oracle: <T as frame_system::Config>::AccountId,
 			is_sudo_oracle: bool,
 		) -> DispatchResult {
-			let o = ensure_root(origin);
-
-			if o.is_err() {
-				return Err(DispatchError::BadOrigin);
+			// sudo oracles may only deregister non-sudo oracles
+			let (id, is_root) = Self::try_resolve(&origin, &oracle);
+			match id {
+				Some(id) if is_root || (!is_sudo_oracle && Self::is_sudo_oracle(&id)) => (),
+				_ => return Err(DispatchError::BadOrigin),
 			}
 
 			if !Self::is_an_oracle(&oracle) {
@@ -315,6 +983,7 @@ pub mod pallet {
 
 			let un_stored = Self::un_store_oracle(&oracle, is_sudo_oracle);
 			if un_stored.is_ok() {
+				Self::unbond_oracle(&oracle);
 				Self::deposit_event(Event::OracleDeRegistered {
 					oracle: oracle.clone(),
 					is_sudo: is_sudo_oracle,
@@ -323,15 +992,117 @@ pub mod pallet {
 			}
 			return un_stored;
 		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::upsert_esg_scores_typed(scores.len() as u32))]
+		pub fn upsert_esg_scores_typed(
+			origin: OriginFor<T>,
+			scores: BoundedVec<(<T as frame_system::Config>::AccountId, u16), T::MaxBatch>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			if !Self::is_an_oracle(&signer) {
+				return Err(Error::<T>::CallerNotAnOracle.into());
+			}
+
+			let is_sudo = Self::is_sudo_oracle(&signer);
+			let non_sudo_oracles = <NonSudoOraclesStore<T>>::get();
+
+			for (company, score) in scores.iter() {
+				Self::apply_score(
+					company,
+					(*score).min(MAX_ESG_SCORE),
+					&signer,
+					is_sudo,
+					&non_sudo_oracles,
+					None,
+				);
+			}
+
+			Self::deposit_event(Event::ESGStored { caller: signer });
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::slash_oracle())]
+		pub fn slash_oracle(
+			origin: OriginFor<T>,
+			oracle: <T as frame_system::Config>::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+			Self::do_slash_oracle(&oracle, amount)
+		}
+
+		/// Disputes the latest score of a company, reserving `DisputeDeposit` from the caller.
+		/// The score has no effect until the dispute is resolved.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::dispute_score())]
+		pub fn dispute_score(
+			origin: OriginFor<T>,
+			company: <T as frame_system::Config>::AccountId,
+			evidence: H256,
+		) -> DispatchResult {
+			let disputer = ensure_signed(origin)?;
+
+			ensure!(!<Disputes<T>>::contains_key(&company), Error::<T>::DisputePending);
+			let record = <ESGScoreHistory<T>>::get(&company)
+				.last()
+				.cloned()
+				.ok_or(Error::<T>::NoScoreToDispute)?;
+
+			let deposit = T::DisputeDeposit::get();
+			T::Currency::reserve(&disputer, deposit)
+				.map_err(|_| Error::<T>::InsufficientDisputeDeposit)?;
+
+			<Disputes<T>>::insert(
+				&company,
+				Dispute { disputer: disputer.clone(), deposit, evidence, record },
+			);
+			Self::deposit_event(Event::ScoreDisputed { company, disputer, evidence });
+			Ok(())
+		}
+
+		/// Resolves the dispute of the score of a company. An upheld dispute restores the
+		/// previous score, returns the deposit of the disputer and slashes as much from the
+		/// oracle that uploaded the disputed score. Otherwise the disputed score is confirmed
+		/// and the deposit of the disputer is slashed.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			company: <T as frame_system::Config>::AccountId,
+			upheld: bool,
+		) -> DispatchResult {
+			T::DisputeResolveOrigin::ensure_origin(origin)?;
+
+			let dispute = <Disputes<T>>::take(&company).ok_or(Error::<T>::DisputeNotFound)?;
+			if upheld {
+				Self::revert_score(&company, &dispute.record);
+				T::Currency::unreserve(&dispute.disputer, dispute.deposit);
+				// the oracle may have been removed since
+				let _ = Self::do_slash_oracle(&dispute.record.oracle, dispute.deposit);
+			} else {
+				let (imbalance, _) =
+					T::Currency::slash_reserved(&dispute.disputer, dispute.deposit);
+				T::Slash::on_unbalanced(imbalance);
+			}
+
+			Self::deposit_event(Event::DisputeResolved { company, upheld });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> ERScoresTrait<<T as frame_system::Config>::AccountId> for Pallet<T> {
 		fn get_score_of(org: <T as frame_system::Config>::AccountId) -> u16 {
-			ESGScoresMap::<T>::get(&org)
+			Self::effective_score_of(org)
 		}
 		fn chilled_validator_status(_org: <T as frame_system::Config>::AccountId) {}
 		fn reset_chilled_validator_status(_org: <T as frame_system::Config>::AccountId) {}
 		fn reset_score_after_era_for_chilled_active_validator() {}
 		fn reset_score_of_chilled_waiting_validator(_org: <T as frame_system::Config>::AccountId) {}
+		fn is_chilled(_org: <T as frame_system::Config>::AccountId) -> bool {
+			false
+		}
 	}
 }
@@ -0,0 +1,145 @@
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{OnRuntimeUpgrade, ReservableCurrency},
+};
+use sp_runtime::traits::{TrailingZeroInput, Zero};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+	const TARGET: &str = "runtime::esg::migration::v1";
+
+	/// Bonds the oracles registered before deposits were introduced and starts their term. An
+	/// oracle that cannot afford the deposit keeps its registration with an empty deposit.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			log::info!(
+				target: TARGET,
+				"Running migration with onchain storage version {:?}",
+				onchain_version
+			);
+
+			if onchain_version == 0 {
+				let deposit = T::OracleDeposit::get();
+				let mut count = 0;
+				for oracle in SudoOraclesStore::<T>::get()
+					.iter()
+					.chain(NonSudoOraclesStore::<T>::get().iter())
+				{
+					let bonded = match T::Currency::reserve(oracle, deposit) {
+						Ok(()) => deposit,
+						Err(_) => {
+							log::warn!(
+								target: TARGET,
+								"Oracle {:?} cannot afford the deposit, bonding nothing",
+								oracle
+							);
+							Zero::zero()
+						},
+					};
+					Pallet::<T>::insert_oracle_info(oracle, bonded);
+					count += 1;
+				}
+				StorageVersion::new(1).put::<Pallet<T>>();
+				log::info!(target: TARGET, "esg v1 applied successfully");
+				T::DbWeight::get().reads_writes((count as u64) + 3, (count as u64) * 3 + 1)
+			} else {
+				log::warn!(target: TARGET, "Skipping esg v1, should be removed");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count = SudoOraclesStore::<T>::decode_len().unwrap_or_default() +
+				NonSudoOraclesStore::<T>::decode_len().unwrap_or_default();
+			Ok((count as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(
+				OracleInfoOf::<T>::iter().count() as u32 == prev_count,
+				"every oracle should have a deposit and a term"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	const TARGET: &str = "runtime::esg::migration::v2";
+
+	/// Seeds the score history of the companies scored before it was kept, so that their scores
+	/// decay, go stale and can be disputed like any other. Their oracle is unknown and is
+	/// recorded as the all-zero account, and their validity starts at the upgrade.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			log::info!(
+				target: TARGET,
+				"Running migration with onchain storage version {:?}",
+				onchain_version
+			);
+
+			if onchain_version == 1 {
+				let block = <frame_system::Pallet<T>>::block_number();
+				let oracle = T::AccountId::decode(&mut TrailingZeroInput::zeroes())
+					.expect("infinite length input; no invalid inputs for type; qed");
+				let (mut scores, mut seeded) = (0u64, 0u64);
+				for (company, score) in ESGScoresMap::<T>::iter() {
+					scores += 1;
+					if ESGScoreHistory::<T>::contains_key(&company) {
+						continue;
+					}
+					let record = ScoreRecord { score, block, oracle: oracle.clone() };
+					ESGScoreHistory::<T>::mutate(&company, |history| {
+						let _ = history.try_push(record);
+					});
+					seeded += 1;
+				}
+				StorageVersion::new(2).put::<Pallet<T>>();
+				log::info!(target: TARGET, "esg v2 seeded the history of {} scores", seeded);
+				T::DbWeight::get().reads_writes(scores * 2 + 1, seeded + 1)
+			} else {
+				log::warn!(target: TARGET, "Skipping esg v2, should be removed");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((ESGScoresMap::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(count: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_count: u32 = Decode::decode(&mut count.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(
+				ESGScoresMap::<T>::iter_keys()
+					.filter(|company| ESGScoreHistory::<T>::contains_key(company))
+					.count() as u32 == prev_count,
+				"every score should have a history"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
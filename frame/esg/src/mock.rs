@@ -7,7 +7,7 @@ use crate as pallet_esg;
 use fp_account::AccountId20;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU32, ConstU64},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
 };
 use frame_system as system;
 use sp_core::{Decode, H160, H256};
@@ -23,6 +23,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		Esg: pallet_esg,
 	}
 );
@@ -42,7 +43,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u128>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -53,10 +54,40 @@ impl system::Config for Test {
 	type Block = Block;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+pub const ORACLE_DEPOSIT: u128 = 100;
+pub const ORACLE_TERM: u64 = 1000;
+pub const DISPUTE_DEPOSIT: u128 = 50;
+pub const SCORE_VALIDITY: u64 = 100;
+pub const SCORE_DECAY_PERIOD: u64 = 50;
+
 parameter_types! {
-	pub const MaxFileSize: u32 = 1024000;
+	pub const MaxFileSize: u32 = 512 * 1024;
 	pub const MaxNumOfSudoOracles: u32 = 3;
-	pub const MaxNumOfNonSudoOracles: u32 = 2;
+	pub storage MaxNumOfNonSudoOracles: u32 = 2;
+	pub const MaxScoreHistory: u32 = 3;
+	pub const MaxBatch: u32 = 20;
+	pub const SubmissionQuorum: u32 = 2;
+	pub const OutlierThreshold: u16 = 20;
+	pub storage EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 2, social: 1, governance: 1 };
 }
 
 impl pallet_esg::Config for Test {
@@ -64,11 +95,33 @@ impl pallet_esg::Config for Test {
 	type MaxFileSize = MaxFileSize;
 	type MaxNumOfSudoOracles = MaxNumOfSudoOracles;
 	type MaxNumOfNonSudoOracles = MaxNumOfNonSudoOracles;
+	type MaxScoreHistory = MaxScoreHistory;
+	type MaxBatch = MaxBatch;
+	type SubmissionQuorum = SubmissionQuorum;
+	type OutlierThreshold = OutlierThreshold;
+	type ScoreValidity = ConstU64<SCORE_VALIDITY>;
+	type ScoreDecayPeriod = ConstU64<SCORE_DECAY_PERIOD>;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
+	type Currency = Balances;
+	type OracleDeposit = ConstU128<ORACLE_DEPOSIT>;
+	type OracleTerm = ConstU64<ORACLE_TERM>;
+	type SlashOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Slash = ();
+	type DisputeDeposit = ConstU128<DISPUTE_DEPOSIT>;
+	type DisputeResolveOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut storage = system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	// every account used by the tests can afford the oracle deposit
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (0u8..16).map(|i| (AccountId20::from([i; 20]), 1000)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
 }
 
 pub fn hexstr2acc_id20(s: &str) -> <Test as frame_system::Config>::AccountId {
@@ -1,6 +1,12 @@
 pub use crate::{mock::*, Error};
 use fp_account::AccountId20;
-use frame_support::{assert_err, assert_noop, assert_ok, WeakBoundedVec};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	traits::{Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::{RuntimeDbWeight, Weight},
+	BoundedVec, WeakBoundedVec,
+};
 use sp_runtime::DispatchError;
 
 const MAX_ESG_SCORE: u16 = 100;
@@ -484,8 +490,8 @@ fn it_must_upload_all_valid_esg_data_with_no_skipping() {
 		let company1 = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
 		let company2 = hexstr2acc_id20("Ba08C49f377a4F01c65340F431B5C65D71B972a9");
 
-		assert_eq!(Esg::get_score_of(company1), 12);
-		assert_eq!(Esg::get_score_of(company2), 40);
+		assert_eq!(Esg::raw_score_of(company1), 12);
+		assert_eq!(Esg::raw_score_of(company2), 40);
 
 		// event `ESGStored` must have got triggered with expected data
 		System::assert_last_event(RuntimeEvent::Esg(crate::Event::ESGStored { caller: addr.ROOT }));
@@ -542,8 +548,8 @@ fn it_must_skip_for_invalid_addresses_in_esg_data() {
 		let valid_id1 = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
 		let valid_id2 = hexstr2acc_id20("25Db9D98e4Ab6af68ac7173f580c444155B7b5C8");
 
-		assert_eq!(Esg::get_score_of(valid_id1), MAX_ESG_SCORE);
-		assert_eq!(Esg::get_score_of(valid_id2), 99);
+		assert_eq!(Esg::raw_score_of(valid_id1), MAX_ESG_SCORE);
+		assert_eq!(Esg::raw_score_of(valid_id2), 99);
 
 		// check `ESGStoredWithSkip` was trigered with expected data
 		System::assert_last_event(RuntimeEvent::Esg(crate::Event::ESGStoredWithSkip {
@@ -594,11 +600,11 @@ fn it_must_handle_invalid_esg_scores() {
 		let company3 = hexstr2acc_id20("25Db9D98e4Ab6af68ac7173f580c444155B7b5C8");
 
 		// negative to zero
-		assert_eq!(Esg::get_score_of(company1), 0);
+		assert_eq!(Esg::raw_score_of(company1), 0);
 		// non-numeric to zero
-		assert_eq!(Esg::get_score_of(company2), 0);
+		assert_eq!(Esg::raw_score_of(company2), 0);
 		// empty to zero
-		assert_eq!(Esg::get_score_of(company3), 0);
+		assert_eq!(Esg::raw_score_of(company3), 0);
 
 		// check `ESGStored` was trigered with expected data
 		System::assert_last_event(RuntimeEvent::Esg(crate::Event::ESGStored {
@@ -643,9 +649,9 @@ fn it_must_handle_scores_exceeding_set_maximum() {
 		let company2 = hexstr2acc_id20("Ba08C49f377a4F01c65340F431B5C65D71B972a9");
 
 		// 121 truncated to MAX_ESG_SCORE because it exceeds MAX_ESG_SCORE
-		assert_eq!(Esg::get_score_of(company1), MAX_ESG_SCORE);
+		assert_eq!(Esg::raw_score_of(company1), MAX_ESG_SCORE);
 		// 7675675 is truncated to MAX_ESG_SCORE because of overflow on u16
-		assert_eq!(Esg::get_score_of(company2), MAX_ESG_SCORE);
+		assert_eq!(Esg::raw_score_of(company2), MAX_ESG_SCORE);
 
 		// check `ESGStored` was trigered with expected data
 		System::assert_last_event(RuntimeEvent::Esg(crate::Event::ESGStored {
@@ -706,3 +712,849 @@ fn it_must_fail_to_register_oracle_non_oracle_exceeding_maximum() {
 		);
 	});
 }
+
+fn upload_score(oracle: AccountId20, score: u16) {
+	let data = format!(
+		r#"[{{"score":"{}","account":"0x82a0EcfDd3174bEF5D5eA452e15219A52bf6161f"}}]"#,
+		score
+	);
+	assert_ok!(Esg::upsert_esg_scores(
+		RuntimeOrigin::signed(oracle),
+		(WeakBoundedVec::try_from(data.as_bytes().to_vec())).unwrap()
+	));
+}
+
+#[test]
+fn it_must_keep_a_bounded_score_history() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_2, true));
+
+		for (block, score) in [(1, 10), (2, 20), (3, 30)] {
+			System::set_block_number(block);
+			upload_score(addr.SUDO_ORACLE, score);
+		}
+		System::set_block_number(4);
+		upload_score(addr.SUDO_ORACLE_2, 40);
+
+		// the oldest score is dropped once `MaxScoreHistory` scores are kept
+		let history = Esg::score_history_of(company);
+		assert_eq!(
+			history.iter().map(|record| (record.score, record.block)).collect::<Vec<_>>(),
+			vec![(20, 2), (30, 3), (40, 4)]
+		);
+		assert_eq!(history[2].oracle, addr.SUDO_ORACLE_2);
+		assert_eq!(history[1].oracle, addr.SUDO_ORACLE);
+		assert_eq!(Esg::raw_score_of(company), 40);
+	});
+}
+
+#[test]
+fn it_must_weigh_scores_by_the_time_they_were_in_effect() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		upload_score(addr.SUDO_ORACLE, 60);
+		// a score uploaded in the current block has not been in effect yet
+		assert_eq!(Esg::effective_score_of(company), 60);
+
+		System::set_block_number(10);
+		upload_score(addr.SUDO_ORACLE, 0);
+		assert_eq!(Esg::effective_score_of(company), 60);
+
+		// 9 blocks at 60 and 3 blocks at 0
+		System::set_block_number(13);
+		assert_eq!(Esg::effective_score_of(company), 45);
+		assert_eq!(<Esg as crate::traits::ERScoresTrait<AccountId20>>::get_score_of(company), 45);
+	});
+}
+
+#[test]
+fn it_must_support_an_exponential_moving_average() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		EsgScoreAveraging::set(&crate::ScoreAveraging::Exponential(
+			sp_runtime::Perbill::from_percent(50),
+		));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		upload_score(addr.SUDO_ORACLE, 80);
+		upload_score(addr.SUDO_ORACLE, 40);
+		upload_score(addr.SUDO_ORACLE, 100);
+
+		// 80 -> 60 -> 80
+		assert_eq!(Esg::effective_score_of(company), 80);
+	});
+}
+
+#[test]
+fn it_must_fall_back_to_the_latest_score_without_history() {
+	new_test_ext().execute_with(|| {
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		crate::ESGScoresMap::<Test>::insert(company, 70);
+
+		assert!(Esg::score_history_of(company).is_empty());
+		assert_eq!(Esg::effective_score_of(company), 70);
+	});
+}
+
+#[test]
+fn it_must_finalize_the_median_once_the_quorum_is_reached() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE_2, false));
+
+		System::set_block_number(1);
+		upload_score(addr.NON_SUDO_ORACLE, 50);
+		// a single submission is below the quorum and is kept pending
+		assert_eq!(Esg::raw_score_of(company), 0);
+		assert_eq!(Esg::submissions_of(company), vec![(addr.NON_SUDO_ORACLE, 50)]);
+
+		// a resubmission replaces the earlier one
+		upload_score(addr.NON_SUDO_ORACLE, 60);
+		assert_eq!(Esg::submissions_of(company), vec![(addr.NON_SUDO_ORACLE, 60)]);
+
+		upload_score(addr.NON_SUDO_ORACLE_2, 70);
+		assert_eq!(Esg::raw_score_of(company), 65);
+		assert!(Esg::submissions_of(company).is_empty());
+		assert_eq!(Esg::score_history_of(company)[0].oracle, addr.NON_SUDO_ORACLE_2);
+		System::assert_has_event(
+			crate::Event::ScoreFinalized { company, score: 65, submissions: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn it_must_not_finalize_submissions_that_disagree() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE_2, false));
+
+		System::set_block_number(1);
+		upload_score(addr.NON_SUDO_ORACLE, 10);
+		upload_score(addr.NON_SUDO_ORACLE_2, 90);
+
+		// both submissions are 40 away from their median of 50, so neither counts
+		assert_eq!(Esg::raw_score_of(company), 0);
+		assert_eq!(
+			Esg::submissions_of(company),
+			vec![(addr.NON_SUDO_ORACLE, 10), (addr.NON_SUDO_ORACLE_2, 90)]
+		);
+		assert!(System::events().iter().all(|record| !matches!(
+			record.event,
+			RuntimeEvent::Esg(crate::Event::ScoreFinalized { .. })
+		)));
+	});
+}
+
+#[test]
+fn it_must_leave_outliers_out_of_the_median_and_flag_them() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		MaxNumOfNonSudoOracles::set(&3);
+		for oracle in [addr.NON_SUDO_ORACLE, addr.NON_SUDO_ORACLE_2, addr.NON_SUDO_ORACLE_6] {
+			assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), oracle, false));
+		}
+
+		System::set_block_number(1);
+		upload_score(addr.NON_SUDO_ORACLE, 10);
+		upload_score(addr.NON_SUDO_ORACLE_2, 90);
+		assert_eq!(Esg::raw_score_of(company), 0);
+
+		// 80 and 90 agree around the median of 80, which leaves 10 out
+		upload_score(addr.NON_SUDO_ORACLE_6, 80);
+		assert_eq!(Esg::raw_score_of(company), 85);
+		assert!(Esg::submissions_of(company).is_empty());
+		System::assert_has_event(
+			crate::Event::OutlierFlagged {
+				oracle: addr.NON_SUDO_ORACLE,
+				company,
+				score: 10,
+				median: 85,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			crate::Event::ScoreFinalized { company, score: 85, submissions: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn it_must_drop_submissions_of_deregistered_oracles() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE_2, false));
+
+		upload_score(addr.NON_SUDO_ORACLE, 40);
+		assert_ok!(Esg::deregister_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+
+		// the stale submission no longer counts towards the quorum
+		upload_score(addr.NON_SUDO_ORACLE_2, 80);
+		assert_eq!(Esg::raw_score_of(company), 0);
+		assert_eq!(Esg::submissions_of(company), vec![(addr.NON_SUDO_ORACLE_2, 80)]);
+	});
+}
+
+#[test]
+fn it_must_let_sudo_oracles_override_pending_submissions() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+
+		upload_score(addr.NON_SUDO_ORACLE, 40);
+		upload_score(addr.SUDO_ORACLE, 90);
+
+		assert_eq!(Esg::raw_score_of(company), 90);
+		assert!(Esg::submissions_of(company).is_empty());
+	});
+}
+
+#[test]
+fn it_must_upload_typed_scores() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let apple = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		let microsoft = hexstr2acc_id20("Ba08C49f377a4F01c65340F431B5C65D71B972a9");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			BoundedVec::try_from(vec![(apple, 42), (microsoft, 450)]).unwrap()
+		));
+
+		// scores above the maximum are capped
+		assert_eq!(Esg::raw_score_of(apple), 42);
+		assert_eq!(Esg::raw_score_of(microsoft), MAX_ESG_SCORE);
+		System::assert_last_event(crate::Event::ESGStored { caller: addr.SUDO_ORACLE }.into());
+	});
+}
+
+#[test]
+fn it_must_submit_typed_scores_of_non_sudo_oracles_towards_the_quorum() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.NON_SUDO_ORACLE),
+			BoundedVec::try_from(vec![(company, 42)]).unwrap()
+		));
+
+		assert_eq!(Esg::raw_score_of(company), 0);
+		assert_eq!(Esg::submissions_of(company), vec![(addr.NON_SUDO_ORACLE, 42)]);
+	});
+}
+
+#[test]
+fn it_must_not_allow_typed_upload_from_a_non_oracle() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+
+		assert_noop!(
+			Esg::upsert_esg_scores_typed(
+				RuntimeOrigin::signed(addr.ALICE),
+				BoundedVec::try_from(vec![(company, 42)]).unwrap()
+			),
+			Error::<Test>::CallerNotAnOracle
+		);
+	});
+}
+
+#[test]
+fn it_must_charge_json_uploads_by_input_length() {
+	let short = WeakBoundedVec::try_from(vec![b' '; 100]).unwrap();
+	let long = WeakBoundedVec::try_from(vec![b' '; 100_000]).unwrap();
+	let weight_of = |json_str_bytes| {
+		crate::Call::<Test>::upsert_esg_scores { json_str_bytes }
+			.get_dispatch_info()
+			.weight
+	};
+
+	assert!(weight_of(long.clone()).ref_time() > weight_of(short).ref_time());
+	// no more than `MaxBatch` entries are charged for, on top of every byte
+	assert_eq!(
+		weight_of(long),
+		<() as crate::weights::WeightInfo>::upsert_esg_scores(MaxBatch::get(), 100_000)
+	);
+}
+
+#[test]
+fn it_must_refund_json_uploads_down_to_their_entries() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		// a single entry padded out to the length of several
+		let data = format!(
+			r#"[{{"score":"50","account":"0x82a0EcfDd3174bEF5D5eA452e15219A52bf6161f"}}{}]"#,
+			" ".repeat(200)
+		);
+		let post_info = Esg::upsert_esg_scores(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			WeakBoundedVec::try_from(data.as_bytes().to_vec()).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as crate::weights::WeightInfo>::upsert_esg_scores(1, data.len() as u32))
+		);
+	});
+}
+
+#[test]
+fn it_must_not_upload_more_json_entries_than_the_batch_limit() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		let entry = r#"{"score":"50","account":"0x82a0EcfDd3174bEF5D5eA452e15219A52bf6161f"}"#;
+		let data = format!("[{}]", vec![entry; MaxBatch::get() as usize + 1].join(","));
+		assert_noop!(
+			Esg::upsert_esg_scores(
+				RuntimeOrigin::signed(addr.SUDO_ORACLE),
+				WeakBoundedVec::try_from(data.as_bytes().to_vec()).unwrap()
+			),
+			Error::<Test>::TooManyScores
+		);
+	});
+}
+
+#[test]
+fn it_must_bond_a_deposit_for_the_oracle_term() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		System::set_block_number(1);
+
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_eq!(Balances::reserved_balance(addr.NON_SUDO_ORACLE), ORACLE_DEPOSIT);
+		assert_eq!(
+			Esg::oracle_info_of(addr.NON_SUDO_ORACLE),
+			Some(crate::OracleInfo { deposit: ORACLE_DEPOSIT, expires_at: 1 + ORACLE_TERM })
+		);
+
+		assert_ok!(Esg::deregister_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_eq!(Balances::reserved_balance(addr.NON_SUDO_ORACLE), 0);
+		assert_eq!(Esg::oracle_info_of(addr.NON_SUDO_ORACLE), None);
+		assert!(crate::OracleExpiries::<Test>::get(1 + ORACLE_TERM).is_empty());
+	});
+}
+
+#[test]
+fn it_must_not_register_an_oracle_that_cannot_afford_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let poor = AccountId20::from([42u8; 20]);
+
+		assert_noop!(
+			Esg::register_an_oracle(RuntimeOrigin::root(), poor, false),
+			Error::<Test>::InsufficientOracleDeposit
+		);
+		assert!(!Esg::get_oracle_nsudo().contains(&poor));
+	});
+}
+
+#[test]
+fn it_must_let_sudo_oracles_deregister_non_sudo_oracles_only() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_2, true));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_3, true));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+
+		assert_noop!(
+			Esg::deregister_an_oracle(
+				RuntimeOrigin::signed(addr.SUDO_ORACLE_2),
+				addr.SUDO_ORACLE_3,
+				true
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Esg::deregister_an_oracle(
+				RuntimeOrigin::signed(addr.NON_SUDO_ORACLE),
+				addr.NON_SUDO_ORACLE,
+				false
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Esg::deregister_an_oracle(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE_2),
+			addr.NON_SUDO_ORACLE,
+			false
+		));
+		assert!(!Esg::get_oracle_nsudo().contains(&addr.NON_SUDO_ORACLE));
+		assert_eq!(Balances::reserved_balance(addr.NON_SUDO_ORACLE), 0);
+	});
+}
+
+#[test]
+fn it_must_slash_the_deposit_of_an_oracle() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+
+		System::set_block_number(1);
+		assert_noop!(
+			Esg::slash_oracle(RuntimeOrigin::signed(addr.SUDO_ORACLE), addr.NON_SUDO_ORACLE, 40),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Esg::slash_oracle(RuntimeOrigin::root(), addr.ALICE, 40),
+			Error::<Test>::OracleNotExist
+		);
+
+		assert_ok!(Esg::slash_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, 40));
+		assert_eq!(Balances::reserved_balance(addr.NON_SUDO_ORACLE), ORACLE_DEPOSIT - 40);
+		assert_eq!(Balances::free_balance(addr.NON_SUDO_ORACLE), 1000 - ORACLE_DEPOSIT);
+		System::assert_last_event(
+			crate::Event::OracleSlashed { oracle: addr.NON_SUDO_ORACLE, amount: 40 }.into(),
+		);
+
+		// the slash is capped at what is left of the deposit
+		assert_ok!(Esg::slash_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, 1000));
+		assert_eq!(Balances::reserved_balance(addr.NON_SUDO_ORACLE), 0);
+		assert_eq!(Esg::oracle_info_of(addr.NON_SUDO_ORACLE).unwrap().deposit, 0);
+
+		// only the remaining deposit is returned on deregistration
+		assert_ok!(Esg::deregister_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_eq!(Balances::free_balance(addr.NON_SUDO_ORACLE), 1000 - ORACLE_DEPOSIT);
+	});
+}
+
+#[test]
+fn it_must_remove_oracles_at_the_end_of_their_term() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		System::set_block_number(1);
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_2, true));
+		System::set_block_number(2);
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+
+		Esg::on_initialize(1 + ORACLE_TERM);
+		assert!(!Esg::get_oracle_sudo().contains(&addr.SUDO_ORACLE_2));
+		assert!(Esg::get_oracle_nsudo().contains(&addr.NON_SUDO_ORACLE));
+		assert_eq!(Balances::reserved_balance(addr.SUDO_ORACLE_2), 0);
+		System::assert_last_event(
+			crate::Event::OracleExpired { is_sudo: true, oracle: addr.SUDO_ORACLE_2 }.into(),
+		);
+
+		// an oracle registered again starts a new term
+		System::set_block_number(3);
+		assert_ok!(Esg::deregister_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		Esg::on_initialize(2 + ORACLE_TERM);
+		assert!(Esg::get_oracle_nsudo().contains(&addr.NON_SUDO_ORACLE));
+		Esg::on_initialize(3 + ORACLE_TERM);
+		assert!(!Esg::get_oracle_nsudo().contains(&addr.NON_SUDO_ORACLE));
+	});
+}
+
+#[test]
+fn it_must_bond_the_oracles_registered_before_deposits() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let poor = AccountId20::from([42u8; 20]);
+		StorageVersion::new(0).put::<Esg>();
+		crate::SudoOraclesStore::<Test>::put(vec![addr.SUDO_ORACLE]);
+		crate::NonSudoOraclesStore::<Test>::put(vec![addr.NON_SUDO_ORACLE, poor]);
+		System::set_block_number(5);
+
+		crate::migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Esg::on_chain_storage_version(), 1);
+		for oracle in [addr.SUDO_ORACLE, addr.NON_SUDO_ORACLE] {
+			assert_eq!(Balances::reserved_balance(oracle), ORACLE_DEPOSIT);
+			assert_eq!(
+				Esg::oracle_info_of(oracle),
+				Some(crate::OracleInfo { deposit: ORACLE_DEPOSIT, expires_at: 5 + ORACLE_TERM })
+			);
+		}
+		// an oracle that cannot afford the deposit stays registered with an empty one
+		assert_eq!(Esg::oracle_info_of(poor).unwrap().deposit, 0);
+		assert_eq!(crate::OracleExpiries::<Test>::get(5 + ORACLE_TERM).len(), 3);
+	});
+}
+
+#[test]
+fn it_must_seed_the_history_of_scores_stored_before_it_was_kept() {
+	new_test_ext().execute_with(|| {
+		let legacy = AccountId20::from([100u8; 20]);
+		let company = AccountId20::from([101u8; 20]);
+		let addr = Addr::default();
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+		System::set_block_number(1);
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			BoundedVec::try_from(vec![(company, 50)]).unwrap()
+		));
+		StorageVersion::new(1).put::<Esg>();
+		crate::ESGScoresMap::<Test>::insert(legacy, 80);
+		System::set_block_number(5);
+
+		crate::migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Esg::on_chain_storage_version(), 2);
+		let zero = AccountId20::from([0u8; 20]);
+		assert_eq!(
+			Esg::score_history_of(legacy).into_inner(),
+			vec![crate::ScoreRecord { score: 80, block: 5, oracle: zero }]
+		);
+		// a history that is already kept is left alone
+		assert_eq!(Esg::score_history_of(company)[0].block, 1);
+
+		// the legacy score can be disputed, and decays and goes stale like any other
+		assert_ok!(Esg::dispute_score(
+			RuntimeOrigin::signed(addr.ALICE),
+			legacy,
+			sp_core::H256::repeat_byte(1)
+		));
+		assert_ok!(Esg::resolve_dispute(RuntimeOrigin::root(), legacy, false));
+		System::set_block_number(5 + SCORE_VALIDITY + SCORE_DECAY_PERIOD / 2);
+		assert_eq!(Esg::effective_score_of(legacy), 40);
+		let now = 5 + SCORE_VALIDITY + SCORE_DECAY_PERIOD;
+		System::set_block_number(now);
+		Esg::on_idle(now, Weight::MAX);
+		assert_eq!(Esg::raw_score_of(legacy), 0);
+		System::assert_has_event(crate::Event::ScoreStale { company: legacy }.into());
+	});
+}
+
+#[test]
+fn it_must_decay_outdated_scores_linearly() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		upload_score(addr.SUDO_ORACLE, 80);
+
+		System::set_block_number(1 + SCORE_VALIDITY);
+		assert_eq!(Esg::effective_score_of(company), 80);
+
+		// halfway through the decay period
+		System::set_block_number(1 + SCORE_VALIDITY + SCORE_DECAY_PERIOD / 2);
+		assert_eq!(Esg::effective_score_of(company), 40);
+
+		System::set_block_number(1 + SCORE_VALIDITY + SCORE_DECAY_PERIOD);
+		assert_eq!(Esg::effective_score_of(company), 0);
+
+		// a new upload lifts the decay, and the average is back to the earlier score that has
+		// been in effect ever since it was uploaded
+		upload_score(addr.SUDO_ORACLE, 60);
+		assert_eq!(Esg::effective_score_of(company), 80);
+	});
+}
+
+#[test]
+fn it_must_sweep_stale_scores_when_idle() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let stale = AccountId20::from([100u8; 20]);
+		let fresh = AccountId20::from([101u8; 20]);
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			BoundedVec::try_from(vec![(stale, 50)]).unwrap()
+		));
+		System::set_block_number(2);
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			BoundedVec::try_from(vec![(fresh, 50)]).unwrap()
+		));
+
+		let now = 1 + SCORE_VALIDITY + SCORE_DECAY_PERIOD;
+		System::set_block_number(now);
+		Esg::on_idle(now, Weight::MAX);
+
+		assert_eq!(Esg::raw_score_of(stale), 0);
+		assert!(Esg::score_history_of(stale).is_empty());
+		assert_eq!(Esg::raw_score_of(fresh), 50);
+		System::assert_has_event(crate::Event::ScoreStale { company: stale }.into());
+		assert_eq!(crate::StaleSweepCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn it_must_resume_the_stale_sweep_where_it_stopped() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let companies: Vec<_> = (100u8..103).map(|i| AccountId20::from([i; 20])).collect();
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			BoundedVec::try_from(companies.iter().map(|c| (*c, 50)).collect::<Vec<_>>()).unwrap()
+		));
+
+		let now = 1 + SCORE_VALIDITY + SCORE_DECAY_PERIOD;
+		System::set_block_number(now);
+
+		// enough weight to check a single score per block
+		let db_weight: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+		let weight = <() as crate::weights::WeightInfo>::sweep_stale_score()
+			.saturating_add(db_weight.reads_writes(1, 1));
+		assert_eq!(
+			Esg::on_idle(now, weight.saturating_sub(Weight::from_parts(1, 0))),
+			Weight::zero()
+		);
+
+		for swept in 1..=companies.len() {
+			assert_eq!(Esg::on_idle(now, weight), weight);
+			assert_eq!(crate::ESGScoreHistory::<Test>::iter().count(), companies.len() - swept);
+			assert!(crate::StaleSweepCursor::<Test>::get().is_some());
+		}
+
+		// the next sweep finds nothing left and starts over
+		Esg::on_idle(now, weight);
+		assert_eq!(crate::StaleSweepCursor::<Test>::get(), None);
+		for company in companies {
+			System::assert_has_event(crate::Event::ScoreStale { company }.into());
+		}
+	});
+}
+
+const EVIDENCE_CID: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+fn upload_breakdown(oracle: AccountId20, environmental: u16, social: u16, governance: u16) {
+	let data = format!(
+		r#"[{{"environmental":"{}","social":"{}","governance":"{}","methodology":"GRI-2021","evidence":"{}","account":"0x82a0EcfDd3174bEF5D5eA452e15219A52bf6161f"}}]"#,
+		environmental, social, governance, EVIDENCE_CID
+	);
+	assert_ok!(Esg::upsert_esg_scores(
+		RuntimeOrigin::signed(oracle),
+		(WeakBoundedVec::try_from(data.as_bytes().to_vec())).unwrap()
+	));
+}
+
+#[test]
+fn it_must_store_the_breakdown_and_its_weighted_composite() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		System::set_block_number(1);
+		upload_breakdown(addr.SUDO_ORACLE, 80, 40, 150);
+
+		// environmental weighs twice as much, and sub-scores are capped like scores
+		assert_eq!(Esg::raw_score_of(company), (2 * 80 + 40 + 100) / 4);
+		assert_eq!(
+			Esg::breakdown_of(company),
+			Some(crate::EsgBreakdown {
+				environmental: 80,
+				social: 40,
+				governance: 100,
+				methodology: BoundedVec::truncate_from(b"GRI-2021".to_vec()),
+				evidence: Some(BoundedVec::truncate_from(EVIDENCE_CID.as_bytes().to_vec())),
+			})
+		);
+		assert_eq!(
+			<Esg as crate::traits::ERScoresTrait<AccountId20>>::get_score_of(company),
+			Esg::raw_score_of(company)
+		);
+
+		// a plain score leaves the earlier breakdown alone
+		upload_score(addr.SUDO_ORACLE, 30);
+		assert_eq!(Esg::raw_score_of(company), 30);
+		assert_eq!(Esg::breakdown_of(company).map(|breakdown| breakdown.environmental), Some(80));
+		assert_ok!(Esg::upsert_esg_scores_typed(
+			RuntimeOrigin::signed(addr.SUDO_ORACLE),
+			BoundedVec::try_from(vec![(company, 40)]).unwrap()
+		));
+		assert_eq!(Esg::raw_score_of(company), 40);
+		assert_eq!(Esg::breakdown_of(company).map(|breakdown| breakdown.environmental), Some(80));
+	});
+}
+
+#[test]
+fn it_must_skip_entries_with_malformed_evidence() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		let other = hexstr2acc_id20("7Bd6Bc8A4A0E5A3A6a1f4B6D0bC0fD0aB1C2d3E4");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE, true));
+
+		let too_long = format!(r#""{}""#, "a".repeat(crate::MAX_EVIDENCE_LEN as usize + 1));
+		for evidence in [r#""""#, "42", r#"["bafy"]"#, too_long.as_str()] {
+			let data = format!(
+				r#"[{{"environmental":"50","social":"50","governance":"50","evidence":{},"account":"0x82a0EcfDd3174bEF5D5eA452e15219A52bf6161f"}},{{"score":"70","account":"0x7Bd6Bc8A4A0E5A3A6a1f4B6D0bC0fD0aB1C2d3E4"}}]"#,
+				evidence
+			);
+			assert_ok!(Esg::upsert_esg_scores(
+				RuntimeOrigin::signed(addr.SUDO_ORACLE),
+				WeakBoundedVec::try_from(data.as_bytes().to_vec()).unwrap()
+			));
+			System::assert_last_event(RuntimeEvent::Esg(crate::Event::ESGStoredWithSkip {
+				skipped_indeces: vec![0],
+				caller: addr.SUDO_ORACLE,
+			}));
+		}
+		assert_eq!(Esg::raw_score_of(company), 0);
+		assert_eq!(Esg::breakdown_of(company), None);
+		assert_eq!(Esg::raw_score_of(other), 70);
+	});
+}
+
+#[test]
+fn it_must_finalize_the_breakdown_with_the_quorum() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE, false));
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.NON_SUDO_ORACLE_2, false));
+
+		System::set_block_number(1);
+		upload_breakdown(addr.NON_SUDO_ORACLE, 60, 60, 60);
+		assert_eq!(Esg::breakdown_of(company), None);
+		assert!(crate::SubmittedBreakdowns::<Test>::contains_key(company, addr.NON_SUDO_ORACLE));
+
+		upload_breakdown(addr.NON_SUDO_ORACLE_2, 60, 60, 60);
+		assert_eq!(Esg::raw_score_of(company), 60);
+		assert_eq!(Esg::breakdown_of(company).map(|breakdown| breakdown.environmental), Some(60));
+		assert_eq!(crate::SubmittedBreakdowns::<Test>::iter_prefix(company).count(), 0);
+	});
+}
+
+#[test]
+fn it_must_average_the_pillars_evenly_without_weights() {
+	let breakdown = crate::EsgBreakdown {
+		environmental: 90,
+		social: 60,
+		governance: 30,
+		methodology: Default::default(),
+		evidence: None,
+	};
+	let unweighted = crate::PillarWeights { environmental: 0, social: 0, governance: 0 };
+	assert_eq!(unweighted.composite(&breakdown), 60);
+	let governance_only = crate::PillarWeights { environmental: 0, social: 0, governance: 1 };
+	assert_eq!(governance_only.composite(&breakdown), 30);
+}
+
+#[test]
+fn it_must_freeze_a_disputed_score_and_restore_the_previous_one() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		let evidence = sp_core::H256::repeat_byte(1);
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_2, true));
+
+		System::set_block_number(1);
+		upload_score(addr.SUDO_ORACLE_2, 40);
+		System::set_block_number(2);
+		upload_score(addr.SUDO_ORACLE_2, 80);
+		System::set_block_number(3);
+		assert_eq!(Esg::effective_score_of(company), 60);
+
+		assert_ok!(Esg::dispute_score(RuntimeOrigin::signed(addr.ALICE), company, evidence));
+		System::assert_last_event(
+			crate::Event::ScoreDisputed { company, disputer: addr.ALICE, evidence }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(addr.ALICE), DISPUTE_DEPOSIT);
+		// the disputed score has no effect while the dispute is pending
+		assert_eq!(<Esg as crate::traits::ERScoresTrait<AccountId20>>::get_score_of(company), 40);
+
+		assert_ok!(Esg::resolve_dispute(RuntimeOrigin::root(), company, true));
+		System::assert_last_event(crate::Event::DisputeResolved { company, upheld: true }.into());
+		assert_eq!(Esg::raw_score_of(company), 40);
+		assert_eq!(Esg::score_history_of(company).len(), 1);
+		assert_eq!(Esg::dispute_of(company), None);
+		// the disputer is refunded and the oracle pays for the wrong score
+		assert_eq!(Balances::reserved_balance(addr.ALICE), 0);
+		assert_eq!(Balances::free_balance(addr.ALICE), 1000);
+		assert_eq!(
+			Balances::reserved_balance(addr.SUDO_ORACLE_2),
+			ORACLE_DEPOSIT - DISPUTE_DEPOSIT
+		);
+	});
+}
+
+#[test]
+fn it_must_confirm_a_disputed_score_and_slash_the_disputer() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_2, true));
+
+		System::set_block_number(1);
+		upload_score(addr.SUDO_ORACLE_2, 80);
+		assert_ok!(Esg::dispute_score(
+			RuntimeOrigin::signed(addr.ALICE),
+			company,
+			Default::default()
+		));
+		// without a previous score, nothing counts until the dispute is resolved
+		assert_eq!(Esg::effective_score_of(company), 0);
+
+		assert_ok!(Esg::resolve_dispute(RuntimeOrigin::root(), company, false));
+		assert_eq!(Esg::effective_score_of(company), 80);
+		assert_eq!(Balances::reserved_balance(addr.ALICE), 0);
+		assert_eq!(Balances::free_balance(addr.ALICE), 1000 - DISPUTE_DEPOSIT);
+		assert_eq!(Balances::reserved_balance(addr.SUDO_ORACLE_2), ORACLE_DEPOSIT);
+	});
+}
+
+#[test]
+fn it_must_reject_invalid_disputes() {
+	new_test_ext().execute_with(|| {
+		let addr = Addr::default();
+		let company = hexstr2acc_id20("82a0EcfDd3174bEF5D5eA452e15219A52bf6161f");
+		let poor = AccountId20::from([42u8; 20]);
+		assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), addr.SUDO_ORACLE_2, true));
+
+		assert_noop!(
+			Esg::dispute_score(RuntimeOrigin::signed(addr.ALICE), company, Default::default()),
+			Error::<Test>::NoScoreToDispute
+		);
+
+		System::set_block_number(1);
+		upload_score(addr.SUDO_ORACLE_2, 80);
+		assert_noop!(
+			Esg::dispute_score(RuntimeOrigin::signed(poor), company, Default::default()),
+			Error::<Test>::InsufficientDisputeDeposit
+		);
+		assert_noop!(
+			Esg::resolve_dispute(RuntimeOrigin::root(), company, true),
+			Error::<Test>::DisputeNotFound
+		);
+
+		assert_ok!(Esg::dispute_score(
+			RuntimeOrigin::signed(addr.ALICE),
+			company,
+			Default::default()
+		));
+		assert_noop!(
+			Esg::dispute_score(RuntimeOrigin::signed(addr.ALICE), company, Default::default()),
+			Error::<Test>::DisputePending
+		);
+		assert_noop!(
+			Esg::resolve_dispute(RuntimeOrigin::signed(addr.ALICE), company, true),
+			DispatchError::BadOrigin
+		);
+	});
+}
@@ -1,28 +1,15 @@
 
-//! Autogenerated weights for `pallet_esg`
+//! Provisional weights for `pallet_esg`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-08-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `antkaraghatr588`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("qa-dev")`, DB CACHE: 1024
+//! NOT GENERATED WITH THE `benchmark pallet` CLI: the regression of the pallet benchmarks was
+//! run natively against the mock runtime, not against a real runtime on reference hardware.
+//! Regenerate with `benchmark pallet` before a release.
+//! DATE: 2026-10-18, STEPS: `6`, REPEAT: `20`
 
-// Executed Command:
-// ./target/release/firechain-node
-// benchmark
-// pallet
-// --chain
-// qa-dev
-// --pallet
-// pallet_esg
-// --extrinsic
-// *
-// --steps
-// 50
-// --repeat
-// 20
-// --output
-// ./frame/esg/src/weights.rs
+// Measured natively against the mock runtime of the pallet, with the worst case configuration
+// of the runtimes (`MaxBatch` 1000, `MaxFileSize` 524288, `MaxNumOfNonSudoOracles` 100,
+// `MaxScoreHistory` 32), 20 repeats per step (10 for `upsert_esg_scores_typed` and
+// `expire_oracles`, 5 for `upsert_esg_scores`).
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -34,7 +21,13 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn register_an_oracle() -> Weight;
 	fn deregister_an_oracle() -> Weight;
-	fn upsert_esg_scores() -> Weight;
+	fn upsert_esg_scores(n: u32, l: u32, ) -> Weight;
+	fn upsert_esg_scores_typed(n: u32, ) -> Weight;
+	fn slash_oracle() -> Weight;
+	fn expire_oracles(n: u32, ) -> Weight;
+	fn sweep_stale_score() -> Weight;
+	fn dispute_score() -> Weight;
+	fn resolve_dispute() -> Weight;
 }
 
 /// Weight functions for `pallet_esg`.
@@ -44,42 +37,187 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeightInfo<T> {
 	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:0)
 	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleExpiries` (r:1 w:1)
+	/// Proof: `EsgScore::OracleExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:0 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register_an_oracle() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2109`
-		//  Estimated: `3594`
-		// Minimum execution time: 17_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3594))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `2198`
+		//  Estimated: `5663`
+		// Minimum execution time: 32_281_000 picoseconds.
+		Weight::from_parts(38_160_000, 0)
+			.saturating_add(Weight::from_parts(0, 5663))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:1)
 	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:1 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleExpiries` (r:1 w:1)
+	/// Proof: `EsgScore::OracleExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn deregister_an_oracle() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
-		//  Estimated: `1723`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 1723))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `3117`
+		//  Estimated: `6582`
+		// Minimum execution time: 35_846_000 picoseconds.
+		Weight::from_parts(41_340_000, 0)
+			.saturating_add(Weight::from_parts(0, 6582))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:0)
 	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `EsgScore::ESGScoresMap` (r:2 w:2)
+	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:0)
+	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1000 w:1000)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ScoreSubmissions` (r:0 w:1000)
+	/// Proof: `EsgScore::ScoreSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1000)
 	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn upsert_esg_scores() -> Weight {
+	/// The range of component `n` is `[1, 1000]`.
+	/// The range of component `l` is `[0, 467272]`.
+	fn upsert_esg_scores(n: u32, l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
-		//  Estimated: `6178`
-		// Minimum execution time: 1_028_000_000 picoseconds.
-		Weight::from_parts(1_048_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 6178))
+		//  Measured:  `2183`
+		//  Estimated: `3668 + n * (2475 ±0)`
+		// Minimum execution time: 34_791_000 picoseconds.
+		Weight::from_parts(35_985_000, 0)
+			.saturating_add(Weight::from_parts(0, 3668))
+			// Standard Error: 302_705
+			.saturating_add(Weight::from_parts(12_337_199, 0).saturating_mul(n.into()))
+			// Standard Error: 649
+			.saturating_add(Weight::from_parts(8_034, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(n.into()))
+	}
+	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:0)
+	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:0)
+	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1000 w:1000)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ScoreSubmissions` (r:0 w:1000)
+	/// Proof: `EsgScore::ScoreSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1000)
+	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn upsert_esg_scores_typed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2183`
+		//  Estimated: `3668 + n * (2475 ±0)`
+		// Minimum execution time: 20_880_000 picoseconds.
+		Weight::from_parts(26_202_000, 0)
+			.saturating_add(Weight::from_parts(0, 3668))
+			// Standard Error: 215_789
+			.saturating_add(Weight::from_parts(11_508_752, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(n.into()))
+	}
+	/// Storage: `EsgScore::OracleInfoOf` (r:1 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn slash_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `3620`
+		// Minimum execution time: 19_329_000 picoseconds.
+		Weight::from_parts(20_793_000, 0)
+			.saturating_add(Weight::from_parts(0, 3620))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EsgScore::OracleExpiries` (r:1 w:1)
+	/// Proof: `EsgScore::OracleExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:1)
+	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:106 w:105)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:105 w:105)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:1)
+	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 105]`.
+	fn expire_oracles(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `21 + n * (233 ±0)`
+		//  Estimated: `3486 + n * (2708 ±0)`
+		// Minimum execution time: 4_177_000 picoseconds.
+		Weight::from_parts(40_667_404, 0)
+			.saturating_add(Weight::from_parts(0, 3486))
+			// Standard Error: 200_769
+			.saturating_add(Weight::from_parts(15_269_195, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2708).saturating_mul(n.into()))
+	}
+	/// Storage: `EsgScore::StaleSweepCursor` (r:1 w:1)
+	/// Proof: `EsgScore::StaleSweepCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:2 w:1)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGBreakdownOf` (r:0 w:1)
+	/// Proof: `EsgScore::ESGBreakdownOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1)
+	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn sweep_stale_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `6049`
+		// Minimum execution time: 14_185_000 picoseconds.
+		Weight::from_parts(15_774_000, 0)
+			.saturating_add(Weight::from_parts(0, 6049))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `EsgScore::Disputes` (r:1 w:1)
+	/// Proof: `EsgScore::Disputes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1 w:0)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	fn dispute_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `408`
+		//  Estimated: `3873`
+		// Minimum execution time: 19_941_000 picoseconds.
+		Weight::from_parts(21_751_000, 0)
+			.saturating_add(Weight::from_parts(0, 3873))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `EsgScore::Disputes` (r:1 w:1)
+	/// Proof: `EsgScore::Disputes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1 w:1)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:1 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `EsgScore::ESGBreakdownOf` (r:0 w:1)
+	/// Proof: `EsgScore::ESGBreakdownOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1)
+	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `665`
+		//  Estimated: `4130`
+		// Minimum execution time: 37_610_000 picoseconds.
+		Weight::from_parts(41_890_000, 0)
+			.saturating_add(Weight::from_parts(0, 4130))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
 }
 
 
@@ -89,40 +227,185 @@ impl WeightInfo for () {
 	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:0)
 	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleExpiries` (r:1 w:1)
+	/// Proof: `EsgScore::OracleExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:0 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn register_an_oracle() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2109`
-		//  Estimated: `3594`
-		// Minimum execution time: 17_000_000 picoseconds.
-		Weight::from_parts(18_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3594))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `2198`
+		//  Estimated: `5663`
+		// Minimum execution time: 32_281_000 picoseconds.
+		Weight::from_parts(38_160_000, 0)
+			.saturating_add(Weight::from_parts(0, 5663))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:1)
 	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:1 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleExpiries` (r:1 w:1)
+	/// Proof: `EsgScore::OracleExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn deregister_an_oracle() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
-		//  Estimated: `1723`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 1723))
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `3117`
+		//  Estimated: `6582`
+		// Minimum execution time: 35_846_000 picoseconds.
+		Weight::from_parts(41_340_000, 0)
+			.saturating_add(Weight::from_parts(0, 6582))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:0)
+	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:0)
+	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1000 w:1000)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ScoreSubmissions` (r:0 w:1000)
+	/// Proof: `EsgScore::ScoreSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1000)
+	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	/// The range of component `l` is `[0, 467272]`.
+	fn upsert_esg_scores(n: u32, l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2183`
+		//  Estimated: `3668 + n * (2475 ±0)`
+		// Minimum execution time: 34_791_000 picoseconds.
+		Weight::from_parts(35_985_000, 0)
+			.saturating_add(Weight::from_parts(0, 3668))
+			// Standard Error: 302_705
+			.saturating_add(Weight::from_parts(12_337_199, 0).saturating_mul(n.into()))
+			// Standard Error: 649
+			.saturating_add(Weight::from_parts(8_034, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(n.into()))
 	}
 	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:0)
 	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `EsgScore::ESGScoresMap` (r:2 w:2)
+	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:0)
+	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1000 w:1000)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ScoreSubmissions` (r:0 w:1000)
+	/// Proof: `EsgScore::ScoreSubmissions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1000)
+	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn upsert_esg_scores_typed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2183`
+		//  Estimated: `3668 + n * (2475 ±0)`
+		// Minimum execution time: 20_880_000 picoseconds.
+		Weight::from_parts(26_202_000, 0)
+			.saturating_add(Weight::from_parts(0, 3668))
+			// Standard Error: 215_789
+			.saturating_add(Weight::from_parts(11_508_752, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2475).saturating_mul(n.into()))
+	}
+	/// Storage: `EsgScore::OracleInfoOf` (r:1 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn slash_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `155`
+		//  Estimated: `3620`
+		// Minimum execution time: 19_329_000 picoseconds.
+		Weight::from_parts(20_793_000, 0)
+			.saturating_add(Weight::from_parts(0, 3620))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `EsgScore::OracleExpiries` (r:1 w:1)
+	/// Proof: `EsgScore::OracleExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::SudoOraclesStore` (r:1 w:1)
+	/// Proof: `EsgScore::SudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:106 w:105)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:105 w:105)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `EsgScore::NonSudoOraclesStore` (r:1 w:1)
+	/// Proof: `EsgScore::NonSudoOraclesStore` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 105]`.
+	fn expire_oracles(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `21 + n * (233 ±0)`
+		//  Estimated: `3486 + n * (2708 ±0)`
+		// Minimum execution time: 4_177_000 picoseconds.
+		Weight::from_parts(40_667_404, 0)
+			.saturating_add(Weight::from_parts(0, 3486))
+			// Standard Error: 200_769
+			.saturating_add(Weight::from_parts(15_269_195, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2708).saturating_mul(n.into()))
+	}
+	/// Storage: `EsgScore::StaleSweepCursor` (r:1 w:1)
+	/// Proof: `EsgScore::StaleSweepCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:2 w:1)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGBreakdownOf` (r:0 w:1)
+	/// Proof: `EsgScore::ESGBreakdownOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1)
 	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn upsert_esg_scores() -> Weight {
+	fn sweep_stale_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `6049`
+		// Minimum execution time: 14_185_000 picoseconds.
+		Weight::from_parts(15_774_000, 0)
+			.saturating_add(Weight::from_parts(0, 6049))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `EsgScore::Disputes` (r:1 w:1)
+	/// Proof: `EsgScore::Disputes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1 w:0)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	fn dispute_score() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `238`
-		//  Estimated: `6178`
-		// Minimum execution time: 1_028_000_000 picoseconds.
-		Weight::from_parts(1_048_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 6178))
+		//  Measured:  `408`
+		//  Estimated: `3873`
+		// Minimum execution time: 19_941_000 picoseconds.
+		Weight::from_parts(21_751_000, 0)
+			.saturating_add(Weight::from_parts(0, 3873))
 			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
-}
\ No newline at end of file
+	/// Storage: `EsgScore::Disputes` (r:1 w:1)
+	/// Proof: `EsgScore::Disputes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoreHistory` (r:1 w:1)
+	/// Proof: `EsgScore::ESGScoreHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+	/// Storage: `EsgScore::OracleInfoOf` (r:1 w:1)
+	/// Proof: `EsgScore::OracleInfoOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `EsgScore::ESGBreakdownOf` (r:0 w:1)
+	/// Proof: `EsgScore::ESGBreakdownOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EsgScore::ESGScoresMap` (r:0 w:1)
+	/// Proof: `EsgScore::ESGScoresMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `665`
+		//  Estimated: `4130`
+		// Minimum execution time: 37_610_000 picoseconds.
+		Weight::from_parts(41_890_000, 0)
+			.saturating_add(Weight::from_parts(0, 4130))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+}
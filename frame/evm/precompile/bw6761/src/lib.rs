@@ -30,8 +30,16 @@ use fp_evm::{
 };
 
 /// Gas discount table for BW6-761 G1 and G2 multi exponentiation operations.
-// TODO::to be estimated
-const BW6761_MULTIEXP_DISCOUNT_TABLE: [u16; 128] = [0u16; 128];
+/// https://eips.ethereum.org/EIPS/eip-3026#discount-table
+const BW6761_MULTIEXP_DISCOUNT_TABLE: [u16; 128] = [
+	1266, 733, 561, 474, 422, 387, 362, 344, 329, 318, 308, 300, 296, 289, 283, 279, 275, 272, 269,
+	266, 265, 260, 259, 256, 255, 254, 252, 251, 250, 249, 249, 220, 228, 225, 223, 219, 216, 214,
+	212, 209, 209, 205, 203, 202, 200, 198, 196, 199, 195, 192, 192, 191, 190, 187, 186, 185, 184,
+	184, 181, 181, 181, 180, 178, 179, 176, 177, 176, 175, 174, 173, 171, 171, 170, 170, 169, 168,
+	168, 167, 167, 166, 165, 167, 166, 166, 165, 165, 164, 164, 163, 163, 162, 162, 160, 163, 159,
+	162, 159, 160, 159, 159, 158, 158, 158, 158, 157, 157, 156, 155, 155, 156, 155, 155, 154, 155,
+	154, 153, 153, 153, 152, 152, 152, 152, 151, 151, 151, 151, 151, 150,
+];
 
 /// Encode Fq as `96` bytes by performing Big-Endian encoding of the corresponding (unsigned)
 /// integer.
@@ -178,8 +186,8 @@ fn decode_g2(input: &[u8], offset: usize) -> Result<G2Projective, PrecompileFail
 pub struct Bw6761G1Add;
 
 impl Bw6761G1Add {
-	// TODO::to be estimated
-	const GAS_COST: u64 = 0;
+	/// https://eips.ethereum.org/EIPS/eip-3026#g1-addition
+	const GAS_COST: u64 = 180;
 }
 
 impl Precompile for Bw6761G1Add {
@@ -214,8 +222,8 @@ impl Precompile for Bw6761G1Add {
 pub struct Bw6761G1Mul;
 
 impl Bw6761G1Mul {
-	// TODO::to be estimated
-	const GAS_COST: u64 = 0;
+	/// https://eips.ethereum.org/EIPS/eip-3026#g1-multiplication
+	const GAS_COST: u64 = 64_000;
 }
 
 impl Precompile for Bw6761G1Mul {
@@ -251,7 +259,6 @@ impl Precompile for Bw6761G1Mul {
 pub struct Bw6761G1MultiExp;
 
 impl Bw6761G1MultiExp {
-	// TODO::to be estimated
 	const MULTIPLIER: u64 = 1_000;
 
 	/// Returns the gas required to execute the pre-compiled contract.
@@ -320,8 +327,8 @@ impl Precompile for Bw6761G1MultiExp {
 pub struct Bw6761G2Add;
 
 impl Bw6761G2Add {
-	// TODO::to be estimated
-	const GAS_COST: u64 = 0;
+	/// https://eips.ethereum.org/EIPS/eip-3026#g2-addition
+	const GAS_COST: u64 = 180;
 }
 
 impl Precompile for Bw6761G2Add {
@@ -356,8 +363,8 @@ impl Precompile for Bw6761G2Add {
 pub struct Bw6761G2Mul;
 
 impl Bw6761G2Mul {
-	// TODO::to be estimated
-	const GAS_COST: u64 = 0;
+	/// https://eips.ethereum.org/EIPS/eip-3026#g2-multiplication
+	const GAS_COST: u64 = 64_000;
 }
 
 impl Precompile for Bw6761G2Mul {
@@ -393,7 +400,6 @@ impl Precompile for Bw6761G2Mul {
 pub struct Bw6761G2MultiExp;
 
 impl Bw6761G2MultiExp {
-	// TODO::to be estimated
 	const MULTIPLIER: u64 = 1_000;
 
 	/// Returns the gas required to execute the pre-compiled contract.
@@ -463,9 +469,9 @@ impl Precompile for Bw6761G2MultiExp {
 pub struct Bw6761Pairing;
 
 impl Bw6761Pairing {
-	// TODO::to be estimated
-	const BASE_GAS: u64 = 0;
-	const PER_PAIR_GAS: u64 = 0;
+	/// https://eips.ethereum.org/EIPS/eip-3026#pairing-operation
+	const BASE_GAS: u64 = 320_000;
+	const PER_PAIR_GAS: u64 = 120_000;
 }
 
 impl Precompile for Bw6761Pairing {
@@ -214,12 +214,34 @@ parameter_types! {
 	pub static ElectionsBoundsOnChain: ElectionBounds = ElectionBoundsBuilder::default().build();
 }
 
+parameter_types! {
+	pub const EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 1, social: 1, governance: 1 };
+}
+
 impl pallet_esg::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxFileSize = ConstU32<1024000>;
 	type WeightInfo = ();
 	type MaxNumOfSudoOracles = ConstU32<5>;
 	type MaxNumOfNonSudoOracles = ConstU32<5>;
+	type MaxScoreHistory = ConstU32<16>;
+	type MaxBatch = ConstU32<100>;
+	type SubmissionQuorum = ConstU32<1>;
+	type OutlierThreshold = frame_support::traits::ConstU16<20>;
+	type ScoreValidity = ConstU64<{ u64::MAX }>;
+	type ScoreDecayPeriod = ConstU64<0>;
+	type Currency = Balances;
+	type OracleDeposit = frame_support::traits::ConstU128<0>;
+	type OracleTerm = ConstU64<{ u64::MAX }>;
+	type SlashOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Slash = ();
+	type DisputeDeposit = frame_support::traits::ConstU128<0>;
+	type DisputeResolveOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
 }
 
 pub struct OnChainSeqPhragmen;
@@ -0,0 +1,24 @@
+//! Runtime API definition for the reliability scores of the im-online pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_im_online::{ReliabilityState, SessionRecord};
+
+sp_api::decl_runtime_apis! {
+	pub trait ReliabilityApi<AccountId>
+		where
+			AccountId: Codec,
+	{
+		/// Returns the outcome of the last sessions of a validator, oldest first.
+		fn reliability_history(account: AccountId) -> Vec<SessionRecord>;
+
+		/// Returns the current reliability score of a validator.
+		fn reliability_score(account: AccountId) -> u16;
+
+		/// Returns the uptime record behind the reliability score of a validator, if it has one.
+		fn reliability_state(account: AccountId) -> Option<ReliabilityState>;
+	}
+}
@@ -93,5 +93,33 @@ benchmarks! {
 			.dispatch_bypass_filter(RawOrigin::None.into())?;
 	}
 
+	score_validator {
+		let validator: ValidatorId<T> = Decode::decode(&mut TrailingZeroInput::zeroes())
+			.expect("infinite input; qed");
+		ReliabilityStates::<T>::insert(&validator, ReliabilityState::default());
+		let history = vec![SessionRecord::default(); T::MaxReliabilityHistory::get() as usize];
+		ReliabilityHistory::<T>::insert(&validator, BoundedVec::truncate_from(history));
+		let page = vec![(validator.clone(), 0, true, true)];
+		PendingReliabilityUpdates::<T>::insert(0, BoundedVec::truncate_from(page));
+	}: {
+		let (_, s_idx, online, heartbeat_received) = PendingReliabilityUpdates::<T>::take(0)
+			.and_then(|page| page.into_iter().next())
+			.expect("inserted above; qed");
+		// the authored blocks are only read for active validators, so they are counted here too
+		ImOnline::<T>::update_reliability_score_of(
+			&validator,
+			s_idx,
+			online,
+			heartbeat_received,
+			Some(1),
+		);
+	}
+	verify {
+		assert_eq!(
+			ImOnline::<T>::reliability_history_of(&validator).last().map(|r| r.session_index),
+			Some(0),
+		);
+	}
+
 	impl_benchmark_test_suite!(ImOnline, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
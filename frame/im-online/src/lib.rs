@@ -113,7 +113,10 @@ use sp_staking::{
 	offence::{DisableStrategy, Kind, Offence, ReportOffence},
 	SessionIndex,
 };
-use sp_std::prelude::*;
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	prelude::*,
+};
 pub use weights::WeightInfo;
 
 pub trait Reliability<ValidatorId> {
@@ -256,12 +259,43 @@ pub type IdentificationTuple<T> = (
 	>>::Identification,
 );
 
+/// Converts a validator id into its full identification.
+type IdentificationOf<T> = <<T as Config>::ValidatorSet as ValidatorSetWithIdentification<
+	<T as frame_system::Config>::AccountId,
+>>::IdentificationOf;
+
 type OffchainResult<T, A> = Result<A, OffchainErr<BlockNumberFor<T>>>;
 
 pub type RScoreType = u16;
-const ACTIVE_FACTOR: RScoreType = 1;
-const WAITING_FACTOR: RScoreType = 1;
 const REL_SCORE_MAX: RScoreType = 100;
+
+/// Uptime record of a validator, from which its reliability score is derived.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ReliabilityState {
+	/// Exponentially weighted share of the last sessions the validator was online in.
+	pub uptime: Perbill,
+	/// Number of consecutive sessions the validator has been offline in.
+	pub missed_streak: u32,
+	/// Number of online sessions left before the uptime gains of the validator recover fully.
+	pub recovering: u32,
+}
+
+/// Outcome of a single session for a validator, as kept in its reliability history.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct SessionRecord {
+	/// The session the record is for.
+	pub session_index: SessionIndex,
+	/// Whether a heartbeat of the validator was received in the session.
+	pub heartbeat: bool,
+	/// Number of blocks the validator authored in the session.
+	pub authored_blocks: u32,
+	/// Change of the reliability score of the validator at the end of the session.
+	pub score_delta: i16,
+}
 pub type ValidatorsListsTuple<T> = (
 	Vec<ValidatorId<T>>,
 	Vec<ValidatorId<T>>,
@@ -289,7 +323,7 @@ pub mod pallet {
 	impl<T: Config> RScoreTrait<T> for Pallet<T> {
 		fn get_and_reset_score_of(acid: &ValidatorId<T>) -> RScoreType {
 			let score = ReliabilityScoresMap::<T>::get(acid);
-			ReliabilityScoresMap::<T>::mutate(acid, |v| *v = 0);
+			Self::reset_reliability_score_of(acid);
 			score
 		}
 		fn peek_reliabilty_score_of(acid: &ValidatorId<T>) -> RScoreType {
@@ -360,6 +394,34 @@ pub mod pallet {
 		/// Bounds the number of targets, when calling into [`Config::DataProvider`]. It might be
 		/// overwritten in the `InstantElectionProvider` impl.
 		type TargetsBound: Get<u32>;
+
+		/// Weight of the last session in the exponentially weighted uptime of a validator.
+		#[pallet::constant]
+		type UptimeSmoothing: Get<Perbill>;
+
+		/// Share of its uptime a validator loses for each consecutive session it is offline in.
+		#[pallet::constant]
+		type MissedSessionPenalty: Get<Perbill>;
+
+		/// Share of the session uptime of an active validator that depends on the blocks it
+		/// authored, relative to the slots it was expected to author.
+		#[pallet::constant]
+		type AuthoringWeight: Get<Perbill>;
+
+		/// Number of online sessions over which the uptime gains of a validator ramp back up
+		/// after it was offline.
+		#[pallet::constant]
+		type RecoverySessions: Get<u32>;
+
+		/// Number of past sessions kept in the reliability history of a validator.
+		#[pallet::constant]
+		type MaxReliabilityHistory: Get<u32>;
+
+		/// Maximum number of waiting validators scored in a block. At the end of a session the
+		/// waiting validators beyond it, or all of them if some are still queued from a previous
+		/// session, are queued and scored in the following blocks.
+		#[pallet::constant]
+		type MaxWaitingScoresPerBlock: Get<u32>;
 	}
 
 	#[pallet::event]
@@ -401,6 +463,39 @@ pub mod pallet {
 	pub type ReliabilityScoresMap<T> =
 		StorageMap<_, Blake2_128Concat, ValidatorId<T>, RScoreType, ValueQuery>;
 
+	/// The uptime record behind the reliability score of each validator.
+	#[pallet::storage]
+	#[pallet::getter(fn reliability_state_of)]
+	pub type ReliabilityStates<T> =
+		StorageMap<_, Blake2_128Concat, ValidatorId<T>, ReliabilityState, OptionQuery>;
+
+	/// The outcome of the last sessions of each validator, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn reliability_history_of)]
+	pub type ReliabilityHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ValidatorId<T>,
+		BoundedVec<SessionRecord, T::MaxReliabilityHistory>,
+		ValueQuery,
+	>;
+
+	/// Waiting validators whose reliability score for a past session is yet to be updated,
+	/// with that session and whether they were online and sent a heartbeat in it. They are
+	/// queued in pages of `MaxWaitingScoresPerBlock`, and a page is scored in each block.
+	#[pallet::storage]
+	pub type PendingReliabilityUpdates<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u32,
+		BoundedVec<(ValidatorId<T>, SessionIndex, bool, bool), T::MaxWaitingScoresPerBlock>,
+		OptionQuery,
+	>;
+
+	/// The first queued page of `PendingReliabilityUpdates` and the page after the last one.
+	#[pallet::storage]
+	pub type PendingReliabilityPages<T> = StorageValue<_, (u32, u32), ValueQuery>;
+
 	#[pallet::storage]
 	pub type ChilledValidatorsMap<T> =
 		StorageMap<_, Blake2_128Concat, ValidatorId<T>, bool, ValueQuery>;
@@ -507,6 +602,21 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let (first, end) = PendingReliabilityPages::<T>::get();
+			if first == end {
+				return T::DbWeight::get().reads(1)
+			}
+			let page = PendingReliabilityUpdates::<T>::take(first).unwrap_or_default();
+			for (vid, s_idx, online, heartbeat) in page.iter() {
+				Self::update_reliability_score_of(vid, *s_idx, *online, *heartbeat, None);
+			}
+			PendingReliabilityPages::<T>::put((first.wrapping_add(1), end));
+			<T as Config>::WeightInfo::score_validator()
+				.saturating_mul(page.len() as u64)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+		}
+
 		fn offchain_worker(now: BlockNumberFor<T>) {
 			// Only send messages if we are a potential validator.
 			if sp_io::offchain::is_validator() {
@@ -599,21 +709,107 @@ impl<T: Config + pallet_authorship::Config>
 }
 
 impl<T: Config> Pallet<T> {
-	/// increases uptime/reliability score of a validator
-	/// by a factor (a formula for this is under work)
-	/// for time being its 1
-	/// implementation is supposed to change in future
-	fn inc_reliability_score_of(acc_id: &ValidatorId<T>, is_active: bool) {
-		<ReliabilityScoresMap<T>>::mutate(acc_id, |v| {
-			*v = (*v + (if is_active { ACTIVE_FACTOR } else { WAITING_FACTOR }))
-				.clamp(0, REL_SCORE_MAX);
+	/// The uptime record of a validator after a session it was online or offline in.
+	///
+	/// An online session moves the uptime towards the session uptime by `UptimeSmoothing`. The
+	/// session uptime of an active validator partly depends on `authored`, the share of its
+	/// expected slots it authored blocks in. Each consecutive offline session costs
+	/// `MissedSessionPenalty` more of the uptime, after which the gains ramp back up over
+	/// `RecoverySessions` online sessions.
+	pub fn next_reliability_state(
+		mut state: ReliabilityState,
+		online: bool,
+		authored: Option<Perbill>,
+	) -> ReliabilityState {
+		let recovery_sessions = T::RecoverySessions::get();
+		if !online {
+			state.missed_streak = state.missed_streak.saturating_add(1);
+			state.recovering = recovery_sessions;
+			let penalty = Perbill::from_parts(
+				T::MissedSessionPenalty::get().deconstruct().saturating_mul(state.missed_streak),
+			);
+			state.uptime = state.uptime.saturating_sub(state.uptime * penalty);
+			return state;
+		}
+
+		let session_uptime = match authored {
+			Some(authored) => {
+				let authoring_weight = T::AuthoringWeight::get();
+				authoring_weight.left_from_one().saturating_add(authoring_weight * authored)
+			},
+			None => Perbill::one(),
+		};
+		let smoothing = T::UptimeSmoothing::get();
+		if session_uptime >= state.uptime {
+			let recovery = match state.recovering {
+				0 => Perbill::one(),
+				left => Perbill::from_rational(
+					recovery_sessions.saturating_sub(left).saturating_add(1),
+					recovery_sessions.saturating_add(1),
+				),
+			};
+			let gain = recovery * (smoothing * session_uptime.saturating_sub(state.uptime));
+			state.uptime = state.uptime.saturating_add(gain);
+		} else {
+			let loss = smoothing * state.uptime.saturating_sub(session_uptime);
+			state.uptime = state.uptime.saturating_sub(loss);
+		}
+		state.missed_streak = 0;
+		state.recovering = state.recovering.saturating_sub(1);
+		state
+	}
+
+	/// Updates the uptime record, the reliability score and the reliability history of a
+	/// validator at the end of session `s_idx`.
+	///
+	/// `expected_blocks` is the number of blocks an active validator was expected to author,
+	/// `None` for waiting validators.
+	fn update_reliability_score_of(
+		acc_id: &ValidatorId<T>,
+		s_idx: SessionIndex,
+		online: bool,
+		heartbeat: bool,
+		expected_blocks: Option<u32>,
+	) {
+		let authored_blocks = AuthoredBlocks::<T>::get(s_idx, acc_id);
+		let authored =
+			expected_blocks.map(|expected| Perbill::from_rational(authored_blocks, expected));
+		let old_score = ReliabilityScoresMap::<T>::get(acc_id);
+		// validators scored before uptime records keep their score as their uptime
+		let state = ReliabilityStates::<T>::get(acc_id).unwrap_or_else(|| ReliabilityState {
+			uptime: Perbill::from_percent(old_score.into()),
+			..Default::default()
+		});
+		let state = Self::next_reliability_state(state, online, authored);
+		let new_score = (state.uptime * u32::from(REL_SCORE_MAX)) as RScoreType;
+		ReliabilityScoresMap::<T>::insert(acc_id, new_score);
+		ReliabilityStates::<T>::insert(acc_id, state);
+
+		let record = SessionRecord {
+			session_index: s_idx,
+			heartbeat,
+			authored_blocks,
+			score_delta: new_score as i16 - old_score as i16,
+		};
+		ReliabilityHistory::<T>::mutate(acc_id, |history| {
+			if history.is_full() {
+				history.remove(0);
+			}
+			// a queued update of a waiting validator can come after a later session of it as an
+			// active validator, the history is kept in session order
+			let at = history
+				.iter()
+				.rposition(|r| r.session_index <= s_idx)
+				.map_or(0, |idx| idx.saturating_add(1));
+			// cannot fail, a record was just removed from a full history
+			let _ = history.try_insert(at, record);
 		});
 	}
 
 	/// resets uptime/reliability score of a validator
-	/// implementation is supposed to change in future
 	fn reset_reliability_score_of(acc_id: &ValidatorId<T>) {
-		ReliabilityScoresMap::<T>::mutate(acc_id, |v| *v = 0);
+		ReliabilityScoresMap::<T>::remove(acc_id);
+		ReliabilityStates::<T>::remove(acc_id);
 	}
 	/// Returns `true` if a heartbeat has been received for the authority at
 	/// `authority_index` in the authorities series or if the authority has
@@ -856,34 +1052,31 @@ impl<T: Config> Pallet<T> {
 			},
 		};
 
-		let total_active = active_validators.len();
+		let mut active_offenders: Vec<IdentificationTuple<T>> = Vec::new();
+		for (i, id) in active_validators.iter().enumerate() {
+			if Self::is_online_aux(i as AuthIndex, id) {
+				continue
+			}
+			match IdentificationOf::<T>::convert(id.clone()) {
+				Some(full_id) => active_offenders.push((id.clone(), full_id)),
+				None => log::warn!(
+					target: "runtime::im-online",
+					"Offline validator {:?} has no identification, not reporting it",
+					id,
+				),
+			}
+		}
+
+		// validator ids are only `Eq`, so they are looked up by their encoding
+		let active_set: BTreeSet<Vec<u8>> = active_validators.iter().map(Encode::encode).collect();
 		let mut all_offenders: Vec<ValidatorId<T>> = Vec::new();
 		let mut waiting_validators: Vec<ValidatorId<T>> = Vec::new();
-		let mut active_offenders: Vec<IdentificationTuple<T>> = Vec::new();
-		// prepare neccessary lists using single iterator
-		// the reason using this iterator to prepare all lists is
-		// all_validators list will always contain all the validators
-		// so considering this feature, we can index other lists with same
-		// index number which is used for all_validators list!
-		for (i, acid) in all_validators.clone().into_iter().enumerate() {
-			if !active_validators.contains(&acid) {
+		for (i, acid) in all_validators.iter().enumerate() {
+			if !active_set.contains(&acid.encode()) {
 				waiting_validators.push(acid.clone());
 			}
-			if i < total_active {
-				let id = &active_validators[i];
-				if !Self::is_online_aux(i as u32, id) {
-					active_offenders.push(
-						<T::ValidatorSet as ValidatorSetWithIdentification<T::AccountId>>::IdentificationOf::convert(
-							id.clone()
-						).map(
-							|full_id| (id.clone(), full_id)
-						)
-							.unwrap()
-					)
-				}
-			}
-			if !Self::is_online_aux(i as u32, &acid) {
-				all_offenders.push(acid)
+			if !Self::is_online_aux(i as AuthIndex, acid) {
+				all_offenders.push(acid.clone())
 			}
 		}
 		(active_validators, all_validators, all_offenders, waiting_validators, active_offenders)
@@ -898,17 +1091,77 @@ impl<T: Config> Pallet<T> {
 			active_offenders,
 		) = all_lists;
 
-		let handle_score = |vid: &ValidatorId<T>, is_active: bool| {
-			if all_offenders.contains(vid) {
-				Self::reset_reliability_score_of(vid);
-			} else {
-				Self::inc_reliability_score_of(vid, is_active);
-			}
-		};
+		// BABE gives every active validator the same share of the slots of a session
+		let session_length: u32 = T::NextSessionRotation::average_session_length().saturated_into();
+		let expected_blocks = session_length / (active_validators.len() as u32).max(1);
+
+		// heartbeats are indexed the same way as in `prepare_all_lists`, and are read at once
+		let heartbeats: BTreeSet<AuthIndex> =
+			ReceivedHeartbeats::<T>::iter_key_prefix(s_idx).collect();
+		let heartbeat_at = |idx: usize| heartbeats.contains(&(idx as AuthIndex));
+		let mut scored = active_validators.len() as u64;
+
+		active_validators.iter().enumerate().for_each(|(idx, vid)| {
+			Self::update_reliability_score_of(
+				vid,
+				s_idx,
+				!all_offenders.contains(vid),
+				heartbeat_at(idx),
+				Some(expected_blocks),
+			)
+		});
 
-		active_validators.iter().for_each(|vid| handle_score(vid, true));
+		// `prepare_all_lists` already found out which of the electable targets were online
+		let offline: BTreeSet<Vec<u8>> = all_offenders.iter().map(Encode::encode).collect();
+		let positions: BTreeMap<Vec<u8>, usize> = all_validators
+			.iter()
+			.enumerate()
+			.map(|(idx, vid)| (vid.encode(), idx))
+			.collect();
+		let outcome_of = |vid: &ValidatorId<T>| {
+			let encoded = vid.encode();
+			let heartbeat = positions.get(&encoded).map_or(false, |&idx| heartbeat_at(idx));
+			(!offline.contains(&encoded), heartbeat)
+		};
 
-		waiting_validators.iter().for_each(|vid| handle_score(vid, false));
+		// updates still queued from a previous session are applied first, so the waiting
+		// validators are all queued behind them
+		let (first, mut end) = PendingReliabilityPages::<T>::get();
+		let limit = T::MaxWaitingScoresPerBlock::get().max(1) as usize;
+		let scored_now = if first == end { limit.min(waiting_validators.len()) } else { 0 };
+		for vid in &waiting_validators[..scored_now] {
+			let (online, heartbeat) = outcome_of(vid);
+			Self::update_reliability_score_of(vid, s_idx, online, heartbeat, None);
+			scored += 1;
+		}
+		let mut queued = 0u64;
+		for chunk in waiting_validators[scored_now..].chunks(limit) {
+			let page = chunk
+				.iter()
+				.map(|vid| {
+					let (online, heartbeat) = outcome_of(vid);
+					(vid.clone(), s_idx, online, heartbeat)
+				})
+				.collect::<Vec<_>>();
+			PendingReliabilityUpdates::<T>::insert(end, BoundedVec::truncate_from(page));
+			end = end.wrapping_add(1);
+			queued += 1;
+		}
+		PendingReliabilityPages::<T>::put((first, end));
+
+		let db_weight = T::DbWeight::get();
+		let weight = <T as Config>::WeightInfo::score_validator()
+			.saturating_mul(scored)
+			// electable targets and heartbeats of the session, plus the queued pages
+			.saturating_add(db_weight.reads(all_validators.len() as u64))
+			.saturating_add(db_weight.reads(heartbeats.len() as u64))
+			.saturating_add(db_weight.reads_writes(1, queued.saturating_add(1)))
+			// pruning the heartbeats and authored blocks of the session
+			.saturating_add(db_weight.writes(2 * active_validators.len() as u64));
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			weight,
+			DispatchClass::Mandatory,
+		);
 
 		Self::deposit_event(Event::<T>::EndSession {
 			s_idx,
@@ -971,13 +1224,6 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 		let s_idx = T::ValidatorSet::session_index();
 		let all_lists = Self::prepare_all_lists();
 		let active_offenders = &all_lists.4;
-		// Remove all received heartbeats and number of authored blocks from the
-		// current session, they have already been processed and won't be needed
-		// anymore.
-		#[allow(deprecated)]
-		ReceivedHeartbeats::<T>::remove_prefix(&s_idx, None);
-		#[allow(deprecated)]
-		AuthoredBlocks::<T>::remove_prefix(&s_idx, None);
 
 		if active_offenders.is_empty() {
 			Self::deposit_event(Event::<T>::AllGood);
@@ -999,6 +1245,14 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 		// finally process reliability score of
 		// every validator of any kind
 		Self::process_reliablilties(all_lists, s_idx);
+
+		// Remove all received heartbeats and number of authored blocks from the
+		// current session, they have already been processed and won't be needed
+		// anymore.
+		#[allow(deprecated)]
+		ReceivedHeartbeats::<T>::remove_prefix(&s_idx, None);
+		#[allow(deprecated)]
+		AuthoredBlocks::<T>::remove_prefix(&s_idx, None);
 	}
 
 	fn on_disabled(_i: u32) {
@@ -1083,7 +1337,7 @@ impl<T: Config> ERScoresTrait<ValidatorId<T>> for Pallet<T> {
 	}
 
 	fn chilled_validator_status(company: ValidatorId<T>) {
-		let active_validators = Self::prepare_all_lists().0;
+		let active_validators = T::ValidatorSet::validators();
 		if active_validators.contains(&company) {
 			ChilledValidatorsMap::<T>::mutate(company, |v| *v = true);
 		}
@@ -1094,19 +1348,23 @@ impl<T: Config> ERScoresTrait<ValidatorId<T>> for Pallet<T> {
 	}
 
 	fn reset_score_after_era_for_chilled_active_validator() {
-		let active_validators = Self::prepare_all_lists().0;
+		let active_validators = T::ValidatorSet::validators();
 		active_validators.iter().for_each(|vid| {
 			if ChilledValidatorsMap::<T>::get(vid) == true {
-				ReliabilityScoresMap::<T>::mutate(vid, |v| *v = 0);
+				Self::reset_reliability_score_of(vid);
 				ChilledValidatorsMap::<T>::mutate(vid, |v| *v = false)
 			}
 		});
 	}
 
 	fn reset_score_of_chilled_waiting_validator(company: ValidatorId<T>) {
-		let active_validators = Self::prepare_all_lists().0;
+		let active_validators = T::ValidatorSet::validators();
 		if !active_validators.contains(&company) {
-			ReliabilityScoresMap::<T>::mutate(company.clone(), |v| *v = 0);
+			Self::reset_reliability_score_of(&company);
 		}
 	}
+
+	fn is_chilled(company: ValidatorId<T>) -> bool {
+		ChilledValidatorsMap::<T>::get(company)
+	}
 }
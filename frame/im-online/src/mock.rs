@@ -239,12 +239,34 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
+parameter_types! {
+	pub const EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 1, social: 1, governance: 1 };
+}
+
 impl pallet_esg::Config for Test {
 	type WeightInfo = ();
 	type MaxFileSize = ConstU32<102400>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxNumOfSudoOracles = ConstU32<5>;
 	type MaxNumOfNonSudoOracles = ConstU32<5>;
+	type MaxScoreHistory = ConstU32<16>;
+	type MaxBatch = ConstU32<100>;
+	type SubmissionQuorum = ConstU32<1>;
+	type OutlierThreshold = frame_support::traits::ConstU16<20>;
+	type ScoreValidity = ConstU64<{ u64::MAX }>;
+	type ScoreDecayPeriod = ConstU64<0>;
+	type Currency = Balances;
+	type OracleDeposit = frame_support::traits::ConstU128<0>;
+	type OracleTerm = ConstU64<{ u64::MAX }>;
+	type SlashOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Slash = ();
+	type DisputeDeposit = frame_support::traits::ConstU128<0>;
+	type DisputeResolveOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
 }
 
 type VoterBagsListInstance = pallet_bags_list::Instance1;
@@ -366,8 +388,9 @@ pub struct TestNextSessionRotation;
 
 impl frame_support::traits::EstimateNextSessionRotation<u64> for TestNextSessionRotation {
 	fn average_session_length() -> u64 {
-		// take the mock result if any and return it
-		let mock = MockAverageSessionLength::mutate(|p| p.take());
+		// return the mock result if any, it is kept since the session length is read both when
+		// scoring the ending session and when starting the new one
+		let mock = MockAverageSessionLength::get();
 
 		mock.unwrap_or(pallet_session::PeriodicSessions::<Period, Offset>::average_session_length())
 	}
@@ -389,6 +412,15 @@ impl frame_support::traits::EstimateNextSessionRotation<u64> for TestNextSession
 	}
 }
 
+parameter_types! {
+	pub const UptimeSmoothing: Perbill = Perbill::from_percent(10);
+	pub const MissedSessionPenalty: Perbill = Perbill::from_percent(25);
+	pub const AuthoringWeight: Perbill = Perbill::from_percent(50);
+	pub const RecoverySessions: u32 = 4;
+	pub const MaxReliabilityHistory: u32 = 4;
+	pub static MaxWaitingScoresPerBlock: u32 = 100;
+}
+
 impl Config for Test {
 	type AuthorityId = UintAuthorityId;
 	type RuntimeEvent = RuntimeEvent;
@@ -401,6 +433,12 @@ impl Config for Test {
 	type MaxPeerInHeartbeats = ConstU32<10_000>;
 	type DataProvider = Staking;
 	type TargetsBound = MaxOnChainElectableTargets;
+	type UptimeSmoothing = UptimeSmoothing;
+	type MissedSessionPenalty = MissedSessionPenalty;
+	type AuthoringWeight = AuthoringWeight;
+	type RecoverySessions = RecoverySessions;
+	type MaxReliabilityHistory = MaxReliabilityHistory;
+	type MaxWaitingScoresPerBlock = MaxWaitingScoresPerBlock;
 }
 
 impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
@@ -504,3 +504,202 @@ fn test_probability_of_heartbeating_increases_with_session_progress() {
 		assert_heartbeat_ok(0.75, 0.18);
 	});
 }
+
+fn reliability(uptime: Perbill, missed_streak: u32, recovering: u32) -> ReliabilityState {
+	ReliabilityState { uptime, missed_streak, recovering }
+}
+
+#[test]
+fn uptime_moves_towards_session_uptime() {
+	new_test_ext(0).execute_with(|| {
+		// a waiting validator that is online gets a full session uptime
+		let state = ImOnline::next_reliability_state(Default::default(), true, None);
+		assert_eq!(state, reliability(Perbill::from_percent(10), 0, 0));
+		let state = ImOnline::next_reliability_state(state, true, None);
+		assert_eq!(state, reliability(Perbill::from_percent(19), 0, 0));
+
+		// an active validator that authored half of its expected blocks
+		let state = ImOnline::next_reliability_state(
+			Default::default(),
+			true,
+			Some(Perbill::from_percent(50)),
+		);
+		assert_eq!(state, reliability(Perbill::from_rational(75u32, 1000u32), 0, 0));
+
+		// an online validator that authored nothing loses uptime
+		let state = ImOnline::next_reliability_state(
+			reliability(Perbill::one(), 0, 0),
+			true,
+			Some(Perbill::zero()),
+		);
+		assert_eq!(state, reliability(Perbill::from_percent(95), 0, 0));
+	});
+}
+
+#[test]
+fn missed_sessions_are_penalised_progressively() {
+	new_test_ext(0).execute_with(|| {
+		let state = reliability(Perbill::from_percent(80), 0, 0);
+
+		let state = ImOnline::next_reliability_state(state, false, None);
+		assert_eq!(state, reliability(Perbill::from_percent(60), 1, 4));
+		let state = ImOnline::next_reliability_state(state, false, None);
+		assert_eq!(state, reliability(Perbill::from_percent(30), 2, 4));
+		let state = ImOnline::next_reliability_state(state, false, None);
+		assert_eq!(state, reliability(Perbill::from_rational(75u32, 1000u32), 3, 4));
+		let state = ImOnline::next_reliability_state(state, false, None);
+		assert_eq!(state, reliability(Perbill::zero(), 4, 4));
+	});
+}
+
+#[test]
+fn uptime_recovers_gradually_after_a_missed_session() {
+	new_test_ext(0).execute_with(|| {
+		let state = reliability(Perbill::from_percent(60), 1, 4);
+
+		// only a fifth of the regular gain right after the miss
+		let state = ImOnline::next_reliability_state(state, true, None);
+		assert_eq!(state, reliability(Perbill::from_rational(608u32, 1000u32), 0, 3));
+
+		let state =
+			(0..3).fold(state, |state, _| ImOnline::next_reliability_state(state, true, None));
+		assert_eq!(state.recovering, 0);
+
+		// full gain once recovered
+		let recovered = ImOnline::next_reliability_state(state, true, None);
+		assert_eq!(
+			recovered.uptime,
+			state.uptime + Perbill::from_percent(10) * state.uptime.left_from_one()
+		);
+	});
+}
+
+#[test]
+fn should_record_missed_sessions_of_offline_validators() {
+	new_test_ext(6).execute_with(|| {
+		System::set_block_number(1);
+		advance_session();
+		Validators::mutate(|l| *l = Some(vec![1, 2, 3, 4, 5, 6]));
+		advance_session();
+
+		advance_session();
+
+		// offline while waiting in sessions 0 and 1 and while active in session 2
+		for v in 1u64..=3 {
+			assert_eq!(ImOnline::reliability_state_of(v), Some(reliability(Perbill::zero(), 3, 4)));
+			assert_eq!(ReliabilityScoresMap::<Test>::get(v), 0);
+		}
+	});
+}
+
+#[test]
+fn should_keep_a_bounded_reliability_history() {
+	use pallet_authorship::EventHandler;
+
+	new_test_ext(6).execute_with(|| {
+		System::set_block_number(1);
+		advance_session();
+		Validators::mutate(|l| *l = Some(vec![1, 2, 3, 4, 5, 6]));
+		advance_session();
+
+		// nobody is online in sessions 0 to 2
+		advance_session();
+		let offline: Vec<_> = (0..3)
+			.map(|session_index| SessionRecord { session_index, ..Default::default() })
+			.collect();
+		assert_eq!(ImOnline::reliability_history_of(1).into_inner(), offline);
+
+		// validator 1 sends a heartbeat and validator 2 authors a block in session 3
+		let _ = heartbeat(1, 3, 0, 1.into(), Session::validators()).unwrap();
+		ImOnline::note_author(2);
+		advance_session();
+
+		let history = ImOnline::reliability_history_of(1);
+		assert_eq!(history[..3], offline[..]);
+		assert_eq!(
+			(history[3].session_index, history[3].heartbeat, history[3].authored_blocks),
+			(3, true, 0)
+		);
+		let record = *ImOnline::reliability_history_of(2).last().unwrap();
+		assert_eq!((record.session_index, record.heartbeat, record.authored_blocks), (3, false, 1));
+		let record = *ImOnline::reliability_history_of(3).last().unwrap();
+		assert_eq!((record.session_index, record.heartbeat, record.authored_blocks), (3, false, 0));
+
+		// only the last sessions are kept
+		for _ in 0..3 {
+			advance_session();
+		}
+		let history = ImOnline::reliability_history_of(1);
+		assert_eq!(history.len(), 4);
+		assert_eq!(history.iter().map(|r| r.session_index).collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+	});
+}
+
+#[test]
+fn should_spread_waiting_validator_scores_across_blocks() {
+	new_test_ext(6).execute_with(|| {
+		System::set_block_number(1);
+		advance_session();
+		Validators::mutate(|l| *l = Some(vec![1, 2, 3, 4, 5, 6]));
+		advance_session();
+		MaxWaitingScoresPerBlock::set(2);
+
+		// validators 4, 5 and 6 are waiting in session 2, only two of them are scored right away
+		advance_session();
+		let scored_in = |session_index| {
+			(4u64..=6)
+				.filter(|v| {
+					ImOnline::reliability_history_of(v).last().map(|r| r.session_index) ==
+						Some(session_index)
+				})
+				.count()
+		};
+		assert_eq!(scored_in(2), 2);
+		assert_eq!(PendingReliabilityUpdates::<Test>::iter().count(), 1);
+
+		// the last one is scored in the next block
+		ImOnline::on_initialize(System::block_number() + 1);
+		assert_eq!(scored_in(2), 3);
+		assert_eq!(PendingReliabilityUpdates::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn should_queue_every_waiting_validator_behind_pending_updates() {
+	new_test_ext(6).execute_with(|| {
+		System::set_block_number(1);
+		advance_session();
+		Validators::mutate(|l| *l = Some(vec![1, 2, 3, 4, 5, 6]));
+		advance_session();
+		MaxWaitingScoresPerBlock::set(1);
+		let sessions_of = |v| {
+			ImOnline::reliability_history_of(v)
+				.iter()
+				.map(|r| r.session_index)
+				.collect::<Vec<_>>()
+		};
+
+		// one of 4, 5 and 6 is scored at the end of session 2, the others are queued in two pages
+		advance_session();
+		assert_eq!(PendingReliabilityPages::<Test>::get(), (0, 2));
+		assert_eq!((4u64..=6).filter(|v| sessions_of(*v).last() == Some(&2)).count(), 1);
+
+		// 4, 5 and 6 are active in sessions 3 and 4 and waiting again in session 5, when they
+		// are all queued behind the updates still pending from session 2
+		Validators::mutate(|l| *l = Some(vec![1, 2, 3]));
+		advance_session();
+		advance_session();
+		advance_session();
+		assert_eq!(PendingReliabilityPages::<Test>::get(), (0, 5));
+		assert!((4u64..=6).all(|v| sessions_of(v).last() == Some(&4)));
+
+		for _ in 0..5 {
+			ImOnline::on_initialize(System::block_number() + 1);
+		}
+		assert_eq!(PendingReliabilityPages::<Test>::get(), (5, 5));
+		assert_eq!(PendingReliabilityUpdates::<Test>::iter().count(), 0);
+		for v in 4u64..=6 {
+			assert_eq!(sessions_of(v), vec![2, 3, 4, 5]);
+		}
+	});
+}
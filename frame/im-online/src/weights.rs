@@ -53,6 +53,7 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_im_online.
 pub trait WeightInfo {
 	fn validate_unsigned_and_then_heartbeat(k: u32, ) -> Weight;
+	fn score_validator() -> Weight;
 }
 
 /// Weights for pallet_im_online using the Substrate node and recommended hardware.
@@ -81,6 +82,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 1761).saturating_mul(k.into()))
 	}
+	/// Storage: ImOnline PendingReliabilityUpdates (r:1 w:1)
+	/// Proof: ImOnline PendingReliabilityUpdates (max_values: None, max_size: Some(1414), added: 3889, mode: MaxEncodedLen)
+	/// Storage: ImOnline AuthoredBlocks (r:1 w:0)
+	/// Proof: ImOnline AuthoredBlocks (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: ImOnline ReliabilityScoresMap (r:1 w:1)
+	/// Proof: ImOnline ReliabilityScoresMap (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: ImOnline ReliabilityStates (r:1 w:1)
+	/// Proof: ImOnline ReliabilityStates (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ImOnline ReliabilityHistory (r:1 w:1)
+	/// Proof: ImOnline ReliabilityHistory (max_values: None, max_size: Some(1138), added: 3613, mode: MaxEncodedLen)
+	fn score_validator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `4879`
+		// Minimum execution time: 13_809_000 picoseconds.
+		Weight::from_parts(14_534_000, 4879)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -108,4 +128,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 1761).saturating_mul(k.into()))
 	}
+	/// Storage: ImOnline PendingReliabilityUpdates (r:1 w:1)
+	/// Proof: ImOnline PendingReliabilityUpdates (max_values: None, max_size: Some(1414), added: 3889, mode: MaxEncodedLen)
+	/// Storage: ImOnline AuthoredBlocks (r:1 w:0)
+	/// Proof: ImOnline AuthoredBlocks (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: ImOnline ReliabilityScoresMap (r:1 w:1)
+	/// Proof: ImOnline ReliabilityScoresMap (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: ImOnline ReliabilityStates (r:1 w:1)
+	/// Proof: ImOnline ReliabilityStates (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: ImOnline ReliabilityHistory (r:1 w:1)
+	/// Proof: ImOnline ReliabilityHistory (max_values: None, max_size: Some(1138), added: 3613, mode: MaxEncodedLen)
+	fn score_validator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183`
+		//  Estimated: `4879`
+		// Minimum execution time: 13_809_000 picoseconds.
+		Weight::from_parts(14_534_000, 4879)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -0,0 +1,25 @@
+//! Runtime API definition for the reward pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait RewardApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Rewards still owed to the validator for its own stake and commission.
+		fn pending_validator_rewards(validator: AccountId) -> Balance;
+
+		/// Rewards still owed to the nominator through the validators it nominates.
+		fn pending_nominator_rewards(nominator: AccountId) -> Balance;
+
+		/// Total rewards received by the account so far.
+		fn lifetime_rewards(who: AccountId) -> Balance;
+
+		/// Rewards the account would receive if the active era ended now.
+		fn projected_era_reward(who: AccountId) -> Balance;
+	}
+}
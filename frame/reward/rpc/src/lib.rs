@@ -0,0 +1,151 @@
+//! RPC interface for the reward pallet.
+
+use std::{convert::TryInto, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+pub use pallet_reward_rpc_runtime_api::RewardApi as RewardRuntimeApi;
+
+#[rpc(client, server)]
+pub trait RewardApi<BlockHash, AccountId> {
+	#[method(name = "reward_pendingValidatorRewards")]
+	fn pending_validator_rewards(
+		&self,
+		validator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "reward_pendingNominatorRewards")]
+	fn pending_nominator_rewards(
+		&self,
+		nominator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "reward_lifetimeRewards")]
+	fn lifetime_rewards(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "reward_projectedEraReward")]
+	fn projected_era_reward(&self, who: AccountId, at: Option<BlockHash>)
+		-> RpcResult<NumberOrHex>;
+}
+
+/// Provides RPC methods to query the rewards owed to and received by an account.
+pub struct Reward<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Reward<C, P> {
+	/// Creates a new instance of the Reward Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block> Reward<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+{
+	/// Calls the runtime API at the given block, or the best block, and converts the returned
+	/// balance into its RPC representation.
+	fn query<Balance>(
+		&self,
+		at: Option<Block::Hash>,
+		desc: &'static str,
+		call: impl FnOnce(&C::Api, Block::Hash) -> Result<Balance, ApiError>,
+	) -> RpcResult<NumberOrHex>
+	where
+		Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>,
+	{
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let balance = call(&api, at_hash).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				desc,
+				Some(e.to_string()),
+			))
+		})?;
+
+		balance.try_into().map_err(|_| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				ErrorCode::InvalidParams.code(),
+				format!("{} doesn't fit in NumberOrHex representation", balance),
+				None::<()>,
+			)))
+		})
+	}
+}
+
+impl<C, Block, AccountId, Balance> RewardApiServer<<Block as BlockT>::Hash, AccountId>
+	for Reward<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RewardRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+{
+	fn pending_validator_rewards(
+		&self,
+		validator: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		self.query(at, "Unable to query pending validator rewards.", |api, at_hash| {
+			api.pending_validator_rewards(at_hash, validator)
+		})
+	}
+
+	fn pending_nominator_rewards(
+		&self,
+		nominator: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		self.query(at, "Unable to query pending nominator rewards.", |api, at_hash| {
+			api.pending_nominator_rewards(at_hash, nominator)
+		})
+	}
+
+	fn lifetime_rewards(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<NumberOrHex> {
+		self.query(at, "Unable to query lifetime rewards.", |api, at_hash| {
+			api.lifetime_rewards(at_hash, who)
+		})
+	}
+
+	fn projected_era_reward(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		self.query(at, "Unable to query projected era reward.", |api, at_hash| {
+			api.projected_era_reward(at_hash, who)
+		})
+	}
+}
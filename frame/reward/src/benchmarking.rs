@@ -2,10 +2,13 @@
 use super::*;
 #[allow(unused)]
 use crate::Pallet as Reward;
-use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_benchmarking::{account, v2::*, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin as SystemOrigin;
 use sp_std::vec;
 
+const SEED: u32 = 0;
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -15,12 +18,150 @@ mod benchmarks {
 		let caller: T::AccountId = whitelisted_caller();
 		let validator: T::AccountId = whitelisted_caller();
 		let balance: T::Balance = 5000u128.into();
-		ValidatorRewardAccounts::<T>::insert(validator.clone(), balance);
+		Reward::<T>::allocate_rewards(0, validator.clone(), None, balance);
 
 		#[extrinsic_call]
 		get_rewards(SystemOrigin::Signed(caller), validator.clone());
 
-		assert!(ValidatorRewardAccounts::<T>::contains_key(&validator.clone()));
+		assert!(EraValidatorRewards::<T>::contains_key(0, &validator.clone()));
+	}
+
+	#[benchmark]
+	fn claim_era_rewards(
+		n: Linear<0, { <T as pallet_staking::Config>::MaxNominatorRewardedPerValidator::get() }>,
+	) {
+		let caller: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let balance: T::Balance = 5_000_000_000_000_000_000u128.into();
+		T::RewardCurrency::make_free_balance_be(
+			&Reward::<T>::account_id(),
+			balance * (n + 2).into(),
+		);
+		Reward::<T>::allocate_rewards(0, validator.clone(), None, balance);
+		for i in 0..n {
+			let nominator: T::AccountId = account("nominator", i, SEED);
+			Reward::<T>::allocate_rewards(0, validator.clone(), Some(nominator), balance);
+		}
+		// the validator has rewards to claim in every era of the history depth
+		for era in 1..<T as pallet_staking::Config>::HistoryDepth::get() {
+			Reward::<T>::allocate_rewards(era, validator.clone(), None, balance);
+		}
+
+		#[extrinsic_call]
+		claim_era_rewards(SystemOrigin::Signed(caller), 0, validator.clone());
+
+		assert!(!UnclaimedEras::<T>::get(&validator).contains(&0));
+	}
+
+	#[benchmark]
+	fn claim_nominator_reward(
+		e: Linear<1, { <T as pallet_staking::Config>::HistoryDepth::get() }>,
+	) {
+		let nominator: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let balance: T::Balance = 5_000_000_000_000_000_000u128.into();
+		T::RewardCurrency::make_free_balance_be(
+			&Reward::<T>::account_id(),
+			balance * (e + 1).into(),
+		);
+		for era in 0..e {
+			Reward::<T>::allocate_rewards(era, validator.clone(), None, balance);
+			Reward::<T>::allocate_rewards(era, validator.clone(), Some(nominator.clone()), balance);
+		}
+
+		#[extrinsic_call]
+		claim_nominator_reward(SystemOrigin::Signed(nominator.clone()), validator.clone());
+
+		assert!(Reward::<T>::nominator_claims(validator, nominator).is_empty());
+	}
+
+	#[benchmark]
+	fn claim_all_nominator_rewards(
+		e: Linear<1, { <T as pallet_staking::Config>::HistoryDepth::get() }>,
+	) {
+		let nominator: T::AccountId = whitelisted_caller();
+		let balance: T::Balance = 5_000_000_000_000_000_000u128.into();
+		T::RewardCurrency::make_free_balance_be(
+			&Reward::<T>::account_id(),
+			balance * (e + 1).into(),
+		);
+		// every era is rewarded through a different validator, the worst case for the sweep
+		for era in 0..e {
+			let validator: T::AccountId = account("validator", era, SEED);
+			Reward::<T>::allocate_rewards(era, validator, Some(nominator.clone()), balance);
+		}
+
+		#[extrinsic_call]
+		claim_all_nominator_rewards(SystemOrigin::Signed(nominator.clone()));
+
+		assert!(NominatorRewardEras::<T>::iter_prefix((nominator,)).next().is_none());
+	}
+
+	#[benchmark]
+	fn expire_era_rewards(n: Linear<0, { Reward::<T>::expired_rewards_per_page() }>) {
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let balance: T::Balance = 5_000_000_000_000_000_000u128.into();
+		for i in 0..n {
+			let nominator: T::AccountId = account("nominator", i, SEED);
+			Reward::<T>::allocate_rewards(0, validator.clone(), Some(nominator), balance);
+		}
+
+		#[block]
+		{
+			Reward::<T>::drain_expired_rewards(0, n as usize);
+		}
+
+		assert!(EraNominatorRewards::<T>::iter_prefix_values((0,)).next().is_none());
+	}
+
+	#[benchmark]
+	fn calculate_validator_reward(
+		n: Linear<0, { <T as pallet_staking::Config>::MaxNominatorRewardedPerValidator::get() }>,
+	) {
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let own: <T as pallet_staking::Config>::CurrencyBalance = 1_000u128.into();
+		let others: Vec<_> = (0..n)
+			.map(|i| pallet_staking::IndividualExposure {
+				who: account("nominator", i, SEED),
+				value: own,
+			})
+			.collect();
+		let total = own * (n + 1).into();
+		pallet_staking::ErasStakers::<T>::insert(
+			0,
+			validator.clone(),
+			pallet_staking::Exposure { total, own, others },
+		);
+		pallet_staking::ErasTotalStake::<T>::insert(0, total);
+		let era_reward: u128 = Reward::<T>::calculate_era_reward().into();
+
+		#[block]
+		{
+			Reward::<T>::allocate_validator_rewards(0, 0, era_reward, validator.clone());
+		}
+
+		assert!(UnclaimedEras::<T>::contains_key(&validator));
+	}
+
+	#[benchmark]
+	fn set_emission_schedule() -> Result<(), BenchmarkError> {
+		let schedule = EmissionSchedule {
+			yearly_reward: 5_000_000_000_000_000_000u128.into(),
+			yearly_decay: Perbill::from_percent(10),
+			ideal_stake: Perbill::from_percent(50),
+			min_reward: Perbill::from_percent(20),
+			falloff: Perbill::from_percent(5),
+			max_supply: 5_000_000_000_000_000_000_000u128.into(),
+			start_era: 0,
+		};
+		let origin =
+			T::EmissionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, schedule.clone());
+
+		assert_eq!(PendingEmissionSchedule::<T>::get(), Some(schedule));
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Reward, crate::mock::new_test_ext(), crate::mock::Test);
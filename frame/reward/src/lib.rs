@@ -5,27 +5,40 @@
 // staking activities.
 
 #![cfg_attr(not(feature = "std"), no_std)]
-use frame_support::{ensure, pallet_prelude::DispatchResult};
+use frame_support::{
+	ensure,
+	pallet_prelude::{DispatchError, DispatchResult},
+};
 pub use pallet::*;
 use pallet_staking::{
-	BalanceOf, CurrentEra, ErasRewardPoints, ErasStakers, Exposure, IndividualExposure, Rewards,
+	Bonded, CurrentEra, ErasRewardPoints, ErasStakers, Payee, RewardDestination, Rewards,
 	Validators,
 };
-use parity_scale_codec::Codec;
+use parity_scale_codec::{Codec, Decode, Encode};
 // use crate::migration::migrate_to_v1;
 use frame_support::{
 	pallet_prelude::StorageVersion,
 	traits::{
-		Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive, Get, LockableCurrency,
-		ValidatorSet,
+		Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive, Get, Imbalance,
+		LockableCurrency, ValidatorSet,
 	},
-	PalletId,
+	weights::Weight,
+	BoundedVec, PalletId,
+};
+use scale_info::{
+	prelude::{fmt::Debug, vec::Vec},
+	TypeInfo,
 };
-use scale_info::prelude::{fmt::Debug, vec::Vec};
 use sp_runtime::{
-	traits::{AccountIdConversion, AtLeast32BitUnsigned, Convert, Zero},
-	FixedPointOperand,
+	curve::PiecewiseLinear,
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, Bounded, Convert, SaturatedConversion,
+		Saturating, UniqueSaturatedInto, Zero,
+	},
+	FixedPointOperand, Perbill, Rounding, RuntimeDebug,
 };
+use sp_staking::{EraIndex, StakingInterface};
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
@@ -36,7 +49,71 @@ mod mock;
 mod tests;
 pub mod weights;
 pub use weights::WeightInfo;
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+/// Part of the reward above `min_reward` left after a quarter of the falloff, `2^(-1/4)`.
+const QUARTER_FALLOFF_RATIO: Perbill = Perbill::from_parts(840_896_415);
+/// Maximum number of points of the staking rate curve after the ideal stake.
+const MAX_FALLOFF_POINTS: u32 = 40;
+
+/// Step of an era reward distribution.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DistributionStage {
+	/// Returning the rewards of the era falling out of `HistoryDepth`.
+	Expire,
+	/// Computing the rewards of the validators and their nominators.
+	Calculate,
+	/// Paying the validators queued through `get_rewards`.
+	Payout,
+}
+
+/// Progress of the reward distribution of an era, processed page by page over several blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RewardDistribution<AccountId, Balance> {
+	/// The era whose rewards are distributed.
+	pub era: EraIndex,
+	/// The era whose exposures are used to split the rewards.
+	pub stakers_era: EraIndex,
+	/// The current step of the distribution.
+	pub stage: DistributionStage,
+	/// The total reward of the era.
+	pub era_reward: Balance,
+	/// The part of the era reward allocated so far.
+	pub allocated: Balance,
+	/// The unclaimed rewards of the era being expired drained so far by the `Expire` step.
+	pub expired: Balance,
+	/// The number of pages of validators in `DistributionValidators`.
+	pub validator_pages: u32,
+	/// The next page of validators whose rewards are to be computed.
+	pub next_page: u32,
+	/// The validator being paid out, and the first of its unclaimed eras left to pay.
+	pub payout: Option<(AccountId, EraIndex)>,
+	/// The number of pages processed so far.
+	pub pages: u32,
+}
+/// Schedule of the rewards emitted by the pallet, adjustable by governance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EmissionSchedule<Balance> {
+	/// The reward emitted over the first year of the schedule, in the smallest unit.
+	pub yearly_reward: Balance,
+	/// The part of the yearly reward removed at the start of every year of the schedule.
+	pub yearly_decay: Perbill,
+	/// The staking rate at which the full yearly reward is emitted.
+	pub ideal_stake: Perbill,
+	/// The part of the yearly reward emitted with no stake, and towards which the reward falls
+	/// off past the ideal stake.
+	pub min_reward: Perbill,
+	/// The increase of the staking rate past the ideal stake over which the reward above
+	/// `min_reward` halves, as the `falloff` of `pallet_staking_reward_curve`.
+	pub falloff: Perbill,
+	/// The total issuance above which no reward is emitted anymore.
+	pub max_supply: Balance,
+	/// The era from which the yearly decay is counted. It is set to the era in which the schedule
+	/// is enacted, unless only the other parameters of the schedule change, in which case the
+	/// era of the current schedule is kept.
+	pub start_era: EraIndex,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -76,6 +153,7 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// Yearly reward of the default emission schedule, in units of `10^Precision`.
 		#[pallet::constant]
 		type TotalReward: Get<u32>;
 
@@ -88,6 +166,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type EraMinutes: Get<u32>;
 
+		/// Account receiving the rounding remainder of each era reward, so that the sum of all
+		/// allocations always equals the era pot, as well as the rewards left unclaimed for more
+		/// than `HistoryDepth` eras.
+		#[pallet::constant]
+		type RewardRemainderAccount: Get<Self::AccountId>;
+
+		/// Maximum number of validators stored per page of `DistributionValidators`. The number of
+		/// validators processed per block only depends on the weight left in the block.
+		#[pallet::constant]
+		type RewardPageSize: Get<u32>;
+
+		/// Origin allowed to change the emission schedule.
+		type EmissionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		type RewardCurrency: LockableCurrency<
 			Self::AccountId,
 			Moment = BlockNumberFor<Self>,
@@ -96,13 +188,24 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	// #[pallet::hooks]
-	// impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-	// 	fn on_runtime_upgrade() -> frame_support::weights::Weight {
-	// 		migrate_to_v1::<T>()
-	// 	}
-
-	// }
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// The reward distributions are only processed with the weight left in the block.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			// the queue and the distribution read by the last call, which finds nothing to process
+			// or no room left
+			let mut consumed_weight = T::DbWeight::get().reads(2);
+			if !remaining_weight.all_gte(consumed_weight) {
+				return Weight::zero();
+			}
+			while let Some(page_weight) =
+				Self::process_reward_page(remaining_weight.saturating_sub(consumed_weight))
+			{
+				consumed_weight.saturating_accrue(page_weight);
+			}
+			consumed_weight
+		}
+	}
 
 	/// The era reward which are distributed among the validator and nominator
 	#[pallet::storage]
@@ -110,42 +213,134 @@ pub mod pallet {
 	pub type BeneficialRewardRecord<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
 
-	/// Specifies the total nominators of the validator
+	/// Reward of a validator for a given era, waiting to be claimed
 	#[pallet::storage]
-	#[pallet::getter(fn era_reward)]
-	pub type EraReward<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+	#[pallet::getter(fn era_validator_reward)]
+	pub type EraValidatorRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
 
-	/// Specifics regarding the rewards distributed within the designated era of the validator
+	/// Reward of a nominator through a validator for a given era, waiting to be claimed
 	#[pallet::storage]
-	#[pallet::getter(fn validator_reward_accounts)]
-	pub type ValidatorRewardAccounts<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+	#[pallet::getter(fn era_nominator_reward)]
+	pub type EraNominatorRewards<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		T::Balance,
+		ValueQuery,
+	>;
 
-	/// Specifics regarding the rewards distributed within the designated era of the nominator
+	/// Eras and validators through which the nominator still has rewards to claim, in
+	/// `EraNominatorRewards`
 	#[pallet::storage]
-	#[pallet::getter(fn nominator_reward_accounts)]
-	pub type NominatorRewardAccounts<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+	pub type NominatorRewardEras<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		(),
+	>;
 
-	/// Storage that tracks the rewards earned by nominators for each validator
+	/// Eras in which the validator or its nominators still have rewards to claim, at most the
+	/// last `HistoryDepth` eras
 	#[pallet::storage]
-	#[pallet::getter(fn nominator_earning_accounts)]
-	pub type NominatorEarningsAccount<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn unclaimed_eras)]
+	pub type UnclaimedEras<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		Blake2_128Concat,
-		T::AccountId,
-		T::Balance,
+		BoundedVec<EraIndex, <T as pallet_staking::Config>::HistoryDepth>,
 		ValueQuery,
 	>;
 
+	/// Specifics regarding the rewards distributed within the designated era of the nominator
+	#[pallet::storage]
+	#[pallet::getter(fn nominator_reward_accounts)]
+	pub type NominatorRewardAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
 	/// Era reward accounts
 	#[pallet::storage]
 	#[pallet::getter(fn era_reward_vault)]
 	pub type EraRewardsVault<T: Config> = StorageValue<_, Vec<T::AccountId>>;
 
+	/// Rewards held by the reward pot on behalf of the stakers: the rewards of the scheduled eras
+	/// that are neither paid, expired nor routed to the remainder account yet
+	#[pallet::storage]
+	#[pallet::getter(fn outstanding_rewards)]
+	pub type OutstandingRewards<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// Eras whose reward distribution is waiting to be processed, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reward_eras)]
+	pub type PendingRewardEras<T: Config> = StorageValue<
+		_,
+		BoundedVec<EraIndex, <T as pallet_staking::Config>::HistoryDepth>,
+		ValueQuery,
+	>;
+
+	/// Oldest era whose unclaimed rewards have not been returned yet, so that the eras without a
+	/// reward distribution expire along with the next one
+	#[pallet::storage]
+	#[pallet::getter(fn next_expiring_era)]
+	pub type NextExpiringEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+	/// Progress of the reward distribution of an era
+	#[pallet::storage]
+	#[pallet::getter(fn reward_distributions)]
+	pub type RewardDistributions<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, RewardDistribution<T::AccountId, T::Balance>>;
+
+	/// Validators whose rewards are computed by a page of the reward distribution of an era
+	#[pallet::storage]
+	pub type DistributionValidators<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		u32,
+		BoundedVec<T::AccountId, T::RewardPageSize>,
+		ValueQuery,
+	>;
+
+	#[pallet::type_value]
+	pub fn DefaultEmissionSchedule<T: Config>() -> EmissionSchedule<T::Balance> {
+		EmissionSchedule {
+			yearly_reward: (T::TotalReward::get() as u128)
+				.saturating_mul((10u128).saturating_pow(T::Precision::get()))
+				.into(),
+			yearly_decay: Perbill::zero(),
+			ideal_stake: Perbill::one(),
+			min_reward: Perbill::one(),
+			falloff: Perbill::from_percent(5),
+			max_supply: T::Balance::max_value(),
+			start_era: 0,
+		}
+	}
+
+	/// The emission schedule the era rewards are computed from
+	#[pallet::storage]
+	#[pallet::getter(fn emission_schedule)]
+	pub type CurrentEmissionSchedule<T: Config> =
+		StorageValue<_, EmissionSchedule<T::Balance>, ValueQuery, DefaultEmissionSchedule<T>>;
+
+	/// The emission schedule taking effect at the end of the active era
+	#[pallet::storage]
+	#[pallet::getter(fn pending_emission_schedule)]
+	pub type PendingEmissionSchedule<T: Config> = StorageValue<_, EmissionSchedule<T::Balance>>;
+
 	// reward account
 	#[pallet::storage]
 	#[pallet::getter(fn reward_account)]
@@ -160,6 +355,25 @@ pub mod pallet {
 		Rewarded { who: T::AccountId },
 		/// Insufficient Reward Balance
 		InsufficientRewardBalance,
+		/// The undistributed remainder of the era reward has been routed
+		RemainderRouted { who: T::AccountId, balance: T::Balance },
+		/// The rewards of an era were not claimed within `HistoryDepth` eras and have been
+		/// returned
+		RewardsExpired { era: EraIndex, balance: T::Balance },
+		/// A page of the reward distribution of an era has been processed
+		RewardPageProcessed { era: EraIndex, stage: DistributionStage, page: u32 },
+		/// The reward distribution of an era is complete
+		RewardDistributionCompleted { era: EraIndex, pages: u32 },
+		/// A new emission schedule has been set and takes effect at the end of the active era
+		EmissionScheduleSet { schedule: EmissionSchedule<T::Balance> },
+		/// The emission schedule set by governance has taken effect
+		EmissionScheduleEnacted { era: EraIndex },
+		/// The reward of an era has been minted into the reward pot
+		EraRewardMinted { era: EraIndex, balance: T::Balance },
+		/// The reward distribution of an era could not be scheduled, its reward is not emitted
+		RewardDistributionNotScheduled { era: EraIndex, error: DispatchError },
+		/// The reward paid to a stash could not be bonded and was kept as free balance
+		RestakeFailed { stash: T::AccountId, balance: T::Balance },
 	}
 
 	#[pallet::error]
@@ -170,6 +384,12 @@ pub mod pallet {
 		WaitTheEraToComplete,
 		/// Insufficient Reward Balance
 		InsufficientRewardBalance,
+		/// The ideal stake or the falloff of the emission schedule is zero
+		InvalidEmissionSchedule,
+		/// The reward distribution of the era has already been scheduled
+		DistributionAlreadyScheduled,
+		/// `HistoryDepth` reward distributions are already waiting to be processed
+		TooManyPendingDistributions,
 	}
 
 	#[pallet::genesis_config]
@@ -207,6 +427,79 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::Rewarded { who: validator });
 			Ok(())
 		}
+
+		/// Pays the rewards of `validator` and its nominators for a single `era`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_era_rewards(
+			<T as pallet_staking::Config>::MaxNominatorRewardedPerValidator::get()
+		))]
+		pub fn claim_era_rewards(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			validator: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(UnclaimedEras::<T>::get(&validator).contains(&era), Error::<T>::NoReward);
+			ensure!(
+				T::RewardCurrency::free_balance(&Self::account_id()) >=
+					Self::pending_era_reward(era, validator.clone()),
+				Error::<T>::InsufficientRewardBalance
+			);
+			Self::distribute_era_rewards(era, validator);
+			Ok(())
+		}
+
+		/// Pays the caller the rewards it earned as a nominator of `validator`, in every
+		/// unclaimed era, without paying the validator or the other nominators.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_nominator_reward(
+			<T as pallet_staking::Config>::HistoryDepth::get()
+		))]
+		pub fn claim_nominator_reward(
+			origin: OriginFor<T>,
+			validator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let nominator = ensure_signed(origin)?;
+			let claims = Self::nominator_claims(validator, nominator.clone());
+			let paid = Self::distribute_nominator_rewards(nominator, claims)?;
+			Ok(Some(<T as pallet::Config>::WeightInfo::claim_nominator_reward(paid)).into())
+		}
+
+		/// Pays the caller the rewards it earned through every validator it nominates or has
+		/// nominated.
+		///
+		/// At most `HistoryDepth` era rewards are paid per call, the rest can be claimed by
+		/// calling it again.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_all_nominator_rewards(
+			<T as pallet_staking::Config>::HistoryDepth::get()
+		))]
+		pub fn claim_all_nominator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let nominator = ensure_signed(origin)?;
+			let claims = Self::all_nominator_claims(nominator.clone())
+				.take(<T as pallet_staking::Config>::HistoryDepth::get() as usize)
+				.collect();
+			let paid = Self::distribute_nominator_rewards(nominator, claims)?;
+			Ok(Some(<T as pallet::Config>::WeightInfo::claim_all_nominator_rewards(paid)).into())
+		}
+
+		/// Sets the emission schedule used from the end of the active era on. The `start_era` of
+		/// `schedule` is ignored, see `EmissionSchedule::start_era`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_emission_schedule())]
+		pub fn set_emission_schedule(
+			origin: OriginFor<T>,
+			schedule: EmissionSchedule<T::Balance>,
+		) -> DispatchResult {
+			T::EmissionOrigin::ensure_origin(origin)?;
+			ensure!(
+				!schedule.ideal_stake.is_zero() && !schedule.falloff.is_zero(),
+				Error::<T>::InvalidEmissionSchedule
+			);
+			PendingEmissionSchedule::<T>::put(schedule.clone());
+			Self::deposit_event(Event::EmissionScheduleSet { schedule });
+			Ok(())
+		}
 	}
 }
 
@@ -219,85 +512,85 @@ impl<T: Config> Rewards<T::AccountId> for Pallet<T> {
 
 	/// Distributing rewards to validators and nominators.
 	fn claim_rewards(validator: T::AccountId) -> DispatchResult {
-		let nominators = EraReward::<T>::get(validator.clone());
 		let reward_balance = Self::verify_balance(validator.clone());
 		if let Err(e) = reward_balance {
 			Self::deposit_event(Event::InsufficientRewardBalance);
 			return Err(e);
 		}
-		Self::distribute_reward(validator.clone(), None)?;
 		Self::update_rewarded_validators(validator.clone())?;
-		if nominators.is_empty() {
-			return Ok(());
-		}
-		nominators.iter().for_each(|nominator| {
-			let _ = Self::distribute_reward(validator.clone(), Some(nominator.clone()));
+		UnclaimedEras::<T>::get(validator.clone()).into_iter().for_each(|era| {
+			Self::distribute_era_rewards(era, validator.clone());
 		});
-		EraReward::<T>::remove(validator.clone());
 		return Ok(());
 	}
 
-	/// Function for computing the rewards of validators and nominators at the end of each era
+	/// Mints the reward of the ending era into the reward pot and schedules its computation and
+	/// payout. The work is processed page by page in the following blocks.
 	fn calculate_reward() -> DispatchResult {
-		let validators = T::Validators::validators();
-
-		validators.iter().for_each(|validator_id| {
-			let validator = T::ValidatorId::convert(validator_id.clone()).unwrap();
-			let validator_points = Self::retrieve_validator_point(validator.clone());
-			let validator_exposure = ErasStakers::<T>::get(Self::current_era(), validator.clone());
-			let total_reward = Self::calculate_era_reward();
-			let validator_era_reward = Self::calculate_validator_era_reward(
-				validator_points.into(),
-				validator_exposure.total,
-				total_reward,
-			);
-			let nominators = Self::check_nominators(validator.clone());
-			if nominators.is_empty() {
-				Self::allocate_rewards(
-					validator.clone(),
-					None,
-					Self::convert_float64_to_unsigned128(validator_era_reward).into(),
-				);
-				return;
-			}
-			let (total_validator_reward, remaining_reward_for_nominators) =
-				Self::calculate_validator_commission_reward(
-					validator.clone(),
-					validator_era_reward,
-					validator_exposure.clone(),
-				);
-			Self::allocate_rewards(
-				validator.clone(),
-				None,
-				Self::convert_float64_to_unsigned128(total_validator_reward).into(),
+		let era = Self::active_era();
+		Self::schedule_reward_distribution(era).map_err(|error| {
+			Self::deposit_event(Event::RewardDistributionNotScheduled { era, error });
+			error
+		})
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Queues the reward distribution of `era` and mints its reward into the reward pot.
+	fn schedule_reward_distribution(era: EraIndex) -> DispatchResult {
+		ensure!(
+			!RewardDistributions::<T>::contains_key(era),
+			Error::<T>::DistributionAlreadyScheduled
+		);
+		PendingRewardEras::<T>::try_append(era)
+			.map_err(|_| Error::<T>::TooManyPendingDistributions)?;
+		Self::enact_emission_schedule();
+		let validators: Vec<T::AccountId> = T::Validators::validators()
+			.into_iter()
+			.filter_map(T::ValidatorId::convert)
+			.collect();
+		let page_size = T::RewardPageSize::get().max(1) as usize;
+		let mut validator_pages = 0;
+		validators.chunks(page_size).for_each(|page| {
+			DistributionValidators::<T>::insert(
+				era,
+				validator_pages,
+				BoundedVec::truncate_from(page.to_vec()),
 			);
-			if remaining_reward_for_nominators.is_zero() {
-				return;
-			}
-			nominators.iter().for_each(|nominator| {
-				let mut current_nominators = EraReward::<T>::get(validator.clone());
-				if !current_nominators.contains(&nominator.who.clone()) {
-					current_nominators.push(nominator.who.clone());
-					EraReward::<T>::insert(validator.clone(), current_nominators);
-				}
-				let nominator_stake = nominator.value;
-				let nominator_reward = Self::calculate_reward_share(
-					nominator_stake.into(),
-					validator_exposure.total.into(),
-					remaining_reward_for_nominators.into(),
-				);
-				Self::allocate_rewards(
-					validator.clone(),
-					Some(nominator.who.clone()),
-					Self::convert_float64_to_unsigned128(nominator_reward).into(),
-				);
-			});
+			validator_pages += 1;
 		});
+		let era_reward = Self::calculate_era_reward();
+		// the era reward is paid out of the free balance of the pot beyond the outstanding
+		// rewards first, only the rest is minted, so that `max_supply` bounds the total issuance
+		let pot = Self::account_id();
+		let outstanding = OutstandingRewards::<T>::get();
+		let surplus = T::RewardCurrency::free_balance(&pot)
+			.saturating_sub(outstanding)
+			.saturating_sub(T::RewardCurrency::minimum_balance());
+		let minted =
+			T::RewardCurrency::deposit_creating(&pot, era_reward.saturating_sub(surplus)).peek();
+		if !minted.is_zero() {
+			Self::deposit_event(Event::EraRewardMinted { era, balance: minted });
+		}
+		OutstandingRewards::<T>::put(outstanding.saturating_add(era_reward));
+		RewardDistributions::<T>::insert(
+			era,
+			RewardDistribution {
+				era,
+				stakers_era: Self::current_era(),
+				stage: DistributionStage::Expire,
+				era_reward,
+				allocated: Zero::zero(),
+				expired: Zero::zero(),
+				validator_pages,
+				next_page: 0,
+				payout: None,
+				pages: 0,
+			},
+		);
 		Ok(())
 	}
-}
 
-impl<T: Config> Pallet<T> {
 	/// Transfer an amount to the accounts with respecting the `keep_alive` requirements.
 	fn transfer(
 		who: T::AccountId,
@@ -312,38 +605,25 @@ impl<T: Config> Pallet<T> {
 
 	/// Validation of an account to determine its reward.
 	fn verify_validator(validator: T::AccountId) -> DispatchResult {
-		let validator = ValidatorRewardAccounts::<T>::get(validator);
-		Self::check_reward(validator)
+		ensure!(!UnclaimedEras::<T>::get(validator).is_empty(), Error::<T>::NoReward);
+		Ok(())
 	}
 
 	/// Verify the balance of reward
 	fn verify_balance(validator: T::AccountId) -> DispatchResult {
-		let validator_reward = ValidatorRewardAccounts::<T>::get(validator.clone());
 		let free_balance = T::RewardCurrency::free_balance(&Self::account_id());
-		let nominators = EraReward::<T>::get(validator.clone());
-		if nominators.is_empty() {
-			ensure!(free_balance >= validator_reward, Error::<T>::InsufficientRewardBalance);
-			return Ok(());
-		}
-		let mut total_nominator_reward: T::Balance = 0u128.into();
-		nominators.iter().for_each(|nominator| {
-			let nominator_reward = NominatorEarningsAccount::<T>::get(validator.clone(), nominator);
-			total_nominator_reward += nominator_reward;
+		let mut total_reward: T::Balance = Zero::zero();
+		UnclaimedEras::<T>::get(validator.clone()).into_iter().for_each(|era| {
+			total_reward += Self::pending_era_reward(era, validator.clone());
 		});
-		ensure!(
-			free_balance >= total_nominator_reward + validator_reward,
-			Error::<T>::InsufficientRewardBalance
-		);
+		ensure!(free_balance >= total_reward, Error::<T>::InsufficientRewardBalance);
 		return Ok(());
 	}
 
-	/// Calculates the commission for the validator.
-	fn validator_commission(validator: T::AccountId) -> u32 {
-		let validator_prefs = Validators::<T>::get(validator.clone());
-		let validator_commission = validator_prefs.commission.deconstruct();
-		let precision: u32 = 7;
-		let scaled_commission: u32 = validator_commission / (10u32).pow(precision);
-		scaled_commission
+	/// Total reward still owed to the validator and its nominators for the given era.
+	fn pending_era_reward(era: EraIndex, validator: T::AccountId) -> T::Balance {
+		EraNominatorRewards::<T>::iter_prefix_values((era, validator.clone()))
+			.fold(EraValidatorRewards::<T>::get(era, validator), |total, reward| total + reward)
 	}
 
 	/// Update the list of validators who have already been rewarded.
@@ -356,110 +636,577 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Compute the total era reward
-	pub fn calculate_era_reward() -> f64 {
-		let total_minutes_per_year = T::TotalMinutesPerYear::get();
-		let era_minutes = T::EraMinutes::get();
-		let era = total_minutes_per_year / era_minutes;
-		let total_reward = T::TotalReward::get();
-		let era_reward = total_reward / era;
-		era_reward.into()
+	/// Compute the total era reward, in the smallest unit of the reward currency, from the
+	/// emission schedule, the staking rate and the supply cap.
+	pub fn calculate_era_reward() -> T::Balance {
+		let schedule = Self::emission_schedule();
+		let eras_per_year = (T::TotalMinutesPerYear::get() / T::EraMinutes::get().max(1)).max(1);
+		let years = Self::active_era().saturating_sub(schedule.start_era) / eras_per_year;
+		let yearly_reward = (Perbill::one() - schedule.yearly_decay)
+			.saturating_pow(years as usize)
+			.mul_floor(schedule.yearly_reward);
+		let issuance = T::RewardCurrency::total_issuance();
+		let staked: u128 = pallet_staking::ErasTotalStake::<T>::get(Self::current_era()).into();
+		let staking_rate = Perbill::from_rational(staked, issuance.into());
+		let era_reward = Self::staking_rate_factor(&schedule, staking_rate)
+			.mul_floor(yearly_reward) /
+			(eras_per_year as u128).into();
+		era_reward.min(schedule.max_supply.saturating_sub(issuance))
 	}
 
-	/// Compute the reward share for a validator based on their stake.
-	pub fn calculate_reward_share(share: u128, total_stake: u128, reward: f64) -> f64 {
-		let precision = T::Precision::get();
-		let scaled_share = share / (10u128).pow(precision);
-		let scaled_total_stake: u64 = (total_stake / (10u128).pow(precision)) as u64;
-		let division: f64 = ((scaled_share as f64) / (scaled_total_stake as f64)) as f64;
-		let total_reward = division * reward;
-		total_reward
+	/// Part of the yearly reward emitted at the given staking rate, following the curve of
+	/// `pallet_staking::inflation`. It grows linearly from `min_reward` with no stake to the full
+	/// reward at `ideal_stake`, then the part above `min_reward` halves every `falloff`.
+	pub fn staking_rate_factor(
+		schedule: &EmissionSchedule<T::Balance>,
+		staking_rate: Perbill,
+	) -> Perbill {
+		let points = Self::staking_rate_curve(schedule);
+		let curve = PiecewiseLinear { points: &points, maximum: Perbill::one() };
+		Perbill::from_parts(curve.calculate_for_fraction_times_denominator(
+			staking_rate.deconstruct(),
+			Perbill::one().deconstruct(),
+		))
 	}
 
-	/// Calculates the total reward for a validator based on their commission and stake, as well as
-	/// the remaining reward for nominators.
-	fn calculate_validator_commission_reward(
-		validator: T::AccountId,
-		validator_era_reward: f64,
-		exposure: Exposure<<T as frame_system::Config>::AccountId, BalanceOf<T>>,
-	) -> (f64, f64) {
-		let validator_commission = Self::validator_commission(validator.clone());
-		let validator_share =
-			((validator_era_reward as f64) * (validator_commission as f64)) / 100.0;
-		let total_stake = exposure.total;
-		let validator_stake = exposure.own;
-		let remaining_reward = validator_era_reward - validator_share;
-		let validator_own_share_reward = Self::calculate_reward_share(
-			validator_stake.into(),
-			total_stake.into(),
-			remaining_reward,
-		);
-		let total_validator_reward = validator_share + validator_own_share_reward;
-		(total_validator_reward, remaining_reward)
+	/// Points of the staking rate curve of the schedule, approximating the exponential falloff
+	/// past the ideal stake by a point every quarter of the falloff.
+	fn staking_rate_curve(schedule: &EmissionSchedule<T::Balance>) -> Vec<(Perbill, Perbill)> {
+		let ideal_stake = schedule.ideal_stake.max(Perbill::from_parts(1));
+		let min_reward = schedule.min_reward;
+		let step =
+			Perbill::from_parts(schedule.falloff.deconstruct() / 4).max(Perbill::from_parts(1));
+		let mut points = sp_std::vec![(Perbill::zero(), min_reward), (ideal_stake, Perbill::one())];
+		let (mut stake, mut excess) = (ideal_stake, Perbill::one() - min_reward);
+		for _ in 0..MAX_FALLOFF_POINTS {
+			if stake == Perbill::one() {
+				break;
+			}
+			let next_excess = excess * QUARTER_FALLOFF_RATIO;
+			let next_stake = stake.saturating_add(step);
+			// the last step is cut at full stake
+			let next_excess = if next_stake == Perbill::one() {
+				let cut =
+					Perbill::from_rational((next_stake - stake).deconstruct(), step.deconstruct());
+				excess - cut * (excess - next_excess)
+			} else {
+				next_excess
+			};
+			(stake, excess) = (next_stake, next_excess);
+			points.push((stake, min_reward.saturating_add(excess)));
+		}
+		points
+	}
+
+	/// Applies the emission schedule set by governance, if any, from the ending era on.
+	fn enact_emission_schedule() {
+		if let Some(mut schedule) = PendingEmissionSchedule::<T>::take() {
+			let era = Self::active_era();
+			let current = Self::emission_schedule();
+			schedule.start_era = if schedule.yearly_reward == current.yearly_reward {
+				current.start_era
+			} else {
+				era
+			};
+			CurrentEmissionSchedule::<T>::put(schedule);
+			Self::deposit_event(Event::EmissionScheduleEnacted { era });
+		}
 	}
 
-	/// Converts a floating-point number to an unsigned 128-bit integer.
-	pub fn convert_float64_to_unsigned128(value: f64) -> u128 {
-		let precision = T::Precision::get();
-		let multiplier = (10u128).pow(precision);
-		let number = (value * (multiplier as f64)) as u128;
-		number
+	/// Compute the `share / total_stake` part of `reward`, rounding down.
+	///
+	/// Returns zero when `total_stake` is zero, and never more than `reward` when
+	/// `share <= total_stake`.
+	pub fn calculate_reward_share(share: u128, total_stake: u128, reward: u128) -> u128 {
+		if share >= total_stake {
+			return if total_stake.is_zero() { 0 } else { reward };
+		}
+		multiply_by_rational_with_rounding(reward, share, total_stake, Rounding::Down).unwrap_or(0)
+	}
+
+	/// Splits the era reward of a validator into the validator payout (commission plus the share
+	/// of its own stake) and one payout per entry of `nominator_stakes`.
+	///
+	/// Every part is rounded down, so the sum of the returned payouts never exceeds `reward`.
+	pub fn split_validator_reward(
+		reward: u128,
+		commission: Perbill,
+		own_stake: u128,
+		total_stake: u128,
+		nominator_stakes: &[u128],
+	) -> (u128, Vec<u128>) {
+		let commission_reward = commission.mul_floor(reward);
+		let remaining_reward = reward.saturating_sub(commission_reward);
+		let own_reward = Self::calculate_reward_share(own_stake, total_stake, remaining_reward);
+		let mut distributed = own_reward;
+		let nominator_rewards = nominator_stakes
+			.iter()
+			.map(|stake| {
+				let share = Self::calculate_reward_share(*stake, total_stake, remaining_reward)
+					.min(remaining_reward.saturating_sub(distributed));
+				distributed = distributed.saturating_add(share);
+				share
+			})
+			.collect();
+		(commission_reward.saturating_add(own_reward), nominator_rewards)
+	}
+
+	/// Transfers the part of the era reward left over by rounding to the remainder account.
+	fn route_remainder(remainder: T::Balance) {
+		if remainder.is_zero() {
+			return;
+		}
+		Self::release_outstanding_rewards(remainder);
+		let who = T::RewardRemainderAccount::get();
+		if T::RewardCurrency::transfer(&Self::account_id(), &who, remainder, KeepAlive).is_ok() {
+			Self::deposit_event(Event::RemainderRouted { who, balance: remainder });
+		}
 	}
 
-	/// Allocates rewards to the specified validator.
+	/// Allocates rewards of the given era to the specified validator.
 	fn allocate_rewards(
+		era: EraIndex,
 		validator: T::AccountId,
 		nominator: Option<T::AccountId>,
 		reward: T::Balance,
 	) {
+		if reward.is_zero() {
+			return;
+		}
 		if let Some(nominator) = nominator {
-			NominatorEarningsAccount::<T>::mutate(validator, nominator.clone(), |earlier_reward| {
-				*earlier_reward += reward;
-			})
+			EraNominatorRewards::<T>::mutate(
+				(era, validator.clone(), nominator.clone()),
+				|earlier_reward| {
+					*earlier_reward += reward;
+				},
+			);
+			NominatorRewardEras::<T>::insert((nominator, era, validator.clone()), ());
 		} else {
-			ValidatorRewardAccounts::<T>::mutate(validator.clone(), |earlier_reward| {
+			EraValidatorRewards::<T>::mutate(era, validator.clone(), |earlier_reward| {
 				*earlier_reward += reward;
 			})
 		}
+		UnclaimedEras::<T>::mutate(validator, |eras| {
+			if !eras.contains(&era) {
+				// eras past the history depth are expired, their rewards can no longer be claimed
+				let history_depth = <T as pallet_staking::Config>::HistoryDepth::get();
+				eras.retain(|unclaimed| unclaimed.saturating_add(history_depth) > era);
+				// the retained eras are distinct and within the history depth, so `era` fits
+				let _ = eras.try_push(era);
+			}
+		});
 	}
 
 	/// Retrieves the points of the validator.
-	fn retrieve_validator_point(account: T::AccountId) -> u32 {
-		let era_reward_points = <ErasRewardPoints<T>>::get(Self::active_era());
+	fn retrieve_validator_point(era: EraIndex, account: T::AccountId) -> u32 {
+		let era_reward_points = <ErasRewardPoints<T>>::get(era);
 		let validator_points = era_reward_points.individual.get(&account).unwrap_or(&0);
 		*validator_points
 	}
 
-	/// Distributes rewards to the validator and nominators.
+	/// Distributes the rewards of an era to the validator and nominators.
+	fn distribute_era_rewards(era: EraIndex, validator: T::AccountId) {
+		let _ = Self::distribute_reward(era, validator.clone(), None);
+		let nominators: Vec<T::AccountId> =
+			EraNominatorRewards::<T>::iter_key_prefix((era, validator.clone())).collect();
+		nominators.into_iter().for_each(|nominator| {
+			let _ = Self::distribute_reward(era, validator.clone(), Some(nominator));
+		});
+		Self::prune_claimed_era(era, validator);
+	}
+
+	/// Rewards owed to the nominator through the validator, per unclaimed era.
+	fn nominator_claims(
+		validator: T::AccountId,
+		nominator: T::AccountId,
+	) -> Vec<(EraIndex, T::AccountId, T::Balance)> {
+		UnclaimedEras::<T>::get(validator.clone())
+			.into_iter()
+			.filter_map(|era| {
+				let reward =
+					EraNominatorRewards::<T>::get((era, validator.clone(), nominator.clone()));
+				(!reward.is_zero()).then(|| (era, validator.clone(), reward))
+			})
+			.collect()
+	}
+
+	/// Rewards owed to the nominator through any validator, per unclaimed era.
+	fn all_nominator_claims(
+		nominator: T::AccountId,
+	) -> impl Iterator<Item = (EraIndex, T::AccountId, T::Balance)> {
+		NominatorRewardEras::<T>::iter_key_prefix((nominator.clone(),)).map(
+			move |(era, validator)| {
+				let reward =
+					EraNominatorRewards::<T>::get((era, validator.clone(), nominator.clone()));
+				(era, validator, reward)
+			},
+		)
+	}
+
+	/// Pays the nominator the given era rewards, returning the number of rewards paid.
+	fn distribute_nominator_rewards(
+		nominator: T::AccountId,
+		claims: Vec<(EraIndex, T::AccountId, T::Balance)>,
+	) -> Result<u32, DispatchError> {
+		ensure!(!claims.is_empty(), Error::<T>::NoReward);
+		let total_reward =
+			claims.iter().fold(T::Balance::zero(), |total, (_, _, reward)| total + *reward);
+		ensure!(
+			T::RewardCurrency::free_balance(&Self::account_id()) >= total_reward,
+			Error::<T>::InsufficientRewardBalance
+		);
+		let paid = claims.len() as u32;
+		for (era, validator, _) in claims {
+			Self::distribute_reward(era, validator.clone(), Some(nominator.clone()))?;
+			Self::prune_claimed_era(era, validator);
+		}
+		Ok(paid)
+	}
+
+	/// Removes the era from the unclaimed eras of the validator once every reward of the
+	/// validator and its nominators in that era has been paid.
+	fn prune_claimed_era(era: EraIndex, validator: T::AccountId) {
+		if !EraValidatorRewards::<T>::contains_key(era, validator.clone()) &&
+			EraNominatorRewards::<T>::iter_key_prefix((era, validator.clone()))
+				.next()
+				.is_none()
+		{
+			Self::remove_unclaimed_era(era, validator);
+		}
+	}
+
+	/// Distributes the reward of an era to the validator or one of its nominators.
 	fn distribute_reward(
+		era: EraIndex,
 		validator: T::AccountId,
 		nominator: Option<T::AccountId>,
 	) -> DispatchResult {
-		let (reward, recipient) = if let Some(nominator) = nominator {
-			let reward = NominatorEarningsAccount::<T>::get(validator.clone(), nominator.clone());
+		if let Some(nominator) = nominator {
+			let reward = EraNominatorRewards::<T>::get((era, validator.clone(), nominator.clone()));
 			Self::check_reward(reward)?;
-			Self::transfer(Self::account_id(), nominator.clone(), reward, KeepAlive)?;
-			let mut nominators = EraReward::<T>::get(validator.clone());
-			if let Some(index) = nominators
-				.iter()
-				.position(|nominator_account| nominator_account == &nominator.clone())
-			{
-				nominators.remove(index);
-			}
-			EraReward::<T>::insert(validator.clone(), nominators.clone());
-			NominatorEarningsAccount::<T>::remove(validator.clone(), nominator.clone());
-			(reward, nominator)
+			Self::pay_reward(nominator.clone(), reward)?;
+			EraNominatorRewards::<T>::remove((era, validator.clone(), nominator.clone()));
+			NominatorRewardEras::<T>::remove((nominator, era, validator));
 		} else {
-			let reward = ValidatorRewardAccounts::<T>::get(validator.clone());
+			let reward = EraValidatorRewards::<T>::get(era, validator.clone());
 			Self::check_reward(reward)?;
-			Self::transfer(Self::account_id(), validator.clone(), reward, KeepAlive)?;
-			ValidatorRewardAccounts::<T>::remove(validator.clone());
-			(reward, validator)
+			Self::pay_reward(validator.clone(), reward)?;
+			EraValidatorRewards::<T>::remove(era, validator);
+		}
+		Ok(())
+	}
+
+	/// Pays the reward of `stash` out of the reward pot to its `Payee` reward destination.
+	fn pay_reward(stash: T::AccountId, reward: T::Balance) -> DispatchResult {
+		let destination = Payee::<T>::get(stash.clone());
+		let dest = match destination.clone() {
+			// A stash without a reward destination is paid like a `Stash` one.
+			RewardDestination::Staked | RewardDestination::Stash | RewardDestination::None =>
+				stash.clone(),
+			RewardDestination::Controller =>
+				Bonded::<T>::get(stash.clone()).unwrap_or(stash.clone()),
+			RewardDestination::Account(account) => account,
 		};
-		Self::store_reward_received(recipient, reward);
+		Self::transfer(Self::account_id(), dest, reward, KeepAlive)?;
+		Self::release_outstanding_rewards(reward);
+		if destination == RewardDestination::Staked {
+			// A stash that is no longer bonded keeps the reward as free balance.
+			let extra: u128 = reward.into();
+			if let Err(e) = <pallet_staking::Pallet<T> as StakingInterface>::bond_extra(
+				&stash,
+				extra.unique_saturated_into(),
+			) {
+				log::warn!(
+					target: "runtime::reward",
+					"failed to restake the reward of {:?}: {:?}",
+					stash,
+					e,
+				);
+				Self::deposit_event(Event::<T>::RestakeFailed {
+					stash: stash.clone(),
+					balance: reward,
+				});
+			}
+		}
+		Self::store_reward_received(stash, reward);
 		Ok(())
 	}
 
+	/// Removes rewards that left the reward pot, or are no longer owed, from the outstanding
+	/// rewards.
+	fn release_outstanding_rewards(released: T::Balance) {
+		OutstandingRewards::<T>::mutate(|outstanding| {
+			*outstanding = outstanding.saturating_sub(released)
+		});
+	}
+
+	/// Removes the era from the unclaimed eras of the validator.
+	fn remove_unclaimed_era(era: EraIndex, validator: T::AccountId) {
+		UnclaimedEras::<T>::mutate_exists(validator, |maybe_eras| {
+			if let Some(eras) = maybe_eras {
+				eras.retain(|unclaimed| *unclaimed != era);
+				if eras.is_empty() {
+					*maybe_eras = None;
+				}
+			}
+		});
+	}
+
+	/// Number of expired rewards the `expire_era_rewards` benchmark ranges over, as many as a
+	/// page of validators and their nominators can earn.
+	fn expired_rewards_per_page() -> u32 {
+		T::RewardPageSize::get().saturating_mul(
+			<T as pallet_staking::Config>::MaxNominatorRewardedPerValidator::get()
+				.saturating_add(1),
+		)
+	}
+
+	/// Weight of processing a page of a reward distribution without any step, reading and
+	/// writing the queue, the progress, the page of the distribution and the next era to expire.
+	fn reward_page_overhead() -> Weight {
+		<T as pallet::Config>::WeightInfo::expire_era_rewards(0)
+			.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+	}
+
+	/// Weight of a single step of the given stage of a reward distribution: returning an
+	/// expired reward, computing the rewards of a validator or paying an era of a validator.
+	fn reward_step_weight(stage: DistributionStage) -> Weight {
+		let nominators = <T as pallet_staking::Config>::MaxNominatorRewardedPerValidator::get();
+		match stage {
+			DistributionStage::Expire => <T as pallet::Config>::WeightInfo::expire_era_rewards(1)
+				.saturating_sub(<T as pallet::Config>::WeightInfo::expire_era_rewards(0)),
+			DistributionStage::Calculate =>
+				<T as pallet::Config>::WeightInfo::calculate_validator_reward(nominators),
+			DistributionStage::Payout =>
+				<T as pallet::Config>::WeightInfo::claim_era_rewards(nominators),
+		}
+	}
+
+	/// Processes as many steps of the current stage of the oldest pending reward distribution
+	/// as fit in `max_weight`. Returns the weight consumed, or `None` if there was nothing to
+	/// process or not a single step fits.
+	fn process_reward_page(max_weight: Weight) -> Option<Weight> {
+		let overhead = Self::reward_page_overhead();
+		let budget = max_weight.checked_sub(&overhead)?;
+		let era = PendingRewardEras::<T>::get().first().copied()?;
+		let Some(mut distribution) = RewardDistributions::<T>::get(era) else {
+			PendingRewardEras::<T>::mutate(|eras| eras.retain(|pending| *pending != era));
+			return Some(overhead);
+		};
+		let stage = distribution.stage;
+		let step_weight = Self::reward_step_weight(stage);
+		let steps = Self::steps_within(budget, step_weight);
+		if steps == 0 {
+			return None;
+		}
+		let mut taken = 0;
+		let mut completed = false;
+
+		match stage {
+			DistributionStage::Expire => {
+				let history_depth = <T as pallet_staking::Config>::HistoryDepth::get();
+				let last_expired = era.checked_sub(history_depth);
+				let mut expiring = NextExpiringEra::<T>::get();
+				let mut limit = steps;
+				while limit > 0 && last_expired.map_or(false, |last| expiring <= last) {
+					// looking up the rewards of an era costs as much as draining one of them
+					limit -= 1;
+					let (drained, expired) = Self::drain_expired_rewards(expiring, limit);
+					distribution.expired += expired;
+					if drained == limit {
+						limit = 0;
+						break;
+					}
+					limit -= drained;
+					Self::return_expired_rewards(expiring, distribution.expired);
+					distribution.expired = Zero::zero();
+					expiring += 1;
+				}
+				taken = steps - limit;
+				NextExpiringEra::<T>::put(expiring);
+				if last_expired.map_or(true, |last| expiring > last) {
+					distribution.stage = DistributionStage::Calculate;
+				}
+			},
+			DistributionStage::Calculate => {
+				let mut validators =
+					DistributionValidators::<T>::take(era, distribution.next_page).into_inner();
+				let remaining = validators.split_off(steps.min(validators.len()));
+				let era_reward: u128 = distribution.era_reward.into();
+				let mut allocated: u128 = distribution.allocated.into();
+				validators.into_iter().for_each(|validator| {
+					taken += 1;
+					allocated = allocated.saturating_add(Self::allocate_validator_rewards(
+						era,
+						distribution.stakers_era,
+						era_reward,
+						validator,
+					));
+				});
+				distribution.allocated = allocated.into();
+				if remaining.is_empty() {
+					distribution.next_page = distribution.next_page.saturating_add(1);
+				} else {
+					// the rest of the page is computed on the next one
+					DistributionValidators::<T>::insert(
+						era,
+						distribution.next_page,
+						BoundedVec::truncate_from(remaining),
+					);
+				}
+				if distribution.next_page >= distribution.validator_pages {
+					Self::route_remainder(era_reward.saturating_sub(allocated).into());
+					distribution.stage = DistributionStage::Payout;
+				}
+			},
+			DistributionStage::Payout => {
+				let mut validators = EraRewardsVault::<T>::get().unwrap_or_default();
+				while taken < steps {
+					let Some((validator, from)) = distribution
+						.payout
+						.take()
+						.or_else(|| (!validators.is_empty()).then(|| (validators.remove(0), 0)))
+					else {
+						break;
+					};
+					let mut eras: Vec<EraIndex> = UnclaimedEras::<T>::get(validator.clone())
+						.into_iter()
+						.filter(|unclaimed| *unclaimed >= from)
+						.collect();
+					eras.sort();
+					if eras.is_empty() {
+						// a validator with nothing left to pay still costs reading its unclaimed
+						// eras
+						taken += 1;
+					}
+					for unclaimed in eras {
+						if taken == steps {
+							distribution.payout = Some((validator.clone(), unclaimed));
+							break;
+						}
+						taken += 1;
+						let free_balance = T::RewardCurrency::free_balance(&Self::account_id());
+						if Self::pending_era_reward(unclaimed, validator.clone()) > free_balance {
+							Self::deposit_event(Event::InsufficientRewardBalance);
+							continue;
+						}
+						Self::distribute_era_rewards(unclaimed, validator.clone());
+					}
+				}
+				completed = distribution.payout.is_none() && validators.is_empty();
+				if validators.is_empty() {
+					EraRewardsVault::<T>::kill();
+				} else {
+					EraRewardsVault::<T>::put(validators);
+				}
+			},
+		}
+
+		distribution.pages = distribution.pages.saturating_add(1);
+		Self::deposit_event(Event::RewardPageProcessed { era, stage, page: distribution.pages });
+		if completed {
+			Self::deposit_event(Event::RewardDistributionCompleted {
+				era,
+				pages: distribution.pages,
+			});
+			RewardDistributions::<T>::remove(era);
+			PendingRewardEras::<T>::mutate(|eras| eras.retain(|pending| *pending != era));
+		} else {
+			RewardDistributions::<T>::insert(era, distribution);
+		}
+		Some(overhead.saturating_add(step_weight.saturating_mul(taken as u64)))
+	}
+
+	/// Number of steps of `step_weight` that fit in `budget`.
+	fn steps_within(budget: Weight, step_weight: Weight) -> usize {
+		let ref_time = budget.ref_time().checked_div(step_weight.ref_time()).unwrap_or(u64::MAX);
+		let proof_size =
+			budget.proof_size().checked_div(step_weight.proof_size()).unwrap_or(u64::MAX);
+		ref_time.min(proof_size).saturated_into()
+	}
+
+	/// Computes and allocates the rewards of the validator and its nominators for `era`,
+	/// returning the allocated amount.
+	fn allocate_validator_rewards(
+		era: EraIndex,
+		stakers_era: EraIndex,
+		era_reward: u128,
+		validator: T::AccountId,
+	) -> u128 {
+		let validator_points = Self::retrieve_validator_point(era, validator.clone());
+		let validator_exposure = ErasStakers::<T>::get(stakers_era, validator.clone());
+		let validator_era_reward = Self::calculate_validator_era_reward(
+			era,
+			stakers_era,
+			validator_points,
+			validator_exposure.total,
+			era_reward,
+		);
+		let nominator_stakes: Vec<u128> = validator_exposure
+			.others
+			.iter()
+			.map(|nominator| nominator.value.into())
+			.collect();
+		let (validator_reward, nominator_rewards) = Self::split_validator_reward(
+			validator_era_reward,
+			Validators::<T>::get(validator.clone()).commission,
+			validator_exposure.own.into(),
+			validator_exposure.total.into(),
+			&nominator_stakes,
+		);
+		Self::allocate_rewards(era, validator.clone(), None, validator_reward.into());
+		let mut allocated = validator_reward;
+
+		validator_exposure.others.iter().zip(nominator_rewards).for_each(
+			|(nominator, nominator_reward)| {
+				Self::allocate_rewards(
+					era,
+					validator.clone(),
+					Some(nominator.who.clone()),
+					nominator_reward.into(),
+				);
+				allocated = allocated.saturating_add(nominator_reward);
+			},
+		);
+		allocated
+	}
+
+	/// Drains up to `limit` unclaimed rewards of the expired `era`, returning how many were
+	/// drained and their total.
+	fn drain_expired_rewards(era: EraIndex, limit: usize) -> (usize, T::Balance) {
+		let mut drained = 0;
+		let mut expired: T::Balance = Zero::zero();
+		EraValidatorRewards::<T>::drain_prefix(era)
+			.take(limit)
+			.for_each(|(validator, reward)| {
+				drained += 1;
+				expired += reward;
+				Self::remove_unclaimed_era(era, validator);
+			});
+		EraNominatorRewards::<T>::drain_prefix((era,)).take(limit - drained).for_each(
+			|((validator, nominator), reward)| {
+				drained += 1;
+				expired += reward;
+				NominatorRewardEras::<T>::remove((nominator, era, validator.clone()));
+				Self::remove_unclaimed_era(era, validator);
+			},
+		);
+		(drained, expired)
+	}
+
+	/// Returns the rewards of `era` left unclaimed for `HistoryDepth` eras to the remainder
+	/// account.
+	fn return_expired_rewards(era: EraIndex, expired: T::Balance) {
+		if expired.is_zero() {
+			return;
+		}
+		Self::release_outstanding_rewards(expired);
+		let who = T::RewardRemainderAccount::get();
+		if T::RewardCurrency::transfer(&Self::account_id(), &who, expired, KeepAlive).is_ok() {
+			Self::deposit_event(Event::RewardsExpired { era, balance: expired });
+		}
+	}
+
 	/// Current era index
 	fn current_era() -> u32 {
 		CurrentEra::<T>::get().unwrap_or(0)
@@ -467,9 +1214,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Active era index
 	fn active_era() -> u32 {
-		let active_era = pallet_staking::Pallet::<T>::active_era().unwrap();
-		let era = active_era.index;
-		era
+		pallet_staking::Pallet::<T>::active_era().map_or(0, |active_era| active_era.index)
 	}
 
 	/// Store the received reward for a specific account.
@@ -490,25 +1235,71 @@ impl<T: Config> Pallet<T> {
 
 	/// Compute the reward of the validator
 	fn calculate_validator_era_reward(
+		era: EraIndex,
+		stakers_era: EraIndex,
 		validator_points: u32,
 		validator_stake: T::CurrencyBalance,
-		era_reward: f64,
-	) -> f64 {
-		let era_reward_points = <ErasRewardPoints<T>>::get(Self::active_era());
-		let validator_points_stake = validator_points as u128 + validator_stake.into();
-		let total_stake = pallet_staking::ErasTotalStake::<T>::get(Self::current_era());
-		let total_points = era_reward_points.total as u128 + total_stake.into();
-		let reward =
-			((validator_points_stake as f64) / (total_points as f64)) * (era_reward as f64);
-		reward
-	}
-
-	/// Determine whether the validator has nominators in the current era.
-	fn check_nominators(
-		validator: T::AccountId,
-	) -> Vec<IndividualExposure<T::AccountId, <T as pallet_staking::Config>::CurrencyBalance>> {
-		let exposure = ErasStakers::<T>::get(Self::current_era(), validator.clone());
-		let nominators = exposure.others;
-		nominators
+		era_reward: u128,
+	) -> u128 {
+		let era_reward_points = <ErasRewardPoints<T>>::get(era);
+		let validator_points_stake =
+			(validator_points as u128).saturating_add(validator_stake.into());
+		let total_stake = pallet_staking::ErasTotalStake::<T>::get(stakers_era);
+		let total_points = (era_reward_points.total as u128).saturating_add(total_stake.into());
+		Self::calculate_reward_share(validator_points_stake, total_points, era_reward)
+	}
+
+	/// Rewards still owed to `validator` for its own stake and commission, over every unclaimed
+	/// era.
+	pub fn pending_validator_rewards(validator: T::AccountId) -> T::Balance {
+		UnclaimedEras::<T>::get(validator.clone())
+			.into_iter()
+			.fold(Zero::zero(), |total, era| {
+				total + EraValidatorRewards::<T>::get(era, validator.clone())
+			})
+	}
+
+	/// Rewards still owed to `nominator` through the validators it nominates or has nominated.
+	pub fn pending_nominator_rewards(nominator: T::AccountId) -> T::Balance {
+		Self::all_nominator_claims(nominator)
+			.fold(Zero::zero(), |total, (_, _, reward)| total + reward)
+	}
+
+	/// Rewards `who` would receive, as a validator or a nominator, if the active era ended now.
+	pub fn projected_era_reward(who: T::AccountId) -> T::Balance {
+		let era = Self::active_era();
+		let stakers_era = Self::current_era();
+		let era_reward: u128 = Self::calculate_era_reward().into();
+		let projected = ErasStakers::<T>::iter_prefix(stakers_era).fold(
+			0u128,
+			|total, (validator, exposure)| {
+				let nominator_position = exposure.others.iter().position(|n| n.who == who);
+				if validator != who && nominator_position.is_none() {
+					return total;
+				}
+				let validator_era_reward = Self::calculate_validator_era_reward(
+					era,
+					stakers_era,
+					Self::retrieve_validator_point(era, validator.clone()),
+					exposure.total,
+					era_reward,
+				);
+				let nominator_stakes: Vec<u128> =
+					exposure.others.iter().map(|nominator| nominator.value.into()).collect();
+				let (validator_reward, nominator_rewards) = Self::split_validator_reward(
+					validator_era_reward,
+					Validators::<T>::get(validator.clone()).commission,
+					exposure.own.into(),
+					exposure.total.into(),
+					&nominator_stakes,
+				);
+				let mut reward = if validator == who { validator_reward } else { 0 };
+				if let Some(position) = nominator_position {
+					reward = reward.saturating_add(nominator_rewards[position]);
+				}
+				total.saturating_add(reward)
+			},
+		);
+		projected.into()
 	}
 }
@@ -1,5 +1,5 @@
 use super::*;
-use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::{storage_alias, traits::OnRuntimeUpgrade};
 
 use log::{log, Level};
 
@@ -12,6 +12,39 @@ pub mod v1 {
 	use super::*;
 	const TARGET: &'static str = "runtime::reward::migration::v1";
 
+	/// Cumulative rewards of the validators, replaced by `EraValidatorRewards` in v2.
+	#[storage_alias]
+	pub type ValidatorRewardAccounts<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		ValueQuery,
+	>;
+
+	/// Cumulative rewards of the nominators per validator, replaced by `EraNominatorRewards` in
+	/// v2.
+	#[storage_alias]
+	pub type NominatorEarningsAccount<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		ValueQuery,
+	>;
+
+	/// Nominators of the validator with a pending reward, replaced by `UnclaimedEras` in v2.
+	#[storage_alias]
+	pub type EraReward<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Vec<<T as frame_system::Config>::AccountId>,
+		ValueQuery,
+	>;
+
 	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
 		fn on_runtime_upgrade() -> Weight {
@@ -74,3 +107,89 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+
+	use super::*;
+	const TARGET: &str = "runtime::reward::migration::v2";
+
+	/// Moves the cumulative v1 reward accounts into the era-indexed ledger. As v1 does not record
+	/// the era a reward belongs to, every pending reward is booked in the active era.
+	///
+	/// The pending rewards are recorded as the outstanding rewards of the reward pot. From v2 on,
+	/// the era rewards are paid out of the free balance of the pot beyond them, and only the
+	/// shortfall is minted, so the pot no longer needs to be funded ahead of the eras.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			log::info!(
+				target: TARGET,
+				"Running migration with onchain storage version {:?}",
+				onchain_version
+			);
+
+			if onchain_version == 1 {
+				let era =
+					pallet_staking::Pallet::<T>::active_era().map(|era| era.index).unwrap_or(0);
+				let mut count = 0;
+				let mut outstanding: T::Balance = Zero::zero();
+				for (validator, balance) in v1::ValidatorRewardAccounts::<T>::drain() {
+					Pallet::<T>::allocate_rewards(era, validator, None, balance);
+					outstanding += balance;
+					count += 1;
+				}
+				for (validator, nominator, balance) in v1::NominatorEarningsAccount::<T>::drain() {
+					Pallet::<T>::allocate_rewards(era, validator, Some(nominator), balance);
+					outstanding += balance;
+					count += 1;
+				}
+				count += v1::EraReward::<T>::drain().count();
+				OutstandingRewards::<T>::put(outstanding);
+				StorageVersion::new(2).put::<Pallet<T>>();
+				log!(Level::Info, "reward v2 applied successfully");
+				T::DbWeight::get().reads_writes((count as u64) + 2, (count as u64) * 2 + 2)
+			} else {
+				log!(Level::Warn, "Skipping reward v2, should be removed");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let total: T::Balance = v1::ValidatorRewardAccounts::<T>::iter_values()
+				.chain(v1::NominatorEarningsAccount::<T>::iter_values())
+				.fold(Zero::zero(), |total, reward| total + reward);
+			Ok(total.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(total: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let prev_total: T::Balance = Decode::decode(&mut total.as_slice()).expect(
+				"the state parameter should be something that was generated by pre_upgrade",
+			);
+			ensure!(
+				v1::ValidatorRewardAccounts::<T>::iter().count() == 0,
+				"count should be 0 in ValidatorRewardAccounts"
+			);
+			ensure!(
+				v1::NominatorEarningsAccount::<T>::iter().count() == 0,
+				"count should be 0 in NominatorEarningsAccount"
+			);
+
+			// the pending rewards must be preserved by the era-indexed ledger
+			let post_total: T::Balance = EraValidatorRewards::<T>::iter_values()
+				.chain(EraNominatorRewards::<T>::iter_values())
+				.fold(Zero::zero(), |total, reward| total + reward);
+			ensure!(prev_total == post_total, "pending rewards should be the same");
+			ensure!(
+				OutstandingRewards::<T>::get() == prev_total,
+				"the pending rewards should be outstanding"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "wrong storage version");
+
+			Ok(())
+		}
+	}
+}
@@ -227,6 +227,7 @@ parameter_types! {
 	pub const TotalMinutesPerYear:u32 = 525600;
 	pub const TotalReward :u32 = 20564830;
 	pub const RewardPalletId: PalletId = PalletId(*b"py/rewrd");
+	pub const RewardRemainderAccount: AccountId = 99;
 }
 
 impl pallet_reward::Config for Test {
@@ -240,6 +241,9 @@ impl pallet_reward::Config for Test {
 	type TotalMinutesPerYear = TotalMinutesPerYear;
 	type EraMinutes = EraMinutes;
 	type TotalReward = TotalReward;
+	type RewardRemainderAccount = RewardRemainderAccount;
+	type RewardPageSize = ConstU32<2>;
+	type EmissionOrigin = frame_system::EnsureRoot<AccountId>;
 	type PalletId = RewardPalletId;
 	type WeightInfo = ();
 }
@@ -258,6 +262,15 @@ parameter_types! {
 	pub MaxOnChainElectableTargets: u16 = 1250;
 }
 
+parameter_types! {
+	pub const UptimeSmoothing: Perbill = Perbill::from_percent(10);
+	pub const MissedSessionPenalty: Perbill = Perbill::from_percent(25);
+	pub const AuthoringWeight: Perbill = Perbill::from_percent(50);
+	pub const RecoverySessions: u32 = 4;
+	pub const MaxReliabilityHistory: u32 = 4;
+	pub const MaxWaitingScoresPerBlock: u32 = 100;
+}
+
 impl pallet_im_online::Config for Test {
 	type AuthorityId = UintAuthorityId;
 	type RuntimeEvent = RuntimeEvent;
@@ -270,6 +283,19 @@ impl pallet_im_online::Config for Test {
 	type MaxPeerInHeartbeats = ConstU32<10_000>;
 	type DataProvider = Staking;
 	type TargetsBound = MaxOnChainElectableTargets;
+	type UptimeSmoothing = UptimeSmoothing;
+	type MissedSessionPenalty = MissedSessionPenalty;
+	type AuthoringWeight = AuthoringWeight;
+	type RecoverySessions = RecoverySessions;
+	type MaxReliabilityHistory = MaxReliabilityHistory;
+	type MaxWaitingScoresPerBlock = MaxWaitingScoresPerBlock;
+}
+
+parameter_types! {
+	pub const EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 1, social: 1, governance: 1 };
 }
 
 impl pallet_esg::Config for Test {
@@ -278,6 +304,21 @@ impl pallet_esg::Config for Test {
 	type MaxFileSize = ConstU32<1024000>;
 	type MaxNumOfSudoOracles = ConstU32<5>;
 	type MaxNumOfNonSudoOracles = ConstU32<5>;
+	type MaxScoreHistory = ConstU32<16>;
+	type MaxBatch = ConstU32<100>;
+	type SubmissionQuorum = ConstU32<1>;
+	type OutlierThreshold = frame_support::traits::ConstU16<20>;
+	type ScoreValidity = ConstU64<{ u64::MAX }>;
+	type ScoreDecayPeriod = ConstU64<0>;
+	type Currency = Balances;
+	type OracleDeposit = frame_support::traits::ConstU128<0>;
+	type OracleTerm = ConstU64<{ u64::MAX }>;
+	type SlashOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Slash = ();
+	type DisputeDeposit = frame_support::traits::ConstU128<0>;
+	type DisputeResolveOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
 }
 
 impl pallet_offences::Config for Test {
@@ -370,6 +411,10 @@ impl ExtBuilder {
 	}
 }
 
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default().build()
+}
+
 /// Progresses from the current block number (whatever that may be) to the `P * session_index + 1`.
 pub(crate) fn start_session(session_index: SessionIndex) {
 	let end: u64 = if Offset::get().is_zero() {
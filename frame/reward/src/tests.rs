@@ -1,9 +1,18 @@
 use crate::{
-	mock::*, EraReward, Error, NominatorEarningsAccount, Rewards, ValidatorRewardAccounts,
+	migration, mock::*, CurrentEmissionSchedule, DistributionStage, DistributionValidators,
+	EmissionSchedule, EraNominatorRewards, EraRewardsVault, EraValidatorRewards, Error,
+	NextExpiringEra, NominatorRewardEras, OutstandingRewards, PendingRewardEras,
+	RewardDistribution, RewardDistributions, Rewards, UnclaimedEras,
+};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{Currency, Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
 };
-use frame_support::{assert_noop, assert_ok, traits::Currency};
 use frame_system::Event;
-use sp_runtime::Perbill;
+use pallet_staking::RewardDestination;
+use sp_runtime::{traits::Zero, Perbill};
 
 pub const VALIDATOR: u64 = 11;
 pub const NOMINATOR: u64 = 22;
@@ -21,11 +30,55 @@ pub fn assert_last_event(generic_event: RuntimeEvent) {
 	assert_eq!(event, &system_event);
 }
 
+pub fn distribution(era: u32) -> RewardDistribution<u64, u128> {
+	RewardDistribution {
+		era,
+		stakers_era: era,
+		stage: DistributionStage::Expire,
+		era_reward: 0,
+		allocated: 0,
+		expired: 0,
+		validator_pages: 0,
+		next_page: 0,
+		payout: None,
+		pages: 0,
+	}
+}
+
+pub fn schedule_distribution(distribution: RewardDistribution<u64, u128>) {
+	assert_ok!(PendingRewardEras::<Test>::try_append(distribution.era));
+	RewardDistributions::<Test>::insert(distribution.era, distribution);
+}
+
+/// Weight of a page of `steps` steps of the given stage of a reward distribution.
+pub fn page_weight(stage: DistributionStage, steps: u64) -> Weight {
+	Reward::reward_page_overhead()
+		.saturating_add(Reward::reward_step_weight(stage).saturating_mul(steps))
+}
+
+pub fn set_active_era(index: u32) {
+	pallet_staking::ActiveEra::<Test>::mutate(|active_era| {
+		active_era.as_mut().expect("the active era is set at genesis").index = index
+	});
+}
+
+pub fn emission_schedule(yearly_reward: u128) -> EmissionSchedule<u128> {
+	EmissionSchedule {
+		yearly_reward,
+		yearly_decay: Perbill::zero(),
+		ideal_stake: Perbill::one(),
+		min_reward: Perbill::one(),
+		falloff: Perbill::from_percent(5),
+		max_supply: u128::MAX,
+		start_era: 0,
+	}
+}
+
 #[test]
 fn get_rewards_should_work() {
 	ExtBuilder::default().build_and_execute(|| {
 		start_session(1);
-		ValidatorRewardAccounts::<Test>::insert(VALIDATOR, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
 		add_reward_balance();
 		assert_eq!(active_era(), 0);
 		assert_eq!(RewardBalance::free_balance(VALIDATOR), 1000);
@@ -39,7 +92,7 @@ fn anyone_can_call_rewards_should_work() {
 		start_session(1);
 		assert_eq!(active_era(), 0);
 		add_reward_balance();
-		ValidatorRewardAccounts::<Test>::insert(VALIDATOR, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
 		assert_ok!(Reward::get_rewards(who(USER), VALIDATOR));
 	});
 }
@@ -59,7 +112,7 @@ fn get_multiple_rewards_in_the_same_era_should_not_work() {
 		start_session(1);
 		assert_eq!(active_era(), 0);
 		add_reward_balance();
-		ValidatorRewardAccounts::<Test>::insert(VALIDATOR, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
 		assert_ok!(Reward::get_rewards(who(USER), VALIDATOR));
 		assert_noop!(
 			Reward::get_rewards(who(USER), VALIDATOR),
@@ -79,9 +132,8 @@ fn nominator_receiving_reward() {
 		let earlier_nominator_balance = RewardBalance::free_balance(NOMINATOR);
 		let earlier_validator_balance = RewardBalance::free_balance(VALIDATOR);
 
-		ValidatorRewardAccounts::<Test>::insert(VALIDATOR, validator_reward);
-		NominatorEarningsAccount::<Test>::insert(VALIDATOR, NOMINATOR, nominator_reward);
-		EraReward::<Test>::insert(VALIDATOR, vec![NOMINATOR]);
+		Reward::allocate_rewards(0, VALIDATOR, None, validator_reward);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), nominator_reward);
 
 		let _ = Balances::deposit_creating(&Reward::account_id(), 15000000);
 		let reward_account_balance_before = RewardBalance::free_balance(Reward::account_id());
@@ -118,17 +170,8 @@ fn accumulated_rewards_over_multiple_eras() {
 			let validator_reward: u128 = 1000 * (era + 1);
 			let nominator_reward: u128 = 500 * (era + 1);
 
-			ValidatorRewardAccounts::<Test>::mutate(VALIDATOR.clone(), |earlier_reward| {
-				*earlier_reward += validator_reward;
-			});
-			NominatorEarningsAccount::<Test>::mutate(
-				VALIDATOR.clone(),
-				NOMINATOR,
-				|earlier_reward| {
-					*earlier_reward += nominator_reward;
-				},
-			);
-			EraReward::<Test>::insert(VALIDATOR, vec![NOMINATOR]);
+			Reward::allocate_rewards(era as u32, VALIDATOR, None, validator_reward);
+			Reward::allocate_rewards(era as u32, VALIDATOR, Some(NOMINATOR), nominator_reward);
 
 			total_validator_reward += validator_reward;
 			total_nominator_reward += nominator_reward;
@@ -145,6 +188,189 @@ fn accumulated_rewards_over_multiple_eras() {
 	});
 }
 
+#[test]
+fn claim_era_rewards_pays_only_the_requested_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		let earlier_validator_balance = RewardBalance::free_balance(VALIDATOR);
+
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(1, VALIDATOR, None, 2000);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 700);
+		assert_eq!(UnclaimedEras::<Test>::get(VALIDATOR), vec![0, 1]);
+
+		assert_ok!(Reward::claim_era_rewards(who(USER), 1, VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), earlier_validator_balance + 2000);
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), 700);
+		assert_eq!(UnclaimedEras::<Test>::get(VALIDATOR), vec![0]);
+		assert_eq!(EraValidatorRewards::<Test>::get(0, VALIDATOR), 1000);
+		assert_eq!(EraNominatorRewards::<Test>::get((0, VALIDATOR, NOMINATOR)), 500);
+		assert_noop!(Reward::claim_era_rewards(who(USER), 1, VALIDATOR), Error::<Test>::NoReward);
+	});
+}
+
+#[test]
+fn claim_era_rewards_with_low_balance_should_not_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		let _ = Balances::deposit_creating(&Reward::account_id(), 1000);
+
+		assert_noop!(
+			Reward::claim_era_rewards(who(USER), 0, VALIDATOR),
+			Error::<Test>::InsufficientRewardBalance
+		);
+	});
+}
+
+#[test]
+fn nominator_can_claim_its_own_reward() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		let earlier_validator_balance = RewardBalance::free_balance(VALIDATOR);
+
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR + 1), 300);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 200);
+
+		assert_ok!(Reward::claim_nominator_reward(who(NOMINATOR), VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), 700);
+		assert_eq!(RewardBalance::free_balance(NOMINATOR + 1), 0);
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), earlier_validator_balance);
+		assert_eq!(EraValidatorRewards::<Test>::get(0, VALIDATOR), 1000);
+		assert_eq!(EraNominatorRewards::<Test>::get((0, VALIDATOR, NOMINATOR + 1)), 300);
+		// era 1 is fully claimed, era 0 is still owed to the validator and the other nominator
+		assert_eq!(UnclaimedEras::<Test>::get(VALIDATOR), vec![0]);
+		assert_noop!(
+			Reward::claim_nominator_reward(who(NOMINATOR), VALIDATOR),
+			Error::<Test>::NoReward
+		);
+	});
+}
+
+#[test]
+fn nominator_claim_with_low_balance_should_not_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 700);
+		let _ = Balances::deposit_creating(&Reward::account_id(), 1000);
+
+		assert_noop!(
+			Reward::claim_nominator_reward(who(NOMINATOR), VALIDATOR),
+			Error::<Test>::InsufficientRewardBalance
+		);
+	});
+}
+
+#[test]
+fn nominator_can_claim_rewards_of_all_nominated_validators() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		// the nominator no longer nominates `other_validator`, its rewards are still swept
+		let other_validator = 21;
+		pallet_staking::Nominators::<Test>::insert(
+			NOMINATOR,
+			pallet_staking::Nominations {
+				targets: vec![VALIDATOR].try_into().unwrap(),
+				submitted_in: 0,
+				suppressed: false,
+			},
+		);
+
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 200);
+		Reward::allocate_rewards(1, other_validator, Some(NOMINATOR), 300);
+		Reward::allocate_rewards(1, other_validator, Some(NOMINATOR + 1), 400);
+
+		assert_ok!(Reward::claim_all_nominator_rewards(who(NOMINATOR)));
+
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), 1000);
+		assert!(UnclaimedEras::<Test>::get(VALIDATOR).is_empty());
+		assert_eq!(UnclaimedEras::<Test>::get(other_validator), vec![1]);
+		assert_eq!(EraNominatorRewards::<Test>::get((1, other_validator, NOMINATOR + 1)), 400);
+		assert!(NominatorRewardEras::<Test>::iter_prefix((NOMINATOR,)).next().is_none());
+		assert_noop!(Reward::claim_all_nominator_rewards(who(NOMINATOR)), Error::<Test>::NoReward);
+	});
+}
+
+#[test]
+fn unclaimed_rewards_expire_after_history_depth() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		let _ = Balances::deposit_creating(&RewardRemainderAccount::get(), 100);
+		let history_depth = <Test as pallet_staking::Config>::HistoryDepth::get();
+
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(1, VALIDATOR, None, 2000);
+
+		schedule_distribution(distribution(history_depth - 1));
+		assert!(Reward::process_reward_page(Weight::MAX).is_some());
+		assert_eq!(UnclaimedEras::<Test>::get(VALIDATOR), vec![0, 1]);
+
+		PendingRewardEras::<Test>::kill();
+		schedule_distribution(distribution(history_depth));
+		assert!(Reward::process_reward_page(Weight::MAX).is_some());
+		assert!(Reward::process_reward_page(Weight::MAX).is_some());
+		System::assert_has_event(RuntimeEvent::Reward(crate::Event::RewardsExpired {
+			era: 0,
+			balance: 1500,
+		}));
+		assert_eq!(RewardBalance::free_balance(RewardRemainderAccount::get()), 1600);
+		assert_eq!(UnclaimedEras::<Test>::get(VALIDATOR), vec![1]);
+		assert!(!EraValidatorRewards::<Test>::contains_key(0, VALIDATOR));
+		assert!(!EraNominatorRewards::<Test>::contains_key((0, VALIDATOR, NOMINATOR)));
+	});
+}
+
+#[test]
+fn unclaimed_eras_are_bounded_by_the_history_depth() {
+	ExtBuilder::default().build_and_execute(|| {
+		let history_depth: u32 = <Test as pallet_staking::Config>::HistoryDepth::get();
+
+		for era in 0..history_depth + 5 {
+			Reward::allocate_rewards(era, VALIDATOR, None, 1000);
+		}
+
+		let unclaimed = UnclaimedEras::<Test>::get(VALIDATOR);
+		assert_eq!(unclaimed.len() as u32, history_depth);
+		assert_eq!(unclaimed.first(), Some(&5));
+		assert_eq!(unclaimed.last(), Some(&(history_depth + 4)));
+	});
+}
+
+#[test]
+fn migration_to_v2_books_pending_rewards_in_the_active_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		StorageVersion::new(1).put::<Reward>();
+		migration::v1::ValidatorRewardAccounts::<Test>::insert(VALIDATOR, 1000);
+		migration::v1::NominatorEarningsAccount::<Test>::insert(VALIDATOR, NOMINATOR, 500);
+		migration::v1::EraReward::<Test>::insert(VALIDATOR, vec![NOMINATOR]);
+
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Reward::on_chain_storage_version(), 2);
+		assert_eq!(EraValidatorRewards::<Test>::get(active_era(), VALIDATOR), 1000);
+		assert_eq!(EraNominatorRewards::<Test>::get((active_era(), VALIDATOR, NOMINATOR)), 500);
+		assert_eq!(UnclaimedEras::<Test>::get(VALIDATOR), vec![active_era()]);
+		assert_eq!(Reward::outstanding_rewards(), 1500);
+		assert_eq!(migration::v1::ValidatorRewardAccounts::<Test>::iter().count(), 0);
+		assert_eq!(migration::v1::NominatorEarningsAccount::<Test>::iter().count(), 0);
+		assert_eq!(migration::v1::EraReward::<Test>::iter().count(), 0);
+	});
+}
+
 #[test]
 fn balance_low_before_distributing() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -156,14 +382,697 @@ fn balance_low_before_distributing() {
 		let nominator_reward2: u128 = 300;
 		let nominator_reward3: u128 = 200;
 
-		ValidatorRewardAccounts::<Test>::insert(VALIDATOR, validator_reward);
-		NominatorEarningsAccount::<Test>::insert(VALIDATOR, NOMINATOR, nominator_reward1);
-		NominatorEarningsAccount::<Test>::insert(VALIDATOR, NOMINATOR + 1, nominator_reward2);
-		NominatorEarningsAccount::<Test>::insert(VALIDATOR, NOMINATOR + 2, nominator_reward3);
-		EraReward::<Test>::insert(VALIDATOR, vec![NOMINATOR, NOMINATOR + 1, NOMINATOR + 2]);
+		Reward::allocate_rewards(0, VALIDATOR, None, validator_reward);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), nominator_reward1);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR + 1), nominator_reward2);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR + 2), nominator_reward3);
 
 		let _ = Balances::deposit_creating(&Reward::account_id(), 1000);
 		let _ = Reward::claim_rewards(VALIDATOR);
 		assert_last_event(RuntimeEvent::Reward(crate::Event::InsufficientRewardBalance));
 	});
 }
+
+/// Deterministic xorshift generator used to drive the property tests.
+struct Rng(u64);
+
+impl Rng {
+	fn next(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	fn next_u128(&mut self, max: u128) -> u128 {
+		let value = ((self.next() as u128) << 64) | self.next() as u128;
+		if max.is_zero() {
+			0
+		} else {
+			value % max
+		}
+	}
+}
+
+#[test]
+fn era_reward_is_computed_without_truncating_precision() {
+	ExtBuilder::default().build_and_execute(|| {
+		let eras_per_year = (TotalMinutesPerYear::get() / EraMinutes::get()) as u128;
+		let yearly_reward = TotalReward::get() as u128 * 10u128.pow(DecimalPrecision::get());
+		assert_eq!(Reward::calculate_era_reward(), yearly_reward / eras_per_year);
+	});
+}
+
+#[test]
+fn reward_share_handles_edge_cases() {
+	assert_eq!(Reward::calculate_reward_share(0, 0, 1000), 0);
+	assert_eq!(Reward::calculate_reward_share(10, 0, 1000), 0);
+	assert_eq!(Reward::calculate_reward_share(10, 10, 1000), 1000);
+	assert_eq!(Reward::calculate_reward_share(1, 3, 1000), 333);
+	assert_eq!(Reward::calculate_reward_share(u128::MAX / 2, u128::MAX, u128::MAX), u128::MAX / 2);
+}
+
+#[test]
+fn split_validator_reward_keeps_dust_out_of_payouts() {
+	let (validator, nominators) =
+		Reward::split_validator_reward(1000, Perbill::from_percent(10), 1, 3, &[1, 1]);
+	// 100 commission, 900 split in thirds.
+	assert_eq!(validator, 400);
+	assert_eq!(nominators, vec![300, 300]);
+
+	let (validator, nominators) =
+		Reward::split_validator_reward(1000, Perbill::zero(), 1, 7, &[2, 4]);
+	assert_eq!(validator, 142);
+	assert_eq!(nominators, vec![285, 571]);
+	assert_eq!(1000 - validator - nominators.iter().sum::<u128>(), 2);
+}
+
+#[test]
+fn split_validator_reward_never_exceeds_validator_reward() {
+	let mut rng = Rng(0x5151_2024_dead_beef);
+	for _ in 0..10_000 {
+		let max_reward = u128::MAX >> (rng.next() % 100);
+		let reward = rng.next_u128(max_reward);
+		let commission = Perbill::from_parts((rng.next() % 1_000_000_001) as u32);
+		let own_stake = rng.next_u128(1u128 << 100);
+		let nominator_stakes: Vec<u128> =
+			(0..rng.next() % 64).map(|_| rng.next_u128(1u128 << 100)).collect();
+		let total_stake = own_stake + nominator_stakes.iter().sum::<u128>();
+
+		let (validator, nominators) = Reward::split_validator_reward(
+			reward,
+			commission,
+			own_stake,
+			total_stake,
+			&nominator_stakes,
+		);
+		let paid = nominators.iter().fold(validator, |acc, reward| acc + reward);
+		assert!(paid <= reward);
+		assert!(validator >= commission.mul_floor(reward));
+		assert_eq!(nominators.len(), nominator_stakes.len());
+		// Only rounding dust is left behind: at most one unit per payout.
+		if !total_stake.is_zero() {
+			assert!(reward - paid <= nominator_stakes.len() as u128 + 1);
+		}
+	}
+}
+
+#[test]
+fn era_split_across_validators_never_exceeds_the_pot() {
+	let mut rng = Rng(0x0005_1e00_c0ff_ee00);
+	for _ in 0..1_000 {
+		let pot = rng.next_u128(1u128 << 90);
+		let stakes: Vec<(u128, u32)> = (1..=rng.next() % 100 + 1)
+			.map(|_| (rng.next_u128(1u128 << 80), (rng.next() % 10_000) as u32))
+			.collect();
+		let total = stakes.iter().map(|(stake, points)| stake + *points as u128).sum::<u128>();
+
+		let mut paid = 0u128;
+		for (stake, points) in stakes.iter() {
+			let validator_reward =
+				Reward::calculate_reward_share(stake + *points as u128, total, pot);
+			let nominator_stake = rng.next_u128(*stake);
+			let (validator, nominators) = Reward::split_validator_reward(
+				validator_reward,
+				Perbill::from_parts((rng.next() % 1_000_000_001) as u32),
+				stake - nominator_stake,
+				*stake,
+				&[nominator_stake],
+			);
+			paid += validator + nominators.iter().sum::<u128>();
+		}
+		assert!(paid <= pot);
+	}
+}
+
+#[test]
+fn calculate_reward_allocates_the_exact_era_pot() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let era_reward = Reward::calculate_era_reward();
+		let _ = Balances::deposit_creating(&Reward::account_id(), era_reward * 2);
+		let _ = Balances::deposit_creating(&RewardRemainderAccount::get(), 100);
+		let remainder_before = RewardBalance::free_balance(RewardRemainderAccount::get());
+
+		assert_ok!(Reward::calculate_reward());
+		while RewardDistributions::<Test>::get(active_era())
+			.map_or(false, |distribution| distribution.stage != DistributionStage::Payout)
+		{
+			assert!(Reward::process_reward_page(Weight::MAX).is_some());
+		}
+
+		let allocated = EraValidatorRewards::<Test>::iter_prefix_values(active_era())
+			.chain(EraNominatorRewards::<Test>::iter_prefix_values((active_era(),)))
+			.sum::<u128>();
+		let remainder =
+			RewardBalance::free_balance(RewardRemainderAccount::get()) - remainder_before;
+		assert!(allocated > 0);
+		assert_eq!(allocated + remainder, era_reward);
+	});
+}
+
+#[test]
+fn reward_distribution_is_processed_within_the_given_weight() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		Reward::allocate_rewards(1, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 500);
+		EraRewardsVault::<Test>::put(vec![VALIDATOR]);
+		let validator_balance = RewardBalance::free_balance(VALIDATOR);
+		let nominator_balance = RewardBalance::free_balance(NOMINATOR);
+		DistributionValidators::<Test>::insert(
+			1,
+			0,
+			BoundedVec::truncate_from(vec![VALIDATOR, USER]),
+		);
+		schedule_distribution(RewardDistribution { validator_pages: 1, ..distribution(1) });
+
+		// expire
+		assert!(Reward::process_reward_page(page_weight(DistributionStage::Expire, 1)).is_some());
+		assert_last_event(RuntimeEvent::Reward(crate::Event::RewardPageProcessed {
+			era: 1,
+			stage: DistributionStage::Expire,
+			page: 1,
+		}));
+		// not a single validator fits
+		assert_eq!(Reward::process_reward_page(page_weight(DistributionStage::Calculate, 0)), None);
+		assert_eq!(RewardDistributions::<Test>::get(1).unwrap().pages, 1);
+		// one validator per page, the rest of the stored page is kept for the next one
+		let one_validator = page_weight(DistributionStage::Calculate, 1);
+		assert_eq!(Reward::process_reward_page(one_validator), Some(one_validator));
+		assert_eq!(DistributionValidators::<Test>::get(1, 0).into_inner(), vec![USER]);
+		assert_eq!(RewardDistributions::<Test>::get(1).unwrap().next_page, 0);
+		assert_eq!(Reward::process_reward_page(one_validator), Some(one_validator));
+		assert!(DistributionValidators::<Test>::iter_prefix(1).next().is_none());
+		assert_eq!(RewardDistributions::<Test>::get(1).unwrap().stage, DistributionStage::Payout);
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), validator_balance);
+
+		// payout
+		assert!(Reward::process_reward_page(page_weight(DistributionStage::Payout, 1)).is_some());
+		assert_last_event(RuntimeEvent::Reward(crate::Event::RewardDistributionCompleted {
+			era: 1,
+			pages: 4,
+		}));
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), validator_balance + 1000);
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), nominator_balance + 500);
+		assert_eq!(EraRewardsVault::<Test>::get(), None);
+		assert!(RewardDistributions::<Test>::get(1).is_none());
+		assert!(PendingRewardEras::<Test>::get().is_empty());
+		assert_eq!(Reward::process_reward_page(Weight::MAX), None);
+	});
+}
+
+#[test]
+fn on_idle_processes_pages_within_the_remaining_weight() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		schedule_distribution(distribution(1));
+
+		assert_eq!(Reward::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+		assert_eq!(RewardDistributions::<Test>::get(1).unwrap().pages, 0);
+
+		Reward::on_idle(System::block_number(), Weight::MAX);
+		assert!(RewardDistributions::<Test>::get(1).is_none());
+		assert!(PendingRewardEras::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn calculate_reward_schedules_one_distribution_per_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let validators = Session::validators().len() as u32;
+
+		assert_ok!(Reward::calculate_reward());
+		assert_err!(Reward::calculate_reward(), Error::<Test>::DistributionAlreadyScheduled);
+		assert_eq!(PendingRewardEras::<Test>::get(), vec![active_era()]);
+		// two validators per stored page
+		let distribution = RewardDistributions::<Test>::get(active_era()).unwrap();
+		assert_eq!(distribution.validator_pages, (validators + 1) / 2);
+		assert_eq!(
+			DistributionValidators::<Test>::iter_prefix(active_era()).count() as u32,
+			(validators + 1) / 2
+		);
+	});
+}
+
+#[test]
+fn calculate_reward_fails_with_history_depth_pending_distributions() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let history_depth: u32 = <Test as pallet_staking::Config>::HistoryDepth::get();
+		for era in 0..history_depth {
+			schedule_distribution(distribution(era + 100));
+		}
+
+		let pot = RewardBalance::free_balance(Reward::account_id());
+
+		assert_err!(Reward::calculate_reward(), Error::<Test>::TooManyPendingDistributions);
+		assert_last_event(RuntimeEvent::Reward(crate::Event::RewardDistributionNotScheduled {
+			era: active_era(),
+			error: Error::<Test>::TooManyPendingDistributions.into(),
+		}));
+		assert!(RewardDistributions::<Test>::get(active_era()).is_none());
+		assert_eq!(RewardBalance::free_balance(Reward::account_id()), pot);
+	});
+}
+
+#[test]
+fn eras_without_a_distribution_expire_with_the_next_one() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		let _ = Balances::deposit_creating(&RewardRemainderAccount::get(), 100);
+		let history_depth: u32 = <Test as pallet_staking::Config>::HistoryDepth::get();
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 500);
+
+		// the distribution of era `history_depth` was never scheduled
+		schedule_distribution(distribution(history_depth + 1));
+		assert!(Reward::process_reward_page(Weight::MAX).is_some());
+
+		System::assert_has_event(RuntimeEvent::Reward(crate::Event::RewardsExpired {
+			era: 0,
+			balance: 1000,
+		}));
+		System::assert_has_event(RuntimeEvent::Reward(crate::Event::RewardsExpired {
+			era: 1,
+			balance: 500,
+		}));
+		assert_eq!(RewardBalance::free_balance(RewardRemainderAccount::get()), 1600);
+		assert_eq!(NextExpiringEra::<Test>::get(), 2);
+		assert!(UnclaimedEras::<Test>::get(VALIDATOR).is_empty());
+		assert_eq!(
+			RewardDistributions::<Test>::get(history_depth + 1).unwrap().stage,
+			DistributionStage::Calculate
+		);
+	});
+}
+
+#[test]
+fn payout_resumes_the_unclaimed_eras_of_a_validator_on_the_next_page() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		Reward::allocate_rewards(1, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(2, VALIDATOR, None, 2000);
+		EraRewardsVault::<Test>::put(vec![VALIDATOR]);
+		let validator_balance = RewardBalance::free_balance(VALIDATOR);
+		schedule_distribution(RewardDistribution {
+			stage: DistributionStage::Payout,
+			..distribution(2)
+		});
+
+		// one era per page
+		let one_era = page_weight(DistributionStage::Payout, 1);
+		assert!(Reward::process_reward_page(one_era).is_some());
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), validator_balance + 1000);
+		assert_eq!(RewardDistributions::<Test>::get(2).unwrap().payout, Some((VALIDATOR, 2)));
+		assert_eq!(EraRewardsVault::<Test>::get(), None);
+
+		assert!(Reward::process_reward_page(one_era).is_some());
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), validator_balance + 3000);
+		assert!(RewardDistributions::<Test>::get(2).is_none());
+	});
+}
+
+#[test]
+fn pending_rewards_sum_the_unclaimed_eras() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(1, VALIDATOR, None, 2000);
+		Reward::allocate_rewards(1, VALIDATOR, Some(NOMINATOR), 200);
+
+		assert_eq!(Reward::pending_validator_rewards(VALIDATOR), 3000);
+		assert_eq!(Reward::pending_nominator_rewards(NOMINATOR), 700);
+
+		assert_ok!(Reward::claim_era_rewards(who(USER), 0, VALIDATOR));
+		assert_eq!(Reward::pending_validator_rewards(VALIDATOR), 2000);
+		assert_eq!(Reward::pending_nominator_rewards(NOMINATOR), 200);
+		assert_eq!(Reward::total_rewards(VALIDATOR), 1000);
+		assert_eq!(Reward::total_rewards(NOMINATOR), 500);
+	});
+}
+
+#[test]
+fn projected_era_reward_stays_within_the_era_pot() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let stakers =
+			pallet_staking::ErasStakers::<Test>::iter_prefix(Staking::current_era().unwrap())
+				.flat_map(|(validator, exposure)| {
+					sp_std::iter::once(validator).chain(exposure.others.into_iter().map(|n| n.who))
+				})
+				.collect::<sp_std::collections::btree_set::BTreeSet<_>>();
+
+		let projected = stakers.iter().map(|who| Reward::projected_era_reward(*who)).sum::<u128>();
+		assert!(Reward::projected_era_reward(VALIDATOR) > 0);
+		assert!(projected <= Reward::calculate_era_reward());
+		assert_eq!(Reward::projected_era_reward(USER_2), 0);
+	});
+}
+
+#[test]
+fn staked_reward_is_bonded_into_the_ledger() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		pallet_staking::Payee::<Test>::insert(VALIDATOR, RewardDestination::Staked);
+		let ledger = Staking::ledger(VALIDATOR).unwrap();
+		let free_balance = RewardBalance::free_balance(VALIDATOR);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+
+		assert_ok!(Reward::claim_era_rewards(who(USER), 0, VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), free_balance + 1000);
+		assert_eq!(Staking::ledger(VALIDATOR).unwrap().active, ledger.active + 1000);
+		assert_eq!(Staking::ledger(VALIDATOR).unwrap().total, ledger.total + 1000);
+		assert_eq!(Reward::total_rewards(VALIDATOR), 1000);
+	});
+}
+
+#[test]
+fn stash_reward_is_not_bonded() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		pallet_staking::Payee::<Test>::insert(VALIDATOR, RewardDestination::Stash);
+		let ledger = Staking::ledger(VALIDATOR).unwrap();
+		let free_balance = RewardBalance::free_balance(VALIDATOR);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+
+		assert_ok!(Reward::claim_era_rewards(who(USER), 0, VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), free_balance + 1000);
+		assert_eq!(Staking::ledger(VALIDATOR).unwrap(), ledger);
+	});
+}
+
+#[test]
+fn controller_reward_is_paid_to_the_controller() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		pallet_staking::Payee::<Test>::insert(VALIDATOR, RewardDestination::Controller);
+		pallet_staking::Bonded::<Test>::insert(VALIDATOR, 10);
+		let free_balance = RewardBalance::free_balance(VALIDATOR);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+
+		assert_ok!(Reward::claim_era_rewards(who(USER), 0, VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(VALIDATOR), free_balance);
+		assert_eq!(RewardBalance::free_balance(10), 1050);
+		assert_eq!(Reward::total_rewards(VALIDATOR), 1000);
+	});
+}
+
+#[test]
+fn account_reward_is_paid_to_the_chosen_account() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		pallet_staking::Payee::<Test>::insert(NOMINATOR, RewardDestination::Account(USER_2));
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+
+		assert_ok!(Reward::claim_nominator_reward(who(NOMINATOR), VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), 0);
+		assert_eq!(RewardBalance::free_balance(USER_2), 500);
+		assert_last_event(RuntimeEvent::Reward(crate::Event::Distributed {
+			who: USER_2,
+			balance: 500,
+		}));
+		assert_eq!(Reward::total_rewards(NOMINATOR), 500);
+	});
+}
+
+#[test]
+fn no_reward_destination_pays_the_stash() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		pallet_staking::Payee::<Test>::insert(NOMINATOR, RewardDestination::None);
+		let pot = RewardBalance::free_balance(Reward::account_id());
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+
+		assert_ok!(Reward::claim_nominator_reward(who(NOMINATOR), VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), 500);
+		assert_eq!(RewardBalance::free_balance(Reward::account_id()), pot - 500);
+		assert!(!EraNominatorRewards::<Test>::contains_key((0, VALIDATOR, NOMINATOR)));
+		assert_eq!(Reward::total_rewards(NOMINATOR), 500);
+	});
+}
+
+#[test]
+fn failed_restake_keeps_the_reward_as_free_balance() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		pallet_staking::Payee::<Test>::insert(NOMINATOR, RewardDestination::Staked);
+		assert!(Staking::ledger(NOMINATOR).is_none());
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+
+		assert_ok!(Reward::claim_nominator_reward(who(NOMINATOR), VALIDATOR));
+
+		assert_eq!(RewardBalance::free_balance(NOMINATOR), 500);
+		System::assert_has_event(RuntimeEvent::Reward(crate::Event::RestakeFailed {
+			stash: NOMINATOR,
+			balance: 500,
+		}));
+		assert_eq!(Reward::total_rewards(NOMINATOR), 500);
+	});
+}
+
+#[test]
+fn era_reward_decays_every_year_of_the_schedule() {
+	ExtBuilder::default().build_and_execute(|| {
+		let eras_per_year = TotalMinutesPerYear::get() / EraMinutes::get();
+		CurrentEmissionSchedule::<Test>::put(EmissionSchedule {
+			yearly_decay: Perbill::from_percent(10),
+			..emission_schedule(eras_per_year as u128 * 1000)
+		});
+		assert_eq!(Reward::calculate_era_reward(), 1000);
+
+		set_active_era(eras_per_year);
+		assert_eq!(Reward::calculate_era_reward(), 900);
+
+		set_active_era(2 * eras_per_year);
+		assert_eq!(Reward::calculate_era_reward(), 810);
+	});
+}
+
+#[test]
+fn staking_rate_factor_peaks_at_the_ideal_stake() {
+	ExtBuilder::default().build_and_execute(|| {
+		let schedule = EmissionSchedule {
+			ideal_stake: Perbill::from_percent(50),
+			min_reward: Perbill::from_percent(20),
+			..emission_schedule(0)
+		};
+		let factor = |rate| Reward::staking_rate_factor(&schedule, Perbill::from_percent(rate));
+		// the falloff is exact up to rounding every quarter of the falloff
+		let assert_close = |rate, expected: Perbill| {
+			let actual = factor(rate);
+			assert!(
+				actual.max(expected) - actual.min(expected) <= Perbill::from_parts(100),
+				"{rate}%: {actual:?} != {expected:?}"
+			);
+		};
+		assert_eq!(factor(0), Perbill::from_percent(20));
+		assert_eq!(factor(25), Perbill::from_percent(60));
+		assert_eq!(factor(50), Perbill::from_percent(100));
+		// the reward above `min_reward` halves every 5%
+		assert_close(55, Perbill::from_percent(60));
+		assert_close(60, Perbill::from_percent(40));
+		assert_close(75, Perbill::from_rational(225u32, 1000));
+		assert_close(100, Perbill::from_percent(20) + Perbill::from_rational(80u32, 102_400));
+		assert!(factor(52) < factor(51));
+		assert!(factor(52) > factor(53));
+	});
+}
+
+#[test]
+fn staking_rate_factor_is_cut_at_full_stake() {
+	ExtBuilder::default().build_and_execute(|| {
+		let schedule = EmissionSchedule {
+			ideal_stake: Perbill::from_percent(99),
+			min_reward: Perbill::zero(),
+			falloff: Perbill::from_percent(8),
+			..emission_schedule(0)
+		};
+		let factor = |rate| Reward::staking_rate_factor(&schedule, Perbill::from_percent(rate));
+		assert_eq!(factor(99), Perbill::one());
+		// half of the first quarter of the falloff
+		let expected = Perbill::one() -
+			Perbill::from_percent(50) * (Perbill::one() - crate::QUARTER_FALLOFF_RATIO);
+		assert!(factor(100).max(expected) - factor(100).min(expected) <= Perbill::from_parts(10));
+	});
+}
+
+#[test]
+fn era_reward_never_exceeds_the_supply_cap() {
+	ExtBuilder::default().build_and_execute(|| {
+		let eras_per_year = TotalMinutesPerYear::get() / EraMinutes::get();
+		let issuance = Balances::total_issuance();
+		CurrentEmissionSchedule::<Test>::put(EmissionSchedule {
+			max_supply: issuance + 10,
+			..emission_schedule(eras_per_year as u128 * 1000)
+		});
+		assert_eq!(Reward::calculate_era_reward(), 10);
+
+		CurrentEmissionSchedule::<Test>::put(EmissionSchedule {
+			max_supply: issuance,
+			..emission_schedule(eras_per_year as u128 * 1000)
+		});
+		assert_eq!(Reward::calculate_era_reward(), 0);
+	});
+}
+
+#[test]
+fn calculate_reward_mints_the_era_reward_up_to_the_supply_cap() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let issuance = Balances::total_issuance();
+		let pot = RewardBalance::free_balance(Reward::account_id());
+		let era_reward = Reward::calculate_era_reward();
+		assert!(era_reward > 0);
+
+		assert_ok!(Reward::calculate_reward());
+		assert_eq!(Balances::total_issuance(), issuance + era_reward);
+		assert_eq!(RewardBalance::free_balance(Reward::account_id()), pot + era_reward);
+		System::assert_has_event(RuntimeEvent::Reward(crate::Event::EraRewardMinted {
+			era: active_era(),
+			balance: era_reward,
+		}));
+
+		CurrentEmissionSchedule::<Test>::mutate(|schedule| {
+			schedule.max_supply = Balances::total_issuance()
+		});
+		set_active_era(active_era() + 1);
+		assert_ok!(Reward::calculate_reward());
+		assert_eq!(Balances::total_issuance(), issuance + era_reward);
+	});
+}
+
+#[test]
+fn calculate_reward_pays_the_era_reward_out_of_the_pot_surplus_first() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let era_reward = Reward::calculate_era_reward();
+		// the rewards owed to the stakers and the existential deposit are not part of the surplus
+		OutstandingRewards::<Test>::put(1000);
+		let _ = Balances::deposit_creating(
+			&Reward::account_id(),
+			1000 + RewardBalance::minimum_balance() + era_reward / 2,
+		);
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(Reward::calculate_reward());
+		let minted = era_reward - era_reward / 2;
+		assert_eq!(Balances::total_issuance(), issuance + minted);
+		System::assert_has_event(RuntimeEvent::Reward(crate::Event::EraRewardMinted {
+			era: active_era(),
+			balance: minted,
+		}));
+		assert_eq!(Reward::outstanding_rewards(), 1000 + era_reward);
+
+		// a pot holding the whole era reward beyond the outstanding rewards mints nothing
+		let _ = Balances::deposit_creating(&Reward::account_id(), era_reward);
+		let issuance = Balances::total_issuance();
+		set_active_era(active_era() + 1);
+		assert_eq!(Reward::calculate_era_reward(), era_reward);
+		assert_ok!(Reward::calculate_reward());
+		assert_eq!(Balances::total_issuance(), issuance);
+		assert_eq!(Reward::outstanding_rewards(), 1000 + 2 * era_reward);
+	});
+}
+
+#[test]
+fn paid_and_expired_rewards_are_no_longer_outstanding() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		add_reward_balance();
+		let history_depth: u32 = <Test as pallet_staking::Config>::HistoryDepth::get();
+		OutstandingRewards::<Test>::put(3500);
+		Reward::allocate_rewards(0, VALIDATOR, None, 1000);
+		Reward::allocate_rewards(0, VALIDATOR, Some(NOMINATOR), 500);
+		Reward::allocate_rewards(1, VALIDATOR, None, 2000);
+
+		assert_ok!(Reward::claim_era_rewards(who(USER), 1, VALIDATOR));
+		assert_eq!(Reward::outstanding_rewards(), 1500);
+
+		schedule_distribution(distribution(history_depth));
+		assert!(Reward::process_reward_page(Weight::MAX).is_some());
+		assert_eq!(Reward::outstanding_rewards(), 0);
+	});
+}
+
+#[test]
+fn emission_schedule_keeps_its_start_era_when_only_the_parameters_change() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		CurrentEmissionSchedule::<Test>::put(emission_schedule(1_000_000));
+		set_active_era(5);
+		let schedule = EmissionSchedule {
+			yearly_decay: Perbill::from_percent(10),
+			start_era: 5,
+			..emission_schedule(1_000_000)
+		};
+
+		assert_ok!(Reward::set_emission_schedule(RuntimeOrigin::root(), schedule.clone()));
+		assert_ok!(Reward::calculate_reward());
+		assert_eq!(Reward::emission_schedule(), EmissionSchedule { start_era: 0, ..schedule });
+	});
+}
+
+#[test]
+fn emission_schedule_takes_effect_at_the_end_of_the_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		start_session(1);
+		let schedule = EmissionSchedule {
+			yearly_decay: Perbill::from_percent(5),
+			ideal_stake: Perbill::from_percent(60),
+			min_reward: Perbill::from_percent(10),
+			..emission_schedule(1_000_000)
+		};
+		assert_noop!(
+			Reward::set_emission_schedule(who(USER), schedule.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Reward::set_emission_schedule(
+				RuntimeOrigin::root(),
+				EmissionSchedule { ideal_stake: Perbill::zero(), ..schedule.clone() }
+			),
+			Error::<Test>::InvalidEmissionSchedule
+		);
+		assert_noop!(
+			Reward::set_emission_schedule(
+				RuntimeOrigin::root(),
+				EmissionSchedule { falloff: Perbill::zero(), ..schedule.clone() }
+			),
+			Error::<Test>::InvalidEmissionSchedule
+		);
+
+		assert_ok!(Reward::set_emission_schedule(RuntimeOrigin::root(), schedule.clone()));
+		assert_last_event(RuntimeEvent::Reward(crate::Event::EmissionScheduleSet {
+			schedule: schedule.clone(),
+		}));
+		assert_eq!(Reward::pending_emission_schedule(), Some(schedule.clone()));
+		assert_eq!(Reward::emission_schedule().yearly_decay, Perbill::zero());
+
+		assert_ok!(Reward::calculate_reward());
+		assert_eq!(Reward::pending_emission_schedule(), None);
+		assert_eq!(
+			Reward::emission_schedule(),
+			EmissionSchedule { start_era: active_era(), ..schedule }
+		);
+		assert!(frame_system::Pallet::<Test>::events().iter().any(|record| record.event ==
+			RuntimeEvent::Reward(crate::Event::EmissionScheduleEnacted { era: active_era() })));
+	});
+}
@@ -1,28 +1,14 @@
 
-//! Autogenerated weights for `pallet_reward`
+//! Provisional weights for `pallet_reward`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-07-29, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `arunjot-ATR592`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//! NOT GENERATED WITH THE `benchmark pallet` CLI: the regression of the pallet benchmarks was
+//! run natively against the mock runtime, not against a real runtime on reference hardware.
+//! Regenerate with `benchmark pallet` before a release.
+//! DATE: 2026-10-18, STEPS: `6`, REPEAT: `20`
 
-// Executed Command:
-// ./target/release/substrate-node
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_reward
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// substrate/frame/reward/src/weights.rs
+// Measured natively against the mock runtime of the pallet, with the configuration of the
+// runtimes (`MaxNominatorRewardedPerValidator` 256, `HistoryDepth` 84, `RewardPageSize` 16), 20
+// repeats per step (10 for the benchmarks with a component, 5 for `expire_era_rewards`).
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -34,40 +20,386 @@ use core::marker::PhantomData;
 
 pub trait WeightInfo {
 	fn get_rewards() -> Weight;
+	fn claim_era_rewards(n: u32, ) -> Weight;
+	fn claim_nominator_reward(e: u32, ) -> Weight;
+	fn claim_all_nominator_rewards(e: u32, ) -> Weight;
+	fn expire_era_rewards(n: u32, ) -> Weight;
+	fn calculate_validator_reward(n: u32, ) -> Weight;
+	fn set_emission_schedule() -> Weight;
 }
 
 /// Weight functions for `pallet_reward`.
 pub struct SubstrateWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeightInfo<T> {
-	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:0)
-	/// Proof: `Reward::ValidatorRewardAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:0)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
 	/// Proof: `Reward::EraRewardsVault` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn get_rewards() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `199`
-		//  Estimated: `3664`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3664))
+		//  Measured:  `112`
+		//  Estimated: `3577`
+		// Minimum execution time: 13_302_000 picoseconds.
+		Weight::from_parts(14_286_000, 0)
+			.saturating_add(Weight::from_parts(0, 3577))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:1)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:258 w:258)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:1)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:257 w:256)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::Payee` (r:257 w:0)
+	/// Proof: `Staking::Payee` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Bonded` (r:257 w:0)
+	/// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:257 w:257)
+	/// Proof: `Reward::BeneficialRewardRecord` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:256)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 256]`.
+	fn claim_era_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2448 + n * (47 ±0)`
+		//  Estimated: `6148 + n * (2579 ±0)`
+		// Minimum execution time: 80_445_000 picoseconds.
+		Weight::from_parts(40_688_233, 0)
+			.saturating_add(Weight::from_parts(0, 6148))
+			// Standard Error: 131_096
+			.saturating_add(Weight::from_parts(29_220_574, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(n.into()))
+	}
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:0)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:84 w:84)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Payee` (r:1 w:0)
+	/// Proof: `Staking::Payee` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:1 w:1)
+	/// Proof: `Reward::BeneficialRewardRecord` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraValidatorRewards` (r:84 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:84)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `e` is `[1, 84]`.
+	fn claim_nominator_reward(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1090 + e * (150 ±0)`
+		//  Estimated: `4555 + e * (2625 ±0)`
+		// Minimum execution time: 63_431_000 picoseconds.
+		Weight::from_parts(39_950_301, 0)
+			.saturating_add(Weight::from_parts(0, 4555))
+			// Standard Error: 112_330
+			.saturating_add(Weight::from_parts(22_631_092, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2625).saturating_mul(e.into()))
+	}
+	/// Storage: `Reward::NominatorRewardEras` (r:85 w:84)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:168 w:84)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Payee` (r:1 w:0)
+	/// Proof: `Staking::Payee` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:1 w:1)
+	/// Proof: `Reward::BeneficialRewardRecord` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraValidatorRewards` (r:84 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:84 w:84)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `e` is `[1, 84]`.
+	fn claim_all_nominator_rewards(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001 + e * (157 ±0)`
+		//  Estimated: `4466 + e * (5107 ±0)`
+		// Minimum execution time: 75_882_000 picoseconds.
+		Weight::from_parts(49_721_314, 0)
+			.saturating_add(Weight::from_parts(0, 4466))
+			// Standard Error: 47_303
+			.saturating_add(Weight::from_parts(28_635_193, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 5107).saturating_mul(e.into()))
+	}
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:4113 w:4112)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:1)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:4112)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 4112]`.
+	fn expire_era_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1057 + n * (44 ±0)`
+		//  Estimated: `5429 + n * (2519 ±0)`
+		// Minimum execution time: 4_709_000 picoseconds.
+		Weight::from_parts(4_934_000, 0)
+			.saturating_add(Weight::from_parts(0, 5429))
+			// Standard Error: 26_640
+			.saturating_add(Weight::from_parts(5_891_242, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(n.into()))
+	}
+	/// Storage: `Staking::ErasRewardPoints` (r:1 w:0)
+	/// Proof: `Staking::ErasRewardPoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasStakers` (r:1 w:0)
+	/// Proof: `Staking::ErasStakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasTotalStake` (r:1 w:0)
+	/// Proof: `Staking::ErasTotalStake` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Proof: `Staking::Validators` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:1)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:1)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:256 w:256)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:256)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 256]`.
+	fn calculate_validator_reward(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + n * (10 ±0)`
+		//  Estimated: `4089 + n * (2485 ±0)`
+		// Minimum execution time: 20_817_000 picoseconds.
+		Weight::from_parts(26_796_169, 0)
+			.saturating_add(Weight::from_parts(0, 4089))
+			// Standard Error: 12_837
+			.saturating_add(Weight::from_parts(2_976_996, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2485).saturating_mul(n.into()))
+	}
+	/// Storage: `Reward::PendingEmissionSchedule` (r:0 w:1)
+	/// Proof: `Reward::PendingEmissionSchedule` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_emission_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_594_000 picoseconds.
+		Weight::from_parts(5_954_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
 
 impl WeightInfo for () {
-	/// Storage: `Reward::ValidatorRewardAccounts` (r:1 w:0)
-	/// Proof: `Reward::ValidatorRewardAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:0)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Reward::EraRewardsVault` (r:1 w:1)
 	/// Proof: `Reward::EraRewardsVault` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn get_rewards() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `199`
-		//  Estimated: `3664`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3664))
+		//  Measured:  `112`
+		//  Estimated: `3577`
+		// Minimum execution time: 13_302_000 picoseconds.
+		Weight::from_parts(14_286_000, 0)
+			.saturating_add(Weight::from_parts(0, 3577))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:1)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:258 w:258)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:1)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:257 w:256)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::Payee` (r:257 w:0)
+	/// Proof: `Staking::Payee` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Bonded` (r:257 w:0)
+	/// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:257 w:257)
+	/// Proof: `Reward::BeneficialRewardRecord` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:256)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 256]`.
+	fn claim_era_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2448 + n * (47 ±0)`
+		//  Estimated: `6148 + n * (2579 ±0)`
+		// Minimum execution time: 80_445_000 picoseconds.
+		Weight::from_parts(40_688_233, 0)
+			.saturating_add(Weight::from_parts(0, 6148))
+			// Standard Error: 131_096
+			.saturating_add(Weight::from_parts(29_220_574, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(n.into()))
+	}
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:0)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:84 w:84)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Payee` (r:1 w:0)
+	/// Proof: `Staking::Payee` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:1 w:1)
+	/// Proof: `Reward::BeneficialRewardRecord` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraValidatorRewards` (r:84 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:84)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `e` is `[1, 84]`.
+	fn claim_nominator_reward(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1090 + e * (150 ±0)`
+		//  Estimated: `4555 + e * (2625 ±0)`
+		// Minimum execution time: 63_431_000 picoseconds.
+		Weight::from_parts(39_950_301, 0)
+			.saturating_add(Weight::from_parts(0, 4555))
+			// Standard Error: 112_330
+			.saturating_add(Weight::from_parts(22_631_092, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2625).saturating_mul(e.into()))
+	}
+	/// Storage: `Reward::NominatorRewardEras` (r:85 w:84)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:168 w:84)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Payee` (r:1 w:0)
+	/// Proof: `Staking::Payee` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Proof: `Staking::Bonded` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::BeneficialRewardRecord` (r:1 w:1)
+	/// Proof: `Reward::BeneficialRewardRecord` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraValidatorRewards` (r:84 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:84 w:84)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `e` is `[1, 84]`.
+	fn claim_all_nominator_rewards(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001 + e * (157 ±0)`
+		//  Estimated: `4466 + e * (5107 ±0)`
+		// Minimum execution time: 75_882_000 picoseconds.
+		Weight::from_parts(49_721_314, 0)
+			.saturating_add(Weight::from_parts(0, 4466))
+			// Standard Error: 47_303
+			.saturating_add(Weight::from_parts(28_635_193, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 5107).saturating_mul(e.into()))
+	}
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:0)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:4113 w:4112)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:1)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:4112)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 4112]`.
+	fn expire_era_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1057 + n * (44 ±0)`
+		//  Estimated: `5429 + n * (2519 ±0)`
+		// Minimum execution time: 4_709_000 picoseconds.
+		Weight::from_parts(4_934_000, 0)
+			.saturating_add(Weight::from_parts(0, 5429))
+			// Standard Error: 26_640
+			.saturating_add(Weight::from_parts(5_891_242, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(n.into()))
+	}
+	/// Storage: `Staking::ErasRewardPoints` (r:1 w:0)
+	/// Proof: `Staking::ErasRewardPoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasStakers` (r:1 w:0)
+	/// Proof: `Staking::ErasStakers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::ErasTotalStake` (r:1 w:0)
+	/// Proof: `Staking::ErasTotalStake` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Staking::Validators` (r:1 w:0)
+	/// Proof: `Staking::Validators` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `Reward::EraValidatorRewards` (r:1 w:1)
+	/// Proof: `Reward::EraValidatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::UnclaimedEras` (r:1 w:1)
+	/// Proof: `Reward::UnclaimedEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::EraNominatorRewards` (r:256 w:256)
+	/// Proof: `Reward::EraNominatorRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Reward::NominatorRewardEras` (r:0 w:256)
+	/// Proof: `Reward::NominatorRewardEras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 256]`.
+	fn calculate_validator_reward(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624 + n * (10 ±0)`
+		//  Estimated: `4089 + n * (2485 ±0)`
+		// Minimum execution time: 20_817_000 picoseconds.
+		Weight::from_parts(26_796_169, 0)
+			.saturating_add(Weight::from_parts(0, 4089))
+			// Standard Error: 12_837
+			.saturating_add(Weight::from_parts(2_976_996, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2485).saturating_mul(n.into()))
+	}
+	/// Storage: `Reward::PendingEmissionSchedule` (r:0 w:1)
+	/// Proof: `Reward::PendingEmissionSchedule` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_emission_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_594_000 picoseconds.
+		Weight::from_parts(5_954_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
-}
\ No newline at end of file
+}
@@ -20,6 +20,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_staking::ValidatorScores;
 
 sp_api::decl_runtime_apis! {
 	pub trait StakingApi<Balance>
@@ -29,4 +32,13 @@ sp_api::decl_runtime_apis! {
 		/// Returns the nominations quota for a nominator with a given balance.
 		fn nominations_quota(balance: Balance) -> u32;
 	}
+
+	pub trait ValidatorScoresApi<AccountId>
+		where
+			AccountId: Codec,
+	{
+		/// Returns the ESG score, reliability score, reward points in the active era and chilled
+		/// status of the current and waiting validators.
+		fn validator_scores() -> Vec<ValidatorScores<AccountId>>;
+	}
 }
@@ -939,6 +939,29 @@ benchmarks! {
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
 	}
 
+	set_author_points {
+		let points = AuthorPoints {
+			base: 10,
+			esg_weight: Perbill::from_percent(50),
+			esg_cap: 50,
+			reliability_weight: Perbill::from_percent(50),
+			reliability_cap: 50,
+		};
+	}: _(RawOrigin::Root, points)
+	verify {
+		assert_eq!(AuthorPointsConfig::<T>::get(), points);
+	}
+
+	note_author {
+		let author: T::AccountId = account("author", 0, SEED);
+		ActiveEra::<T>::put(ActiveEraInfo { index: 1, start: None });
+	}: {
+		Staking::<T>::reward_author(author.clone());
+	}
+	verify {
+		assert!(ErasRewardPoints::<T>::get(1).individual.contains_key(&author));
+	}
+
 	impl_benchmark_test_suite!(
 		Staking,
 		crate::mock::ExtBuilder::default().has_stakers(true),
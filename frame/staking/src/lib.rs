@@ -422,6 +422,69 @@ pub struct ValidatorPrefs {
 	pub blocked: bool,
 }
 
+/// Reward points earned by the author of a block, depending on its ESG and reliability scores.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AuthorPoints {
+	/// Points for producing a block, whatever the scores of the author.
+	pub base: RewardPoint,
+	/// Share of the ESG score of the author added to its points.
+	pub esg_weight: Perbill,
+	/// Maximum number of points added for the ESG score.
+	pub esg_cap: RewardPoint,
+	/// Share of the reliability score of the author added to its points.
+	pub reliability_weight: Perbill,
+	/// Maximum number of points added for the reliability score.
+	pub reliability_cap: RewardPoint,
+}
+
+impl Default for AuthorPoints {
+	fn default() -> Self {
+		AuthorPoints {
+			base: 20,
+			esg_weight: Perbill::one(),
+			esg_cap: RewardPoint::MAX,
+			reliability_weight: Perbill::one(),
+			reliability_cap: RewardPoint::MAX,
+		}
+	}
+}
+
+impl AuthorPoints {
+	/// Points earned by an author with the given scores.
+	pub fn points(&self, esg_score: u16, reliability_score: u16) -> RewardPoint {
+		let esg = (self.esg_weight * RewardPoint::from(esg_score)).min(self.esg_cap);
+		let reliability = (self.reliability_weight * RewardPoint::from(reliability_score))
+			.min(self.reliability_cap);
+		self.base.saturating_add(esg).saturating_add(reliability)
+	}
+}
+
+/// The scores behind the reward points of a validator in the active era.
+#[derive(
+	PartialEq,
+	Eq,
+	Clone,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	serde::Serialize,
+	serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorScores<AccountId> {
+	/// The stash account of the validator.
+	pub validator: AccountId,
+	/// ESG score of the validator.
+	pub esg_score: u16,
+	/// Reliability score of the validator.
+	pub reliability_score: u16,
+	/// Reward points earned by the validator so far in the active era.
+	pub reward_points: RewardPoint,
+	/// Whether the validator chilled while being active in the era.
+	pub chilled: bool,
+}
+
 /// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be unlocked.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct UnlockChunk<Balance: HasCompact + MaxEncodedLen> {
@@ -174,12 +174,34 @@ impl pallet_offences::Config for Test {
 	type OnOffenceHandler = Staking;
 }
 
+parameter_types! {
+	pub const EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 1, social: 1, governance: 1 };
+}
+
 impl pallet_esg::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type MaxFileSize = ConstU32<1024000>;
 	type MaxNumOfSudoOracles = ConstU32<5>;
 	type MaxNumOfNonSudoOracles = ConstU32<5>;
+	type MaxScoreHistory = ConstU32<16>;
+	type MaxBatch = ConstU32<100>;
+	type SubmissionQuorum = ConstU32<1>;
+	type OutlierThreshold = frame_support::traits::ConstU16<20>;
+	type ScoreValidity = ConstU64<{ u64::MAX }>;
+	type ScoreDecayPeriod = ConstU64<0>;
+	type Currency = Balances;
+	type OracleDeposit = frame_support::traits::ConstU128<0>;
+	type OracleTerm = ConstU64<{ u64::MAX }>;
+	type SlashOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type Slash = ();
+	type DisputeDeposit = frame_support::traits::ConstU128<0>;
+	type DisputeResolveOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
 }
 
 pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
@@ -192,6 +214,15 @@ where
 	type OverarchingCall = RuntimeCall;
 }
 
+parameter_types! {
+	pub const UptimeSmoothing: Perbill = Perbill::from_percent(10);
+	pub const MissedSessionPenalty: Perbill = Perbill::from_percent(25);
+	pub const AuthoringWeight: Perbill = Perbill::from_percent(50);
+	pub const RecoverySessions: u32 = 4;
+	pub const MaxReliabilityHistory: u32 = 4;
+	pub const MaxWaitingScoresPerBlock: u32 = 100;
+}
+
 impl pallet_im_online::Config for Test {
 	type AuthorityId = UintAuthorityId;
 	type RuntimeEvent = RuntimeEvent;
@@ -204,6 +235,12 @@ impl pallet_im_online::Config for Test {
 	type MaxPeerInHeartbeats = ConstU32<10_000>;
 	type DataProvider = Staking;
 	type TargetsBound = MaxOnChainElectableTargets;
+	type UptimeSmoothing = UptimeSmoothing;
+	type MissedSessionPenalty = MissedSessionPenalty;
+	type AuthoringWeight = AuthoringWeight;
+	type RecoverySessions = RecoverySessions;
+	type MaxReliabilityHistory = MaxReliabilityHistory;
+	type MaxWaitingScoresPerBlock = MaxWaitingScoresPerBlock;
 }
 
 sp_runtime::impl_opaque_keys! {
@@ -45,13 +45,13 @@ use sp_staking::{
 	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
 	EraIndex, SessionIndex, Stake, StakingInterface,
 };
-use sp_std::prelude::*;
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 
 use crate::{
 	election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
 	BalanceOf, EraPayout, Exposure, ExposureOf, Forcing, IndividualExposure, MaxNominationsOf,
 	MaxWinnersOf, Nominations, NominationsQuota, RewardDestination, SessionInterface,
-	StakingLedger, ValidatorPrefs,
+	StakingLedger, ValidatorPrefs, ValidatorScores,
 };
 
 use super::{pallet::*, STAKING_ID};
@@ -299,11 +299,15 @@ impl<T: Config> Pallet<T> {
 			let (validator_payout, remainder) =
 				T::EraPayout::era_payout(staked, issuance, era_duration);
 
-			let _ = T::RewardDistribution::calculate_reward();
-			let reward = T::RewardDistribution::payout_validators();
-			reward.iter().for_each(|accounts| {
-				let _ = T::RewardDistribution::claim_rewards(accounts.clone());
-			});
+			// The reward distribution computes and pays out the era rewards over the next blocks.
+			if let Err(e) = T::RewardDistribution::calculate_reward() {
+				log!(
+					warn,
+					"the reward distribution of era {:?} could not be scheduled: {:?}",
+					active_era.index,
+					e,
+				);
+			}
 
 			// Set ending era reward.
 			<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
@@ -576,6 +580,15 @@ impl<T: Config> Pallet<T> {
 		reward
 	}
 
+	/// Rewards the author of a block with `AuthorPointsConfig` points.
+	pub(crate) fn reward_author(author: T::AccountId) {
+		let points = AuthorPointsConfig::<T>::get().points(
+			T::ESG::get_score_of(author.clone()),
+			T::Reliability::get_score_of(author.clone()),
+		);
+		Self::reward_by_ids(vec![(author, points)])
+	}
+
 	/// Helper to set a new `ForceEra` mode.
 	pub(crate) fn set_force_era(mode: Forcing) {
 		log!(info, "Setting force era mode {:?}.", mode);
@@ -880,6 +893,30 @@ impl<T: Config> Pallet<T> {
 	pub fn api_nominations_quota(balance: BalanceOf<T>) -> u32 {
 		T::NominationsQuota::get_quota(balance)
 	}
+
+	/// Returns the scores of the validators of the current session and of the validators waiting
+	/// to be elected.
+	///
+	/// Used by the runtime API.
+	pub fn api_validator_scores() -> Vec<ValidatorScores<T::AccountId>> {
+		let era = Self::active_era().map(|era| era.index).unwrap_or_default();
+		let reward_points = ErasRewardPoints::<T>::get(era).individual;
+
+		let validators: BTreeSet<T::AccountId> = T::SessionInterface::validators()
+			.into_iter()
+			.chain(Validators::<T>::iter_keys())
+			.collect();
+		validators
+			.into_iter()
+			.map(|validator| ValidatorScores {
+				esg_score: T::ESG::get_score_of(validator.clone()),
+				reliability_score: T::Reliability::get_score_of(validator.clone()),
+				reward_points: reward_points.get(&validator).copied().unwrap_or_default(),
+				chilled: T::Reliability::is_chilled(validator.clone()),
+				validator,
+			})
+			.collect()
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1136,16 +1173,18 @@ impl<T: Config> historical::SessionManager<T::AccountId, Exposure<T::AccountId,
 }
 
 /// Add reward points to block authors:
-/// * 20 points to the block producer for producing a (non-uncle) block,
+/// * `AuthorPointsConfig` points to the block producer for producing a (non-uncle) block,
+/// depending on its ESG and reliability scores.
 impl<T> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T>
 where
 	T: Config + pallet_authorship::Config + pallet_session::Config,
 {
 	fn note_author(author: T::AccountId) {
-		let esg_score = T::ESG::get_score_of(author.clone());
-		let reliability_score = T::Reliability::get_score_of(author.clone());
-		let esg_reliability_score = esg_score.saturating_add(reliability_score);
-		Self::reward_by_ids(vec![(author.clone(), 20.saturating_add(esg_reliability_score).into())])
+		<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+			<T as Config>::WeightInfo::note_author(),
+			DispatchClass::Mandatory,
+		);
+		Self::reward_author(author)
 	}
 }
 
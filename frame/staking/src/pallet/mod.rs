@@ -48,10 +48,10 @@ use crate::Rewards;
 pub use impls::*;
 
 use crate::{
-	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf, EraPayout,
-	EraRewardPoints, Exposure, Forcing, MaxNominationsOf, NegativeImbalanceOf, Nominations,
-	NominationsQuota, PositiveImbalanceOf, RewardDestination, SessionInterface, StakingLedger,
-	UnappliedSlash, UnlockChunk, ValidatorPrefs,
+	slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, AuthorPoints, BalanceOf,
+	EraPayout, EraRewardPoints, Exposure, Forcing, MaxNominationsOf, NegativeImbalanceOf,
+	Nominations, NominationsQuota, PositiveImbalanceOf, RewardDestination, SessionInterface,
+	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 const STAKING_ID: LockIdentifier = *b"staking ";
@@ -331,6 +331,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MinCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+	/// The reward points earned by block authors, depending on their ESG and reliability scores.
+	#[pallet::storage]
+	pub type AuthorPointsConfig<T: Config> = StorageValue<_, AuthorPoints, ValueQuery>;
+
 	/// Map from all (unlocked) "controller" accounts to the info regarding the staking.
 	#[pallet::storage]
 	#[pallet::getter(fn ledger)]
@@ -719,6 +723,8 @@ pub mod pallet {
 		ForceEra { mode: Forcing },
 		/// renominate   event
 		NominatorPrefsSet { stash: T::AccountId, nominations: Nominations<T> },
+		/// The reward points of block authors have been updated.
+		AuthorPointsSet { points: AuthorPoints },
 	}
 
 	#[pallet::error]
@@ -1770,6 +1776,19 @@ pub mod pallet {
 			MinCommission::<T>::put(new);
 			Ok(())
 		}
+
+		/// Sets the reward points earned by block authors: the base points and the weight and
+		/// cap of the ESG and reliability scores of the author.
+		///
+		/// Can be called by the `T::AdminOrigin`. Root can always call this.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_author_points())]
+		pub fn set_author_points(origin: OriginFor<T>, points: AuthorPoints) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			AuthorPointsConfig::<T>::put(points);
+			Self::deposit_event(Event::<T>::AuthorPointsSet { points });
+			Ok(())
+		}
 	}
 }
 
@@ -2253,6 +2253,58 @@ fn reward_from_authorship_event_handler_works() {
 	})
 }
 
+#[test]
+fn reward_from_authorship_event_handler_follows_author_points() {
+	ExtBuilder::default().build_and_execute(|| {
+		use pallet_authorship::EventHandler;
+
+		// the mock uses the ESG score as the reliability score too
+		pallet_esg::ESGScoresMap::<Test>::insert(11, 50);
+		Pallet::<Test>::note_author(11);
+		assert_eq!(ErasRewardPoints::<Test>::get(active_era()).individual[&11], 20 + 50 + 50);
+
+		let points = AuthorPoints {
+			base: 10,
+			esg_weight: Perbill::from_percent(50),
+			esg_cap: 20,
+			reliability_weight: Perbill::from_percent(100),
+			reliability_cap: RewardPoint::MAX,
+		};
+		assert_noop!(Staking::set_author_points(RuntimeOrigin::signed(2), points), BadOrigin);
+		assert_ok!(Staking::set_author_points(RuntimeOrigin::signed(1), points));
+		assert_eq!(*staking_events().last().unwrap(), Event::AuthorPointsSet { points });
+
+		// half of the ESG score is capped at 20
+		Pallet::<Test>::note_author(11);
+		assert_eq!(ErasRewardPoints::<Test>::get(active_era()).individual[&11], 120 + 10 + 20 + 50);
+	})
+}
+
+#[test]
+fn api_validator_scores_reports_scores_and_points() {
+	ExtBuilder::default().build_and_execute(|| {
+		pallet_esg::ESGScoresMap::<Test>::insert(11, 50);
+		Pallet::<Test>::reward_by_ids(vec![(11, 70)]);
+
+		let scores = Staking::api_validator_scores();
+		let scores_of = |who| scores.iter().find(|s| s.validator == who).cloned();
+		assert_eq!(
+			scores_of(11),
+			Some(ValidatorScores {
+				validator: 11,
+				esg_score: 50,
+				// the mock uses the ESG score as the reliability score too
+				reliability_score: 50,
+				reward_points: 70,
+				chilled: false,
+			})
+		);
+		assert_eq!(scores_of(21).map(|s| (s.esg_score, s.reward_points)), Some((0, 0)));
+		// nominators are not reported
+		assert_eq!(scores_of(101), None);
+	})
+}
+
 #[test]
 fn add_reward_points_fns_works() {
 	ExtBuilder::default().build_and_execute(|| {
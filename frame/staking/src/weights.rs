@@ -83,6 +83,8 @@ pub trait WeightInfo {
 	fn force_apply_min_commission() -> Weight;
 	fn set_min_commission() -> Weight;
 	fn update_esg() -> Weight;
+	fn set_author_points() -> Weight;
+	fn note_author() -> Weight;
 }
 
 /// Weights for pallet_staking using the Substrate node and recommended hardware.
@@ -800,6 +802,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Staking AuthorPointsConfig (r:0 w:1)
+	/// Proof: Staking AuthorPointsConfig (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	fn set_author_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_102_000 picoseconds.
+		Weight::from_parts(5_371_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Staking AuthorPointsConfig (r:1 w:0)
+	/// Proof: Staking AuthorPointsConfig (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: EsgScore ESGScoreHistory (r:2 w:0)
+	/// Proof Skipped: EsgScore ESGScoreHistory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EsgScore Disputes (r:2 w:0)
+	/// Proof Skipped: EsgScore Disputes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EsgScore ESGScoresMap (r:2 w:0)
+	/// Proof Skipped: EsgScore ESGScoresMap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Staking ActiveEra (r:1 w:0)
+	/// Proof: Staking ActiveEra (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
+	/// Storage: Staking ErasRewardPoints (r:1 w:1)
+	/// Proof Skipped: Staking ErasRewardPoints (max_values: None, max_size: None, mode: Measured)
+	fn note_author() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `3922`
+		// Minimum execution time: 17_829_000 picoseconds.
+		Weight::from_parts(18_404_000, 3922)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1517,4 +1550,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Storage: Staking AuthorPointsConfig (r:0 w:1)
+	/// Proof: Staking AuthorPointsConfig (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	fn set_author_points() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_102_000 picoseconds.
+		Weight::from_parts(5_371_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Staking AuthorPointsConfig (r:1 w:0)
+	/// Proof: Staking AuthorPointsConfig (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: EsgScore ESGScoreHistory (r:2 w:0)
+	/// Proof Skipped: EsgScore ESGScoreHistory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EsgScore Disputes (r:2 w:0)
+	/// Proof Skipped: EsgScore Disputes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EsgScore ESGScoresMap (r:2 w:0)
+	/// Proof Skipped: EsgScore ESGScoresMap (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Staking ActiveEra (r:1 w:0)
+	/// Proof: Staking ActiveEra (max_values: Some(1), max_size: Some(13), added: 508, mode: MaxEncodedLen)
+	/// Storage: Staking ErasRewardPoints (r:1 w:1)
+	/// Proof Skipped: Staking ErasRewardPoints (max_values: None, max_size: None, mode: Measured)
+	fn note_author() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `457`
+		//  Estimated: `3922`
+		// Minimum execution time: 17_829_000 picoseconds.
+		Weight::from_parts(18_404_000, 3922)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
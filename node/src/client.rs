@@ -286,6 +286,8 @@ pub trait RuntimeApiCollection:
 	+ sp_consensus_grandpa::GrandpaApi<Block>
 	+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 	+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+	+ pallet_reward_rpc_runtime_api::RewardApi<Block, AccountId, Balance>
+	+ pallet_staking_runtime_api::ValidatorScoresApi<Block, AccountId>
 {
 }
 
@@ -296,6 +298,8 @@ impl<Api> RuntimeApiCollection for Api where
 		+ sp_consensus_grandpa::GrandpaApi<Block>
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+		+ pallet_reward_rpc_runtime_api::RewardApi<Block, AccountId, Balance>
+		+ pallet_staking_runtime_api::ValidatorScoresApi<Block, AccountId>
 {
 }
 
@@ -0,0 +1,69 @@
+//! RPC exposing the scores behind the reward points of the validators.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use pallet_staking_runtime_api::ValidatorScoresApi as ValidatorScoresRuntimeApi;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Validator scores RPC methods.
+#[rpc(server)]
+pub trait ValidatorScoresApi<BlockHash, AccountId> {
+	/// Returns the ESG score, reliability score, reward points in the active era and chilled
+	/// status of the current and waiting validators.
+	#[method(name = "staking_validatorScores")]
+	fn validator_scores(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<pallet_staking_runtime_api::ValidatorScores<AccountId>>>;
+}
+
+/// Provides RPC methods to query the scores of the validators.
+pub struct ValidatorScores<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> ValidatorScores<C, B> {
+	/// Creates a new instance of the validator scores RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId> ValidatorScoresApiServer<<Block as BlockT>::Hash, AccountId>
+	for ValidatorScores<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ValidatorScoresRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn validator_scores(
+		&self,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<pallet_staking_runtime_api::ValidatorScores<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.validator_scores(at_hash).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Unable to query validator scores.",
+				Some(e.to_string()),
+			))
+			.into()
+		})
+	}
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Council contract's address.
+address constant COUNCIL_ADDRESS = 0x0000000000000000000000000000000000001005;
+
+/// @dev The Council contract's instance.
+Collective constant COUNCIL_CONTRACT = Collective(COUNCIL_ADDRESS);
+
+/// @dev The Technical Committee contract's address.
+address constant TECHNICAL_COMMITTEE_ADDRESS = 0x0000000000000000000000000000000000001006;
+
+/// @dev The Technical Committee contract's instance.
+Collective constant TECHNICAL_COMMITTEE_CONTRACT = Collective(TECHNICAL_COMMITTEE_ADDRESS);
+
+/// @title Collective precompile
+/// @dev Allows the members of a collective, such as the council or the technical committee, to
+/// propose motions, vote on them and close them.
+/// @custom:address 0x0000000000000000000000000000000000001005
+interface Collective {
+    /// @dev Execute a proposal with the caller as the single member approving it.
+    /// @param proposal The SCALE encoded call to execute.
+    /// @custom:selector 09c5eabe
+    function execute(bytes memory proposal) external;
+
+    /// @dev Propose a motion. It is executed right away if the threshold is lower than 2.
+    /// @param threshold The number of members approving the motion needed to execute it.
+    /// @param proposal The SCALE encoded call of the motion.
+    /// @return index The index of the motion.
+    /// @custom:selector c57f3260
+    function propose(uint32 threshold, bytes memory proposal)
+        external
+        returns (uint32 index);
+
+    /// @dev Vote on a motion.
+    /// @param proposalHash The hash of the motion.
+    /// @param proposalIndex The index of the motion.
+    /// @param approve Whether the caller approves the motion.
+    /// @custom:selector 73e37688
+    function vote(
+        bytes32 proposalHash,
+        uint32 proposalIndex,
+        bool approve
+    ) external;
+
+    /// @dev Close a motion, executing it if it has been approved.
+    /// @param proposalHash The hash of the motion.
+    /// @param proposalIndex The index of the motion.
+    /// @param proposalRefTimeBound The maximum ref time the motion may use to execute.
+    /// @param proposalProofSizeBound The maximum proof size the motion may use to execute.
+    /// @param lengthBound The length of the SCALE encoded call of the motion.
+    /// @return executed Whether the motion has been executed.
+    /// @custom:selector 1c5cea41
+    function close(
+        bytes32 proposalHash,
+        uint32 proposalIndex,
+        uint64 proposalRefTimeBound,
+        uint64 proposalProofSizeBound,
+        uint32 lengthBound
+    ) external returns (bool executed);
+
+    /// @dev Compute the hash of a proposal.
+    /// @param proposal The SCALE encoded call.
+    /// @return The hash of the proposal.
+    /// @custom:selector fc379417
+    function proposalHash(bytes memory proposal)
+        external
+        view
+        returns (bytes32);
+
+    /// @dev The hashes of the motions being voted on.
+    /// @custom:selector 55ef20e6
+    function proposals() external view returns (bytes32[] memory);
+
+    /// @dev Whether an account is a member of the collective.
+    /// @param account The account to check.
+    /// @custom:selector a230c524
+    function isMember(address account) external view returns (bool);
+
+    /// @dev The members of the collective.
+    /// @custom:selector bdd4d18d
+    function members() external view returns (address[] memory);
+
+    /// @dev The prime member of the collective, or the zero address if there is none.
+    /// @custom:selector c7ee005e
+    function prime() external view returns (address);
+
+    /// @dev A proposal has been executed.
+    /// @custom:selector a74c8847d513feba22a0f0cb38d53081abf97562cdb293926ba243689e7c41ca
+    /// @param proposalHash The hash of the proposal.
+    event Executed(bytes32 indexed proposalHash);
+
+    /// @dev A motion has been proposed.
+    /// @custom:selector 5acd7a1d9d1fe158b3fe3be10bbc1c44d8571e4a3696e2d195afd36f2952d9c7
+    /// @param who The member proposing the motion.
+    /// @param proposalIndex The index of the motion.
+    /// @param proposalHash The hash of the motion.
+    /// @param threshold The number of approvals needed to execute the motion.
+    event Proposed(
+        address indexed who,
+        uint32 indexed proposalIndex,
+        bytes32 indexed proposalHash,
+        uint32 threshold
+    );
+
+    /// @dev A member voted on a motion.
+    /// @custom:selector a448f14934e131ddc08d9e2eb30b168167cdd3ef91b829718c99b539153b5222
+    /// @param who The member voting.
+    /// @param proposalHash The hash of the motion.
+    /// @param voted Whether the member approves the motion.
+    event Voted(address indexed who, bytes32 indexed proposalHash, bool voted);
+
+    /// @dev A motion has been closed without being executed.
+    /// @custom:selector 7b6ac8bce3193cb9464e9070476bf8926e449f5f743f8c7578eea15265467d79
+    /// @param proposalHash The hash of the motion.
+    event Closed(bytes32 indexed proposalHash);
+}
@@ -0,0 +1,279 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::{
+	dispatch::{GetDispatchInfo, Pays, PostDispatchInfo},
+	traits::{ConstU32, Get},
+	weights::Weight,
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::DecodeLimit;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256};
+use sp_runtime::traits::{Dispatchable, Hash};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum size of an encoded proposal.
+pub const PROPOSAL_LIMIT: u32 = 2u32.pow(16);
+
+/// Maximum depth of the calls nested in a proposal, as in XCM call decoding.
+pub const PROPOSAL_DECODE_LIMIT: u32 = 8;
+
+type GetProposalLimit = ConstU32<PROPOSAL_LIMIT>;
+
+type ProposalOf<Runtime, Instance> = <Runtime as pallet_collective::Config<Instance>>::Proposal;
+
+/// Solidity selector of the Executed log.
+pub const SELECTOR_LOG_EXECUTED: [u8; 32] = keccak256!("Executed(bytes32)");
+
+/// Solidity selector of the Proposed log.
+pub const SELECTOR_LOG_PROPOSED: [u8; 32] = keccak256!("Proposed(address,uint32,bytes32,uint32)");
+
+/// Solidity selector of the Voted log.
+pub const SELECTOR_LOG_VOTED: [u8; 32] = keccak256!("Voted(address,bytes32,bool)");
+
+/// Solidity selector of the Closed log.
+pub const SELECTOR_LOG_CLOSED: [u8; 32] = keccak256!("Closed(bytes32)");
+
+pub fn log_executed(address: impl Into<H160>, hash: H256) -> Log {
+	log2(address, SELECTOR_LOG_EXECUTED, hash, Vec::new())
+}
+
+pub fn log_proposed(
+	address: impl Into<H160>,
+	who: H160,
+	index: u32,
+	hash: H256,
+	threshold: u32,
+) -> Log {
+	log4(
+		address,
+		SELECTOR_LOG_PROPOSED,
+		who,
+		H256::from_low_u64_be(index.into()),
+		hash,
+		solidity::encode_event_data(threshold),
+	)
+}
+
+pub fn log_voted(address: impl Into<H160>, who: H160, hash: H256, voted: bool) -> Log {
+	log3(address, SELECTOR_LOG_VOTED, who, hash, solidity::encode_event_data(voted))
+}
+
+pub fn log_closed(address: impl Into<H160>, hash: H256) -> Log {
+	log2(address, SELECTOR_LOG_CLOSED, hash, Vec::new())
+}
+
+/// Precompile of a `pallet_collective` instance, such as the council or the technical committee.
+#[derive(Debug, Clone)]
+pub struct CollectivePrecompile<Runtime, Instance: 'static>(PhantomData<(Runtime, Instance)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Instance> CollectivePrecompile<Runtime, Instance>
+where
+	Instance: 'static,
+	Runtime: pallet_collective::Config<Instance> + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::Hash: From<H256> + Into<H256>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_collective::Call<Runtime, Instance>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	#[precompile::public("execute(bytes)")]
+	fn execute(
+		handle: &mut impl PrecompileHandle,
+		proposal: BoundedBytes<GetProposalLimit>,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let proposal: Vec<_> = proposal.into();
+		let proposal_hash: H256 = Runtime::Hashing::hash(&proposal).into();
+		let length_bound = proposal.len() as u32;
+		let proposal = Self::decode_proposal(proposal).in_field("proposal")?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::execute {
+			proposal: Box::new(proposal),
+			length_bound,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log_executed(handle.context().address, proposal_hash).record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("propose(uint32,bytes)")]
+	fn propose(
+		handle: &mut impl PrecompileHandle,
+		threshold: u32,
+		proposal: BoundedBytes<GetProposalLimit>,
+	) -> EvmResult<u32> {
+		// Storage item: ProposalCount:
+		// ProposalIndex(4)
+		handle.record_db_read::<Runtime>(4)?;
+		handle.record_log_costs_manual(4, 32)?;
+
+		let proposal: Vec<_> = proposal.into();
+		let proposal_hash: H256 = Runtime::Hashing::hash(&proposal).into();
+		let length_bound = proposal.len() as u32;
+		let proposal = Self::decode_proposal(proposal).in_field("proposal")?;
+		let proposal_index = pallet_collective::Pallet::<Runtime, Instance>::proposal_count();
+
+		let caller = handle.context().caller;
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::propose {
+			threshold,
+			proposal: Box::new(proposal),
+			length_bound,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		// Proposals needing a single approval are executed right away.
+		let log = if threshold < 2 {
+			log_executed(handle.context().address, proposal_hash)
+		} else {
+			log_proposed(handle.context().address, caller, proposal_index, proposal_hash, threshold)
+		};
+		log.record(handle)?;
+
+		Ok(proposal_index)
+	}
+
+	#[precompile::public("vote(bytes32,uint32,bool)")]
+	fn vote(
+		handle: &mut impl PrecompileHandle,
+		proposal_hash: H256,
+		proposal_index: u32,
+		approve: bool,
+	) -> EvmResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let caller = handle.context().caller;
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::vote {
+			proposal: proposal_hash.into(),
+			index: proposal_index,
+			approve,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log_voted(handle.context().address, caller, proposal_hash, approve).record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("close(bytes32,uint32,uint64,uint64,uint32)")]
+	fn close(
+		handle: &mut impl PrecompileHandle,
+		proposal_hash: H256,
+		proposal_index: u32,
+		proposal_ref_time_bound: u64,
+		proposal_proof_size_bound: u64,
+		length_bound: u32,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(2, 0)?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_collective::Call::<Runtime, Instance>::close {
+			proposal_hash: proposal_hash.into(),
+			index: proposal_index,
+			proposal_weight_bound: Weight::from_parts(
+				proposal_ref_time_bound,
+				proposal_proof_size_bound,
+			),
+			length_bound,
+		};
+		let post_dispatch_info =
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		// Closing only makes the caller pay when the proposal got approved and executed.
+		let (executed, log) = match post_dispatch_info.pays_fee {
+			Pays::Yes => (true, log_executed(handle.context().address, proposal_hash)),
+			Pays::No => (false, log_closed(handle.context().address, proposal_hash)),
+		};
+		log.record(handle)?;
+
+		Ok(executed)
+	}
+
+	#[precompile::public("proposalHash(bytes)")]
+	#[precompile::view]
+	fn proposal_hash(
+		_handle: &mut impl PrecompileHandle,
+		proposal: BoundedBytes<GetProposalLimit>,
+	) -> EvmResult<H256> {
+		let proposal: Vec<_> = proposal.into();
+
+		Ok(Runtime::Hashing::hash(&proposal).into())
+	}
+
+	#[precompile::public("proposals()")]
+	#[precompile::view]
+	fn proposals(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+		// Storage item: Proposals:
+		// BoundedVec(4 + MaxProposals * Hash(32))
+		let max_proposals = <Runtime as pallet_collective::Config<Instance>>::MaxProposals::get();
+		handle.record_db_read::<Runtime>(4 + 32 * max_proposals as usize)?;
+
+		Ok(pallet_collective::Pallet::<Runtime, Instance>::proposals()
+			.into_iter()
+			.map(Into::into)
+			.collect())
+	}
+
+	#[precompile::public("isMember(address)")]
+	#[precompile::view]
+	fn is_member(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<bool> {
+		Self::record_members_read(handle)?;
+
+		let account = Runtime::AddressMapping::into_account_id(account.into());
+		Ok(pallet_collective::Pallet::<Runtime, Instance>::is_member(&account))
+	}
+
+	#[precompile::public("members()")]
+	#[precompile::view]
+	fn members(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+		Self::record_members_read(handle)?;
+
+		Ok(pallet_collective::Pallet::<Runtime, Instance>::members()
+			.into_iter()
+			.map(|member| Address(member.into()))
+			.collect())
+	}
+
+	#[precompile::public("prime()")]
+	#[precompile::view]
+	fn prime(handle: &mut impl PrecompileHandle) -> EvmResult<Address> {
+		// Storage item: Prime:
+		// Option(1) + AccountId(20)
+		handle.record_db_read::<Runtime>(21)?;
+
+		Ok(pallet_collective::Pallet::<Runtime, Instance>::prime()
+			.map(|prime| Address(prime.into()))
+			.unwrap_or_default())
+	}
+
+	fn record_members_read(handle: &mut impl PrecompileHandle) -> EvmResult {
+		// Storage item: Members:
+		// Vec(4) + MaxMembers * AccountId(20)
+		let max_members = <Runtime as pallet_collective::Config<Instance>>::MaxMembers::get();
+		handle.record_db_read::<Runtime>(4 + 20 * max_members as usize)?;
+
+		Ok(())
+	}
+
+	fn decode_proposal(proposal: Vec<u8>) -> MayRevert<ProposalOf<Runtime, Instance>> {
+		ProposalOf::<Runtime, Instance>::decode_with_depth_limit(
+			PROPOSAL_DECODE_LIMIT,
+			&mut &*proposal,
+		)
+		.map_err(|_| RevertReason::custom("Failed to decode proposal").into())
+	}
+}
@@ -0,0 +1,276 @@
+use crate::{
+	log_closed, log_executed, log_proposed, log_voted,
+	mock::{
+		events, Alice, Bob, Charlie, Council, David, ExtBuilder, MockPrecompileSet, PCall,
+		Precompile1, PrecompilesValue, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	},
+};
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn precompiles() -> MockPrecompileSet<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000), (Charlie.into(), 1_000)])
+		.with_members(vec![Alice.into(), Bob.into(), Charlie.into()])
+		.build()
+}
+
+/// A proposal any origin can dispatch.
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: b"remark".to_vec() })
+}
+
+fn remark_hash() -> H256 {
+	BlakeTwo256::hash_of(&remark())
+}
+
+fn propose(threshold: u32) {
+	precompiles()
+		.prepare_test(
+			Alice,
+			Precompile1,
+			PCall::propose { threshold, proposal: remark().encode().into() },
+		)
+		.execute_returns(0u32);
+}
+
+fn vote(voter: impl Into<MockAccount>, approve: bool) {
+	precompiles()
+		.prepare_test(
+			voter.into(),
+			Precompile1,
+			PCall::vote { proposal_hash: remark_hash(), proposal_index: 0, approve },
+		)
+		.execute_returns(());
+}
+
+fn close() -> PCall {
+	let weight = remark().get_dispatch_info().weight;
+	PCall::close {
+		proposal_hash: remark_hash(),
+		proposal_index: 0,
+		proposal_ref_time_bound: weight.ref_time(),
+		proposal_proof_size_bound: weight.proof_size(),
+		length_bound: remark().encode().len() as u32,
+	}
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::execute_selectors().contains(&0x09c5eabe));
+	assert!(PCall::propose_selectors().contains(&0xc57f3260));
+	assert!(PCall::vote_selectors().contains(&0x73e37688));
+	assert!(PCall::close_selectors().contains(&0x1c5cea41));
+	assert!(PCall::proposal_hash_selectors().contains(&0xfc379417));
+	assert!(PCall::proposals_selectors().contains(&0x55ef20e6));
+	assert!(PCall::is_member_selectors().contains(&0xa230c524));
+	assert!(PCall::members_selectors().contains(&0xbdd4d18d));
+	assert!(PCall::prime_selectors().contains(&0xc7ee005e));
+}
+
+#[test]
+fn modifiers() {
+	ext().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::execute_selectors());
+		tester.test_default_modifier(PCall::propose_selectors());
+		tester.test_default_modifier(PCall::vote_selectors());
+		tester.test_default_modifier(PCall::close_selectors());
+		tester.test_view_modifier(PCall::proposal_hash_selectors());
+		tester.test_view_modifier(PCall::proposals_selectors());
+		tester.test_view_modifier(PCall::is_member_selectors());
+		tester.test_view_modifier(PCall::members_selectors());
+		tester.test_view_modifier(PCall::prime_selectors());
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["src/Collective.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn execute_works_for_members() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::execute { proposal: remark().encode().into() })
+			.expect_log(log_executed(Precompile1, remark_hash()))
+			.execute_returns(());
+
+		assert!(events().contains(&RuntimeEvent::Council(
+			pallet_collective::Event::MemberExecuted {
+				proposal_hash: remark_hash(),
+				result: Ok(()),
+			}
+		)));
+	});
+}
+
+#[test]
+fn execute_reverts_for_non_members() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(David, Precompile1, PCall::execute { proposal: remark().encode().into() })
+			.execute_reverts(|output| core::str::from_utf8(output).unwrap().contains("NotMember"));
+	});
+}
+
+#[test]
+fn execute_reverts_for_undecodable_proposals() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::execute { proposal: vec![0xff; 4].into() })
+			.execute_reverts(|output| output == b"proposal: Failed to decode proposal");
+	});
+}
+
+#[test]
+fn propose_with_threshold_of_one_executes() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::propose { threshold: 1, proposal: remark().encode().into() },
+			)
+			.expect_log(log_executed(Precompile1, remark_hash()))
+			.execute_returns(0u32);
+
+		assert_eq!(Council::proposals().len(), 0);
+	});
+}
+
+#[test]
+fn propose_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::propose { threshold: 2, proposal: remark().encode().into() },
+			)
+			.expect_log(log_proposed(Precompile1, Alice.into(), 0, remark_hash(), 2))
+			.execute_returns(0u32);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::proposals {})
+			.execute_returns(vec![remark_hash()]);
+	});
+}
+
+#[test]
+fn vote_works() {
+	ext().execute_with(|| {
+		propose(2);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::vote { proposal_hash: remark_hash(), proposal_index: 0, approve: true },
+			)
+			.expect_log(log_voted(Precompile1, Bob.into(), remark_hash(), true))
+			.execute_returns(());
+
+		let votes = Council::voting(remark_hash()).expect("proposal is being voted on");
+		assert!(votes.ayes.contains(&Bob.into()));
+	});
+}
+
+#[test]
+fn close_executes_approved_proposals() {
+	ext().execute_with(|| {
+		propose(2);
+		vote(Bob, true);
+		vote(Charlie, true);
+
+		precompiles()
+			.prepare_test(Charlie, Precompile1, close())
+			.expect_log(log_executed(Precompile1, remark_hash()))
+			.execute_returns(true);
+
+		assert_eq!(Council::proposals().len(), 0);
+		assert!(events().contains(&RuntimeEvent::Council(pallet_collective::Event::Executed {
+			proposal_hash: remark_hash(),
+			result: Ok(()),
+		})));
+	});
+}
+
+#[test]
+fn close_rejects_disapproved_proposals() {
+	ext().execute_with(|| {
+		propose(2);
+		vote(Bob, false);
+		vote(Charlie, false);
+
+		precompiles()
+			.prepare_test(Charlie, Precompile1, close())
+			.expect_log(log_closed(Precompile1, remark_hash()))
+			.execute_returns(false);
+
+		assert_eq!(Council::proposals().len(), 0);
+		assert!(events().contains(&RuntimeEvent::Council(pallet_collective::Event::Disapproved {
+			proposal_hash: remark_hash(),
+		})));
+	});
+}
+
+#[test]
+fn proposal_hash_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::proposal_hash { proposal: remark().encode().into() },
+			)
+			.execute_returns(remark_hash());
+	});
+}
+
+#[test]
+fn members_and_prime() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::members {})
+			.execute_returns(vec![
+				Address(Alice.into()),
+				Address(Bob.into()),
+				Address(Charlie.into()),
+			]);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_member { account: Address(Bob.into()) })
+			.execute_returns(true);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::is_member { account: Address(David.into()) })
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::prime {})
+			.execute_returns(Address(H160::zero()));
+
+		assert_ok!(Council::set_members(
+			RuntimeOrigin::root(),
+			vec![Alice.into(), Bob.into()],
+			Some(Bob.into()),
+			3,
+		));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::prime {})
+			.execute_returns(Address(Bob.into()));
+	});
+}
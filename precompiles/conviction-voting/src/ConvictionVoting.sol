@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Conviction Voting contract's address.
+address constant CONVICTION_VOTING_ADDRESS = 0x0000000000000000000000000000000000001007;
+
+/// @dev The Conviction Voting contract's instance.
+ConvictionVoting constant CONVICTION_VOTING_CONTRACT = ConvictionVoting(
+    CONVICTION_VOTING_ADDRESS
+);
+
+/// @title Conviction Voting precompile
+/// @dev Allows accounts and contracts to vote on referenda with conviction, and to delegate their
+/// votes on a track.
+/// @custom:address 0x0000000000000000000000000000000000001007
+interface ConvictionVoting {
+    /// @dev Lock held on the balance of an account for one of the tracks it voted on.
+    struct ClassLock {
+        /// The track of the lock.
+        uint16 trackId;
+        /// The amount locked.
+        uint256 amount;
+    }
+
+    /// @dev Vote of an account on a poll. Only standard votes have a conviction.
+    struct PollVote {
+        /// The index of the poll.
+        uint32 pollIndex;
+        /// The amount voting aye.
+        uint256 aye;
+        /// The amount voting nay.
+        uint256 nay;
+        /// The amount abstaining.
+        uint256 abstain;
+        /// The conviction of the vote, from 0 to 6.
+        uint8 conviction;
+    }
+
+    /// @dev Delegation of the votes of an account on a track.
+    struct Delegation {
+        /// The account votes are delegated to, or the zero address if there is none.
+        address target;
+        /// The amount delegated.
+        uint256 balance;
+        /// The conviction of the delegation, from 0 to 6.
+        uint8 conviction;
+    }
+
+    /// @dev Vote aye on a poll.
+    /// @param pollIndex The index of the poll.
+    /// @param voteAmount The amount voting aye.
+    /// @param conviction The conviction of the vote, from 0 to 6.
+    /// @custom:selector da9df518
+    function voteYes(
+        uint32 pollIndex,
+        uint256 voteAmount,
+        uint8 conviction
+    ) external;
+
+    /// @dev Vote nay on a poll.
+    /// @param pollIndex The index of the poll.
+    /// @param voteAmount The amount voting nay.
+    /// @param conviction The conviction of the vote, from 0 to 6.
+    /// @custom:selector cc600eba
+    function voteNo(
+        uint32 pollIndex,
+        uint256 voteAmount,
+        uint8 conviction
+    ) external;
+
+    /// @dev Split a vote on a poll between aye and nay, without conviction.
+    /// @param pollIndex The index of the poll.
+    /// @param aye The amount voting aye.
+    /// @param nay The amount voting nay.
+    /// @custom:selector dd6c52a4
+    function voteSplit(
+        uint32 pollIndex,
+        uint256 aye,
+        uint256 nay
+    ) external;
+
+    /// @dev Split a vote on a poll between aye, nay and abstain, without conviction.
+    /// @param pollIndex The index of the poll.
+    /// @param aye The amount voting aye.
+    /// @param nay The amount voting nay.
+    /// @param abstain The amount abstaining.
+    /// @custom:selector 52004540
+    function voteSplitAbstain(
+        uint32 pollIndex,
+        uint256 aye,
+        uint256 nay,
+        uint256 abstain
+    ) external;
+
+    /// @dev Remove the caller's vote on an ongoing poll.
+    /// @param pollIndex The index of the poll.
+    /// @custom:selector 79cae220
+    function removeVote(uint32 pollIndex) external;
+
+    /// @dev Remove the caller's vote on a poll of a track, which may be over.
+    /// @param pollIndex The index of the poll.
+    /// @param trackId The track of the poll.
+    /// @custom:selector cc3aee1a
+    function removeVoteForTrack(uint32 pollIndex, uint16 trackId) external;
+
+    /// @dev Delegate the caller's votes on a track.
+    /// @param trackId The track to delegate votes on.
+    /// @param representative The account votes are delegated to.
+    /// @param conviction The conviction of the delegation, from 0 to 6.
+    /// @param amount The amount delegated.
+    /// @custom:selector 681750e8
+    function delegate(
+        uint16 trackId,
+        address representative,
+        uint8 conviction,
+        uint256 amount
+    ) external;
+
+    /// @dev Undelegate the caller's votes on a track.
+    /// @param trackId The track to undelegate votes on.
+    /// @custom:selector 98be4094
+    function undelegate(uint16 trackId) external;
+
+    /// @dev Remove the expired locks of an account on a track.
+    /// @param trackId The track to unlock.
+    /// @param target The account to unlock.
+    /// @custom:selector 4259d98c
+    function unlock(uint16 trackId, address target) external;
+
+    /// @dev The locks held on the balance of an account for the tracks it voted on.
+    /// @param who The account to query.
+    /// @custom:selector 7ae8ac92
+    function classLocksFor(address who)
+        external
+        view
+        returns (ClassLock[] memory);
+
+    /// @dev The votes of an account on the polls of a track.
+    /// @param who The account to query.
+    /// @param trackId The track to query.
+    /// @custom:selector 221a4c9a
+    function votesOf(address who, uint16 trackId)
+        external
+        view
+        returns (PollVote[] memory);
+
+    /// @dev The delegation of the votes of an account on a track.
+    /// @param who The account to query.
+    /// @param trackId The track to query.
+    /// @custom:selector 9a5c4d49
+    function delegationOf(address who, uint16 trackId)
+        external
+        view
+        returns (Delegation memory);
+
+    /// @dev A standard vote has been cast.
+    /// @custom:selector 3839f7832b2a6263aa1fd5040f37d10fd4f9e9c4a9ef07ec384cb1cef9fb4c0e
+    /// @param pollIndex The index of the poll.
+    /// @param voter The account voting.
+    /// @param aye Whether the vote is aye.
+    /// @param voteAmount The amount voting.
+    /// @param conviction The conviction of the vote.
+    event Voted(
+        uint32 indexed pollIndex,
+        address voter,
+        bool aye,
+        uint256 voteAmount,
+        uint8 conviction
+    );
+
+    /// @dev A split vote has been cast.
+    /// @custom:selector 022787093a8aa26fe59d28969068711f73e0e78ae67d9359c71058b6a21f7ef0
+    /// @param pollIndex The index of the poll.
+    /// @param voter The account voting.
+    /// @param aye The amount voting aye.
+    /// @param nay The amount voting nay.
+    event VoteSplit(
+        uint32 indexed pollIndex,
+        address voter,
+        uint256 aye,
+        uint256 nay
+    );
+
+    /// @dev A split vote with abstentions has been cast.
+    /// @custom:selector 476e687ab5e38fc714552f3acc083d7d83ccaa12ea11dd5f3393478d158c6fd4
+    /// @param pollIndex The index of the poll.
+    /// @param voter The account voting.
+    /// @param aye The amount voting aye.
+    /// @param nay The amount voting nay.
+    /// @param abstain The amount abstaining.
+    event VoteSplitAbstained(
+        uint32 indexed pollIndex,
+        address voter,
+        uint256 aye,
+        uint256 nay,
+        uint256 abstain
+    );
+
+    /// @dev A vote has been removed.
+    /// @custom:selector 49fc1dd929f126e1d88cbb9c135625e30c2deba291adeea4740e446098b9957b
+    /// @param pollIndex The index of the poll.
+    /// @param voter The account whose vote has been removed.
+    event VoteRemoved(uint32 indexed pollIndex, address voter);
+
+    /// @dev Votes on a track have been delegated.
+    /// @custom:selector 6cc151d547592e227b1e85a264ac3699c6f1014112b08bb3832de1f23b9c66db
+    /// @param trackId The track of the delegation.
+    /// @param from The account delegating.
+    /// @param to The account votes are delegated to.
+    /// @param delegatedAmount The amount delegated.
+    /// @param conviction The conviction of the delegation.
+    event Delegated(
+        uint16 indexed trackId,
+        address from,
+        address to,
+        uint256 delegatedAmount,
+        uint8 conviction
+    );
+
+    /// @dev Votes on a track have been undelegated.
+    /// @custom:selector 1053303328f6db14014ccced6297bcad2b3897157ce46070711ab995a05dfa14
+    /// @param trackId The track of the delegation.
+    /// @param caller The account undelegating.
+    event Undelegated(uint16 indexed trackId, address caller);
+
+    /// @dev The expired locks of an account on a track have been removed.
+    /// @custom:selector dcf72fa65ca7fb720b9ccc8ee28e0188edc3d943115124cdd4086c49f836a128
+    /// @param trackId The track unlocked.
+    /// @param target The account unlocked.
+    event Unlocked(uint16 indexed trackId, address target);
+}
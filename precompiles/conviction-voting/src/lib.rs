@@ -0,0 +1,477 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Log, PrecompileHandle};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{Currency, Get, Polling},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_conviction_voting::{AccountVote, Conviction, TallyOf, Vote, Voting};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_conviction_voting::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+type IndexOf<Runtime> =
+	<<Runtime as pallet_conviction_voting::Config>::Polls as Polling<TallyOf<Runtime>>>::Index;
+type ClassOf<Runtime> =
+	<<Runtime as pallet_conviction_voting::Config>::Polls as Polling<TallyOf<Runtime>>>::Class;
+type VotingOf<Runtime> = Voting<
+	BalanceOf<Runtime>,
+	<Runtime as frame_system::Config>::AccountId,
+	BlockNumberFor<Runtime>,
+	IndexOf<Runtime>,
+	<Runtime as pallet_conviction_voting::Config>::MaxVotes,
+>;
+
+/// Solidity selector of the Voted log.
+pub const SELECTOR_LOG_VOTED: [u8; 32] = keccak256!("Voted(uint32,address,bool,uint256,uint8)");
+
+/// Solidity selector of the VoteSplit log.
+pub const SELECTOR_LOG_VOTE_SPLIT: [u8; 32] =
+	keccak256!("VoteSplit(uint32,address,uint256,uint256)");
+
+/// Solidity selector of the VoteSplitAbstained log.
+pub const SELECTOR_LOG_VOTE_SPLIT_ABSTAINED: [u8; 32] =
+	keccak256!("VoteSplitAbstained(uint32,address,uint256,uint256,uint256)");
+
+/// Solidity selector of the VoteRemoved log.
+pub const SELECTOR_LOG_VOTE_REMOVED: [u8; 32] = keccak256!("VoteRemoved(uint32,address)");
+
+/// Solidity selector of the Delegated log.
+pub const SELECTOR_LOG_DELEGATED: [u8; 32] =
+	keccak256!("Delegated(uint16,address,address,uint256,uint8)");
+
+/// Solidity selector of the Undelegated log.
+pub const SELECTOR_LOG_UNDELEGATED: [u8; 32] = keccak256!("Undelegated(uint16,address)");
+
+/// Solidity selector of the Unlocked log.
+pub const SELECTOR_LOG_UNLOCKED: [u8; 32] = keccak256!("Unlocked(uint16,address)");
+
+/// Lock held on the balance of an account for one of the tracks it voted on.
+#[derive(Default, solidity::Codec)]
+pub struct ClassLock {
+	pub track_id: u16,
+	pub amount: U256,
+}
+
+/// Vote of an account on a poll. Only standard votes have a conviction.
+#[derive(Default, solidity::Codec)]
+pub struct PollVote {
+	pub poll_index: u32,
+	pub aye: U256,
+	pub nay: U256,
+	pub abstain: U256,
+	pub conviction: u8,
+}
+
+/// Delegation of the votes of an account on a track, with a zero target when there is none.
+#[derive(Default, solidity::Codec)]
+pub struct Delegation {
+	pub target: Address,
+	pub balance: U256,
+	pub conviction: u8,
+}
+
+/// Conviction voting precompile.
+#[derive(Debug, Clone)]
+pub struct ConvictionVotingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> ConvictionVotingPrecompile<Runtime>
+where
+	Runtime: pallet_conviction_voting::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_conviction_voting::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+	IndexOf<Runtime>: TryFrom<u32> + Into<u32>,
+	ClassOf<Runtime>: TryFrom<u16> + Into<u16>,
+{
+	#[precompile::public("voteYes(uint32,uint256,uint8)")]
+	fn vote_yes(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		vote_amount: U256,
+		conviction: u8,
+	) -> EvmResult {
+		Self::standard_vote(handle, poll_index, true, vote_amount, conviction)
+	}
+
+	#[precompile::public("voteNo(uint32,uint256,uint8)")]
+	fn vote_no(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		vote_amount: U256,
+		conviction: u8,
+	) -> EvmResult {
+		Self::standard_vote(handle, poll_index, false, vote_amount, conviction)
+	}
+
+	#[precompile::public("voteSplit(uint32,uint256,uint256)")]
+	fn vote_split(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		aye: U256,
+		nay: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32 * 3)?;
+
+		let vote = AccountVote::Split {
+			aye: Self::u256_to_amount(aye).in_field("aye")?,
+			nay: Self::u256_to_amount(nay).in_field("nay")?,
+		};
+		Self::dispatch_vote(handle, poll_index, vote)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_VOTE_SPLIT,
+			H256::from_low_u64_be(poll_index.into()),
+			solidity::encode_event_data((Address(handle.context().caller), aye, nay)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("voteSplitAbstain(uint32,uint256,uint256,uint256)")]
+	fn vote_split_abstain(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		aye: U256,
+		nay: U256,
+		abstain: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32 * 4)?;
+
+		let vote = AccountVote::SplitAbstain {
+			aye: Self::u256_to_amount(aye).in_field("aye")?,
+			nay: Self::u256_to_amount(nay).in_field("nay")?,
+			abstain: Self::u256_to_amount(abstain).in_field("abstain")?,
+		};
+		Self::dispatch_vote(handle, poll_index, vote)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_VOTE_SPLIT_ABSTAINED,
+			H256::from_low_u64_be(poll_index.into()),
+			solidity::encode_event_data((Address(handle.context().caller), aye, nay, abstain)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("removeVote(uint32)")]
+	fn remove_vote(handle: &mut impl PrecompileHandle, poll_index: u32) -> EvmResult {
+		Self::dispatch_remove_vote(handle, poll_index, None)
+	}
+
+	#[precompile::public("removeVoteForTrack(uint32,uint16)")]
+	fn remove_vote_for_track(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		track_id: u16,
+	) -> EvmResult {
+		let class = Self::u16_to_class(track_id).in_field("trackId")?;
+		Self::dispatch_remove_vote(handle, poll_index, Some(class))
+	}
+
+	#[precompile::public("delegate(uint16,address,uint8,uint256)")]
+	fn delegate(
+		handle: &mut impl PrecompileHandle,
+		track_id: u16,
+		representative: Address,
+		conviction: u8,
+		amount: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32 * 4)?;
+
+		let call = pallet_conviction_voting::Call::<Runtime>::delegate {
+			class: Self::u16_to_class(track_id).in_field("trackId")?,
+			to: Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(
+				representative.into(),
+			)),
+			conviction: Self::u8_to_conviction(conviction).in_field("conviction")?,
+			balance: Self::u256_to_amount(amount).in_field("amount")?,
+		};
+		Self::dispatch(handle, call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_DELEGATED,
+			H256::from_low_u64_be(track_id.into()),
+			solidity::encode_event_data((
+				Address(handle.context().caller),
+				representative,
+				amount,
+				conviction,
+			)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("undelegate(uint16)")]
+	fn undelegate(handle: &mut impl PrecompileHandle, track_id: u16) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let class = Self::u16_to_class(track_id).in_field("trackId")?;
+		Self::dispatch(handle, pallet_conviction_voting::Call::<Runtime>::undelegate { class })?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_UNDELEGATED,
+			H256::from_low_u64_be(track_id.into()),
+			solidity::encode_event_data(Address(handle.context().caller)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unlock(uint16,address)")]
+	fn unlock(handle: &mut impl PrecompileHandle, track_id: u16, target: Address) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let call = pallet_conviction_voting::Call::<Runtime>::unlock {
+			class: Self::u16_to_class(track_id).in_field("trackId")?,
+			target: Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(
+				target.into(),
+			)),
+		};
+		Self::dispatch(handle, call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_UNLOCKED,
+			H256::from_low_u64_be(track_id.into()),
+			solidity::encode_event_data(target),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("classLocksFor(address)")]
+	#[precompile::view]
+	fn class_locks_for(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+	) -> EvmResult<Vec<ClassLock>> {
+		// Storage item: ClassLocksFor:
+		// Twox64(8) + AccountId(20) + BoundedVec(4 + MaxVotes * (Class + Balance))
+		let max_votes = <Runtime as pallet_conviction_voting::Config>::MaxVotes::get() as usize;
+		let lock_len =
+			ClassOf::<Runtime>::max_encoded_len() + BalanceOf::<Runtime>::max_encoded_len();
+		handle.record_db_read::<Runtime>(32 + max_votes * lock_len)?;
+
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+		Ok(pallet_conviction_voting::ClassLocksFor::<Runtime>::get(who)
+			.into_iter()
+			.map(|(class, amount)| ClassLock { track_id: class.into(), amount: amount.into() })
+			.collect())
+	}
+
+	#[precompile::public("votesOf(address,uint16)")]
+	#[precompile::view]
+	fn votes_of(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+		track_id: u16,
+	) -> EvmResult<Vec<PollVote>> {
+		let class = Self::u16_to_class(track_id).in_field("trackId")?;
+		let voting = Self::voting_for(handle, who, class)?;
+
+		let Voting::Casting(casting) = voting else { return Ok(Vec::new()) };
+		Ok(casting
+			.votes
+			.into_iter()
+			.map(|(poll_index, vote)| {
+				let mut poll_vote =
+					PollVote { poll_index: poll_index.into(), ..Default::default() };
+				match vote {
+					AccountVote::Standard { vote, balance } => {
+						if vote.aye {
+							poll_vote.aye = balance.into();
+						} else {
+							poll_vote.nay = balance.into();
+						}
+						poll_vote.conviction = vote.conviction.into();
+					},
+					AccountVote::Split { aye, nay } => {
+						poll_vote.aye = aye.into();
+						poll_vote.nay = nay.into();
+					},
+					AccountVote::SplitAbstain { aye, nay, abstain } => {
+						poll_vote.aye = aye.into();
+						poll_vote.nay = nay.into();
+						poll_vote.abstain = abstain.into();
+					},
+				}
+				poll_vote
+			})
+			.collect())
+	}
+
+	#[precompile::public("delegationOf(address,uint16)")]
+	#[precompile::view]
+	fn delegation_of(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+		track_id: u16,
+	) -> EvmResult<Delegation> {
+		let class = Self::u16_to_class(track_id).in_field("trackId")?;
+		let voting = Self::voting_for(handle, who, class)?;
+
+		let Voting::Delegating(delegating) = voting else { return Ok(Delegation::default()) };
+		Ok(Delegation {
+			target: Address(delegating.target.into()),
+			balance: delegating.balance.into(),
+			conviction: delegating.conviction.into(),
+		})
+	}
+
+	fn standard_vote(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		aye: bool,
+		vote_amount: U256,
+		conviction: u8,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32 * 4)?;
+
+		let vote = AccountVote::Standard {
+			vote: Vote {
+				aye,
+				conviction: Self::u8_to_conviction(conviction).in_field("conviction")?,
+			},
+			balance: Self::u256_to_amount(vote_amount).in_field("voteAmount")?,
+		};
+		Self::dispatch_vote(handle, poll_index, vote)?;
+
+		log_voted(
+			handle.context().address,
+			poll_index,
+			handle.context().caller,
+			aye,
+			vote_amount,
+			conviction,
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	fn dispatch_vote(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		vote: AccountVote<BalanceOf<Runtime>>,
+	) -> EvmResult {
+		let poll_index = Self::u32_to_index(poll_index).in_field("pollIndex")?;
+		Self::dispatch(handle, pallet_conviction_voting::Call::<Runtime>::vote { poll_index, vote })
+	}
+
+	fn dispatch_remove_vote(
+		handle: &mut impl PrecompileHandle,
+		poll_index: u32,
+		class: Option<ClassOf<Runtime>>,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let index = Self::u32_to_index(poll_index).in_field("pollIndex")?;
+		Self::dispatch(
+			handle,
+			pallet_conviction_voting::Call::<Runtime>::remove_vote { class, index },
+		)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_VOTE_REMOVED,
+			H256::from_low_u64_be(poll_index.into()),
+			solidity::encode_event_data(Address(handle.context().caller)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		call: pallet_conviction_voting::Call<Runtime>,
+	) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	fn voting_for(
+		handle: &mut impl PrecompileHandle,
+		who: Address,
+		class: ClassOf<Runtime>,
+	) -> EvmResult<VotingOf<Runtime>> {
+		// Storage item: VotingFor:
+		// Twox64(8) + AccountId(20) + Twox64(8) + Class + Voting
+		handle.record_db_read::<Runtime>(
+			36 + ClassOf::<Runtime>::max_encoded_len() + VotingOf::<Runtime>::max_encoded_len(),
+		)?;
+
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+		Ok(pallet_conviction_voting::VotingFor::<Runtime>::get(who, class))
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+
+	fn u32_to_index(index: u32) -> MayRevert<IndexOf<Runtime>> {
+		index
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("poll index type").into())
+	}
+
+	fn u16_to_class(track_id: u16) -> MayRevert<ClassOf<Runtime>> {
+		track_id
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("track id type").into())
+	}
+
+	fn u8_to_conviction(conviction: u8) -> MayRevert<Conviction> {
+		conviction
+			.try_into()
+			.map_err(|_| RevertReason::custom("Must be an integer between 0 and 6 included").into())
+	}
+}
+
+/// Voted log of a standard vote of `voter` on `poll_index`.
+pub fn log_voted(
+	address: impl Into<H160>,
+	poll_index: u32,
+	voter: H160,
+	aye: bool,
+	vote_amount: U256,
+	conviction: u8,
+) -> Log {
+	log2(
+		address,
+		SELECTOR_LOG_VOTED,
+		H256::from_low_u64_be(poll_index.into()),
+		solidity::encode_event_data((Address(voter), aye, vote_amount, conviction)),
+	)
+}
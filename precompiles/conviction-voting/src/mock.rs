@@ -0,0 +1,298 @@
+use super::*;
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything, PollStatus, TotalIssuanceOf},
+	weights::Weight,
+};
+use pallet_conviction_voting::Tally;
+use pallet_evm::{
+	EnsureAddressNever, EnsureAddressRoot, IdentityAddressMapping, IsPrecompileResult, Precompile,
+	PrecompileResult, PrecompileSet,
+};
+use precompile_utils::{mock_account, testing::MockAccount};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, Perbill,
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Evm: pallet_evm,
+		Timestamp: pallet_timestamp,
+		ConvictionVoting: pallet_conviction_voting,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+}
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ConstU32<50>;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ();
+}
+
+/// State of a poll of [`TestPolls`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestPollState {
+	Ongoing(TallyOf<Runtime>, u16),
+	Completed(BlockNumber, bool),
+}
+
+parameter_types! {
+	// Poll 0 is ongoing on track 0, poll 1 is ongoing on track 1 and poll 2 is over.
+	pub static Polls: BTreeMap<u32, TestPollState> = vec![
+		(0, TestPollState::Ongoing(Tally::from_parts(0, 0, 0), 0)),
+		(1, TestPollState::Ongoing(Tally::from_parts(0, 0, 0), 1)),
+		(2, TestPollState::Completed(1, true)),
+	]
+	.into_iter()
+	.collect();
+}
+
+/// Polls kept in memory, standing in for the referenda.
+pub struct TestPolls;
+
+impl Polling<TallyOf<Runtime>> for TestPolls {
+	type Index = u32;
+	type Votes = Balance;
+	type Class = u16;
+	type Moment = BlockNumber;
+
+	fn classes() -> Vec<u16> {
+		vec![0, 1]
+	}
+
+	fn as_ongoing(index: u32) -> Option<(TallyOf<Runtime>, u16)> {
+		match Polls::get().remove(&index) {
+			Some(TestPollState::Ongoing(tally, class)) => Some((tally, class)),
+			_ => None,
+		}
+	}
+
+	fn access_poll<R>(
+		index: u32,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Runtime>, BlockNumber, u16>) -> R,
+	) -> R {
+		let mut polls = Polls::get();
+		let result = match polls.get_mut(&index) {
+			Some(TestPollState::Ongoing(ref mut tally, class)) =>
+				f(PollStatus::Ongoing(tally, *class)),
+			Some(TestPollState::Completed(when, approved)) =>
+				f(PollStatus::Completed(*when, *approved)),
+			None => f(PollStatus::None),
+		};
+		Polls::set(polls);
+		result
+	}
+
+	fn try_access_poll<R>(
+		index: u32,
+		f: impl FnOnce(
+			PollStatus<&mut TallyOf<Runtime>, BlockNumber, u16>,
+		) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		let mut polls = Polls::get();
+		let result = match polls.get_mut(&index) {
+			Some(TestPollState::Ongoing(ref mut tally, class)) =>
+				f(PollStatus::Ongoing(tally, *class)),
+			Some(TestPollState::Completed(when, approved)) =>
+				f(PollStatus::Completed(*when, *approved)),
+			None => f(PollStatus::None),
+		}?;
+		Polls::set(polls);
+		Ok(result)
+	}
+}
+
+parameter_types! {
+	pub const VoteLockingPeriod: BlockNumber = 10;
+}
+
+impl pallet_conviction_voting::Config for Runtime {
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxVotes = ConstU32<3>;
+	type MaxTurnout = TotalIssuanceOf<Balances, AccountId>;
+	type Polls = TestPolls;
+}
+
+pub struct MockPrecompileSet<Runtime>(PhantomData<Runtime>);
+
+impl<R> PrecompileSet for MockPrecompileSet<R>
+where
+	ConvictionVotingPrecompile<R>: Precompile,
+{
+	/// Tries to execute a precompile in the precompile set.
+	/// If the provided address is not a precompile, returns None.
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		let address = handle.code_address();
+
+		if address == Precompile1.into() {
+			return Some(ConvictionVotingPrecompile::<R>::execute(handle))
+		}
+
+		None
+	}
+
+	/// Check if the given address is a precompile. Should only be called to
+	/// perform the check while not executing the precompile afterward, since
+	/// `execute` already performs a check internally.
+	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer { is_precompile: address == Precompile1.into(), extra_cost: 0 }
+	}
+}
+
+pub type PCall = ConvictionVotingPrecompileCall<Runtime>;
+
+mock_account!(Precompile1, |_| MockAccount::from_u64(1));
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u64::MAX);
+	pub PrecompilesValue: MockPrecompileSet<Runtime> = MockPrecompileSet(PhantomData);
+
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+	pub GasLimitPovSizeRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(MAX_POV_SIZE)
+	};
+	pub GasLimitStorageGrowthRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+	};
+}
+
+impl pallet_evm::Config for Runtime {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = IdentityAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = MockPrecompileSet<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type OnChargeTransaction = ();
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type Author = ();
+	type OnCreate = ();
+	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+mock_account!(Alice, |_| H160::repeat_byte(0xAA).into());
+mock_account!(Bob, |_| H160::repeat_byte(0xBB).into());
+
+pub(crate) struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> ExtBuilder {
+		ExtBuilder { balances: vec![] }
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Runtime> { balances: self.balances }
+			.assimilate_storage(&mut t)
+			.expect("Pallet balances storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Tally of the ongoing poll `index`.
+pub(crate) fn tally(index: u32) -> TallyOf<Runtime> {
+	match Polls::get().remove(&index) {
+		Some(TestPollState::Ongoing(tally, _)) => tally,
+		_ => panic!("poll {index} is not ongoing"),
+	}
+}
@@ -0,0 +1,375 @@
+use crate::{
+	log_voted,
+	mock::{
+		tally, Alice, Bob, ExtBuilder, MockPrecompileSet, PCall, Precompile1, PrecompilesValue,
+		Runtime,
+	},
+	ClassLock, Delegation, PollVote, SELECTOR_LOG_DELEGATED, SELECTOR_LOG_UNDELEGATED,
+	SELECTOR_LOG_UNLOCKED, SELECTOR_LOG_VOTE_REMOVED, SELECTOR_LOG_VOTE_SPLIT,
+};
+use pallet_conviction_voting::Tally;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256, U256};
+
+fn precompiles() -> MockPrecompileSet<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.build()
+}
+
+fn vote_yes(poll_index: u32, amount: u32, conviction: u8) {
+	precompiles()
+		.prepare_test(
+			Alice,
+			Precompile1,
+			PCall::vote_yes { poll_index, vote_amount: amount.into(), conviction },
+		)
+		.execute_returns(());
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::vote_yes_selectors().contains(&0xda9df518));
+	assert!(PCall::vote_no_selectors().contains(&0xcc600eba));
+	assert!(PCall::vote_split_selectors().contains(&0xdd6c52a4));
+	assert!(PCall::vote_split_abstain_selectors().contains(&0x52004540));
+	assert!(PCall::remove_vote_selectors().contains(&0x79cae220));
+	assert!(PCall::remove_vote_for_track_selectors().contains(&0xcc3aee1a));
+	assert!(PCall::delegate_selectors().contains(&0x681750e8));
+	assert!(PCall::undelegate_selectors().contains(&0x98be4094));
+	assert!(PCall::unlock_selectors().contains(&0x4259d98c));
+	assert!(PCall::class_locks_for_selectors().contains(&0x7ae8ac92));
+	assert!(PCall::votes_of_selectors().contains(&0x221a4c9a));
+	assert!(PCall::delegation_of_selectors().contains(&0x9a5c4d49));
+}
+
+#[test]
+fn modifiers() {
+	ext().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::vote_yes_selectors());
+		tester.test_default_modifier(PCall::vote_no_selectors());
+		tester.test_default_modifier(PCall::vote_split_selectors());
+		tester.test_default_modifier(PCall::vote_split_abstain_selectors());
+		tester.test_default_modifier(PCall::remove_vote_selectors());
+		tester.test_default_modifier(PCall::remove_vote_for_track_selectors());
+		tester.test_default_modifier(PCall::delegate_selectors());
+		tester.test_default_modifier(PCall::undelegate_selectors());
+		tester.test_default_modifier(PCall::unlock_selectors());
+		tester.test_view_modifier(PCall::class_locks_for_selectors());
+		tester.test_view_modifier(PCall::votes_of_selectors());
+		tester.test_view_modifier(PCall::delegation_of_selectors());
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["src/ConvictionVoting.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn vote_yes_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::vote_yes { poll_index: 0, vote_amount: 100.into(), conviction: 1 },
+			)
+			.expect_log(log_voted(Precompile1, 0, Alice.into(), true, 100.into(), 1))
+			.execute_returns(());
+
+		assert_eq!(tally(0), Tally::from_parts(100, 0, 100));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::votes_of { who: Address(Alice.into()), track_id: 0 },
+			)
+			.execute_returns(vec![PollVote {
+				poll_index: 0,
+				aye: 100.into(),
+				conviction: 1,
+				..Default::default()
+			}]);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::class_locks_for { who: Address(Alice.into()) })
+			.execute_returns(vec![ClassLock { track_id: 0, amount: 100.into() }]);
+	});
+}
+
+#[test]
+fn vote_no_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::vote_no { poll_index: 1, vote_amount: 100.into(), conviction: 2 },
+			)
+			.expect_log(log_voted(Precompile1, 1, Alice.into(), false, 100.into(), 2))
+			.execute_returns(());
+
+		assert_eq!(tally(1), Tally::from_parts(0, 200, 0));
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::votes_of { who: Address(Alice.into()), track_id: 1 },
+			)
+			.execute_returns(vec![PollVote {
+				poll_index: 1,
+				nay: 100.into(),
+				conviction: 2,
+				..Default::default()
+			}]);
+	});
+}
+
+#[test]
+fn vote_reverts_for_invalid_conviction() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::vote_yes { poll_index: 0, vote_amount: 100.into(), conviction: 7 },
+			)
+			.execute_reverts(|output| {
+				output == b"conviction: Must be an integer between 0 and 6 included"
+			});
+	});
+}
+
+#[test]
+fn vote_split_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::vote_split { poll_index: 0, aye: 100.into(), nay: 50.into() },
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_VOTE_SPLIT,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data((
+					Address(Alice.into()),
+					U256::from(100),
+					U256::from(50),
+				)),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::votes_of { who: Address(Alice.into()), track_id: 0 },
+			)
+			.execute_returns(vec![PollVote {
+				poll_index: 0,
+				aye: 100.into(),
+				nay: 50.into(),
+				..Default::default()
+			}]);
+	});
+}
+
+#[test]
+fn vote_split_abstain_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::vote_split_abstain {
+					poll_index: 0,
+					aye: 100.into(),
+					nay: 50.into(),
+					abstain: 25.into(),
+				},
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::votes_of { who: Address(Alice.into()), track_id: 0 },
+			)
+			.execute_returns(vec![PollVote {
+				poll_index: 0,
+				aye: 100.into(),
+				nay: 50.into(),
+				abstain: 25.into(),
+				conviction: 0,
+			}]);
+	});
+}
+
+#[test]
+fn remove_vote_works() {
+	ext().execute_with(|| {
+		vote_yes(0, 100, 1);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::remove_vote { poll_index: 0 })
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_VOTE_REMOVED,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data(Address(Alice.into())),
+			))
+			.execute_returns(());
+
+		assert_eq!(tally(0), Tally::from_parts(0, 0, 0));
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::votes_of { who: Address(Alice.into()), track_id: 0 },
+			)
+			.execute_returns(Vec::<PollVote>::new());
+	});
+}
+
+#[test]
+fn remove_vote_for_track_works() {
+	ext().execute_with(|| {
+		vote_yes(1, 100, 1);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::remove_vote_for_track { poll_index: 1, track_id: 1 },
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::votes_of { who: Address(Alice.into()), track_id: 1 },
+			)
+			.execute_returns(Vec::<PollVote>::new());
+	});
+}
+
+#[test]
+fn delegate_and_undelegate_work() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::delegate {
+					track_id: 0,
+					representative: Address(Bob.into()),
+					conviction: 2,
+					amount: 100.into(),
+				},
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_DELEGATED,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data((
+					Address(Alice.into()),
+					Address(Bob.into()),
+					U256::from(100),
+					2u8,
+				)),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::delegation_of { who: Address(Alice.into()), track_id: 0 },
+			)
+			.execute_returns(Delegation {
+				target: Address(Bob.into()),
+				balance: 100.into(),
+				conviction: 2,
+			});
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::undelegate { track_id: 0 })
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_UNDELEGATED,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data(Address(Alice.into())),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::delegation_of { who: Address(Alice.into()), track_id: 0 },
+			)
+			.execute_returns(Delegation {
+				target: Address(H160::zero()),
+				balance: 0.into(),
+				conviction: 0,
+			});
+	});
+}
+
+#[test]
+fn unlock_removes_expired_locks() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::delegate {
+					track_id: 0,
+					representative: Address(Bob.into()),
+					conviction: 0,
+					amount: 100.into(),
+				},
+			)
+			.execute_returns(());
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::undelegate { track_id: 0 })
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::class_locks_for { who: Address(Alice.into()) })
+			.execute_returns(vec![ClassLock { track_id: 0, amount: 100.into() }]);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				Precompile1,
+				PCall::unlock { track_id: 0, target: Address(Alice.into()) },
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_UNLOCKED,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data(Address(Alice.into())),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::class_locks_for { who: Address(Alice.into()) })
+			.execute_returns(Vec::<ClassLock>::new());
+	});
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Democracy contract's address.
+address constant DEMOCRACY_ADDRESS = 0x0000000000000000000000000000000000001008;
+
+/// @dev The Democracy contract's instance.
+Democracy constant DEMOCRACY_CONTRACT = Democracy(DEMOCRACY_ADDRESS);
+
+/// @title Democracy precompile
+/// @dev Allows accounts and contracts to make, second and vote on public proposals and
+/// referenda.
+/// @custom:address 0x0000000000000000000000000000000000001008
+interface Democracy {
+    /// @dev State of an ongoing referendum.
+    struct OngoingReferendumInfo {
+        /// The block the referendum ends at.
+        uint32 end;
+        /// The hash of the proposal being voted on.
+        bytes32 proposalHash;
+        /// The threshold: 0 for super majority approve, 1 for super majority against and
+        /// 2 for simple majority.
+        uint8 threshold;
+        /// The number of blocks after the end before the proposal is enacted.
+        uint32 delay;
+        /// The amount voting aye, weighted by conviction.
+        uint256 ayes;
+        /// The amount voting nay, weighted by conviction.
+        uint256 nays;
+        /// The amount that voted, without conviction.
+        uint256 turnout;
+    }
+
+    /// @dev Outcome of a finished referendum.
+    struct FinishedReferendumInfo {
+        /// Whether the referendum was approved.
+        bool approved;
+        /// The block the referendum ended at.
+        uint32 end;
+    }
+
+    /// @dev Make a new public proposal.
+    /// @param proposalHash The hash of the noted preimage of the proposal.
+    /// @param value The deposit backing the proposal.
+    /// @custom:selector 7824e7d1
+    function propose(bytes32 proposalHash, uint256 value) external;
+
+    /// @dev Second a public proposal, reserving the same deposit as the proposer.
+    /// @param proposalIndex The index of the proposal.
+    /// @custom:selector f0f77847
+    function second(uint32 proposalIndex) external;
+
+    /// @dev Vote on an ongoing referendum.
+    /// @param refIndex The index of the referendum.
+    /// @param aye Whether the vote is aye.
+    /// @param voteAmount The amount voting.
+    /// @param conviction The conviction of the vote, from 0 to 6.
+    /// @custom:selector 3da493f0
+    function standardVote(
+        uint32 refIndex,
+        bool aye,
+        uint256 voteAmount,
+        uint8 conviction
+    ) external;
+
+    /// @dev Remove the caller's vote on a referendum.
+    /// @param refIndex The index of the referendum.
+    /// @custom:selector 79cae220
+    function removeVote(uint32 refIndex) external;
+
+    /// @dev Delegate the caller's votes.
+    /// @param representative The account votes are delegated to.
+    /// @param conviction The conviction of the delegation, from 0 to 6.
+    /// @param amount The amount delegated.
+    /// @custom:selector 3680a9d3
+    function delegate(
+        address representative,
+        uint8 conviction,
+        uint256 amount
+    ) external;
+
+    /// @dev Undelegate the caller's votes.
+    /// @custom:selector 1eef225c
+    function unDelegate() external;
+
+    /// @dev Remove the expired locks of an account.
+    /// @param target The account to unlock.
+    /// @custom:selector 2f6c493c
+    function unlock(address target) external;
+
+    /// @dev Note the preimage of a proposal.
+    /// @param encodedProposal The SCALE-encoded call of the proposal.
+    /// @return The hash of the preimage.
+    /// @custom:selector cb00f603
+    function notePreimage(bytes memory encodedProposal)
+        external
+        returns (bytes32);
+
+    /// @dev The number of public proposals made so far.
+    /// @custom:selector 31305462
+    function publicPropCount() external view returns (uint32);
+
+    /// @dev The deposit backing a public proposal.
+    /// @param proposalIndex The index of the proposal.
+    /// @custom:selector 0cd8c00a
+    function depositOf(uint32 proposalIndex) external view returns (uint256);
+
+    /// @dev The lowest referendum index that has not been enacted or rejected yet.
+    /// @custom:selector d49dccf0
+    function lowestUnbaked() external view returns (uint32);
+
+    /// @dev The state of an ongoing referendum.
+    /// @param refIndex The index of the referendum.
+    /// @custom:selector f033b7cd
+    function ongoingReferendumInfo(uint32 refIndex)
+        external
+        view
+        returns (OngoingReferendumInfo memory);
+
+    /// @dev The outcome of a finished referendum.
+    /// @param refIndex The index of the referendum.
+    /// @custom:selector c75abcce
+    function finishedReferendumInfo(uint32 refIndex)
+        external
+        view
+        returns (FinishedReferendumInfo memory);
+
+    /// @dev A public proposal has been made.
+    /// @custom:selector d89e173ca5c9fd0ec38f2b01995c4f1748210f686fa189a6b8d189c210444924
+    /// @param proposalIndex The index of the proposal.
+    /// @param deposit The deposit backing the proposal.
+    event Proposed(uint32 indexed proposalIndex, uint256 deposit);
+
+    /// @dev A public proposal has been seconded.
+    /// @custom:selector e1613d7e3f54885ef3ffdb714435193b9b80818bd3381f108a4d4b21e842654a
+    /// @param proposalIndex The index of the proposal.
+    /// @param seconder The account seconding.
+    event Seconded(uint32 indexed proposalIndex, address seconder);
+
+    /// @dev A standard vote has been cast on a referendum.
+    /// @custom:selector 057363260bf880d3658601ecff97e75b67a22f38b7066c0e47e2d170477579c3
+    /// @param refIndex The index of the referendum.
+    /// @param voter The account voting.
+    /// @param aye Whether the vote is aye.
+    /// @param voteAmount The amount voting.
+    /// @param conviction The conviction of the vote.
+    event StandardVote(
+        uint32 indexed refIndex,
+        address voter,
+        bool aye,
+        uint256 voteAmount,
+        uint8 conviction
+    );
+
+    /// @dev Votes have been delegated.
+    /// @custom:selector 4bc154dd35d6a5cb9206482ecb473cdbf2473006d6bce728b9cc0741bcc59ea2
+    /// @param who The account delegating.
+    /// @param target The account votes are delegated to.
+    event Delegated(address indexed who, address target);
+
+    /// @dev Votes have been undelegated.
+    /// @custom:selector 42176493fdfcada70cc1bcf321c9a2314e9571a9fe53c54a5385a1eeac8bc1d7
+    /// @param who The account undelegating.
+    event Undelegated(address indexed who);
+}
@@ -0,0 +1,365 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{Bounded, ConstU32, Currency, Get, QueryPreimage},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_democracy::{
+	AccountVote, Conviction, ReferendumInfo, ReferendumStatus, Tally, Vote, VoteThreshold,
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Maximum size of a preimage noted through the precompile.
+pub const PREIMAGE_LIMIT: u32 = 2u32.pow(16);
+
+type GetPreimageLimit = ConstU32<PREIMAGE_LIMIT>;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_democracy::Config>::Currency as Currency<
+	<Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+type CallOf<Runtime> = <Runtime as frame_system::Config>::RuntimeCall;
+type ReferendumInfoOf<Runtime> =
+	ReferendumInfo<BlockNumberFor<Runtime>, Bounded<CallOf<Runtime>>, BalanceOf<Runtime>>;
+
+/// Solidity selector of the Proposed log.
+pub const SELECTOR_LOG_PROPOSED: [u8; 32] = keccak256!("Proposed(uint32,uint256)");
+
+/// Solidity selector of the Seconded log.
+pub const SELECTOR_LOG_SECONDED: [u8; 32] = keccak256!("Seconded(uint32,address)");
+
+/// Solidity selector of the StandardVote log.
+pub const SELECTOR_LOG_STANDARD_VOTE: [u8; 32] =
+	keccak256!("StandardVote(uint32,address,bool,uint256,uint8)");
+
+/// Solidity selector of the Delegated log.
+pub const SELECTOR_LOG_DELEGATED: [u8; 32] = keccak256!("Delegated(address,address)");
+
+/// Solidity selector of the Undelegated log.
+pub const SELECTOR_LOG_UNDELEGATED: [u8; 32] = keccak256!("Undelegated(address)");
+
+/// State of an ongoing referendum.
+#[derive(Default, solidity::Codec)]
+pub struct OngoingReferendumInfo {
+	pub end: u32,
+	pub proposal_hash: H256,
+	pub threshold: u8,
+	pub delay: u32,
+	pub ayes: U256,
+	pub nays: U256,
+	pub turnout: U256,
+}
+
+/// Outcome of a finished referendum.
+#[derive(Default, solidity::Codec)]
+pub struct FinishedReferendumInfo {
+	pub approved: bool,
+	pub end: u32,
+}
+
+/// Democracy precompile.
+#[derive(Debug, Clone)]
+pub struct DemocracyPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> DemocracyPrecompile<Runtime>
+where
+	Runtime: pallet_democracy::Config + pallet_preimage::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_democracy::Call<Runtime>>
+		+ From<pallet_preimage::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+	BlockNumberFor<Runtime>: Into<u32>,
+{
+	#[precompile::public("propose(bytes32,uint256)")]
+	fn propose(handle: &mut impl PrecompileHandle, proposal_hash: H256, value: U256) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		// Storage item: PublicPropCount: u32(4)
+		handle.record_db_read::<Runtime>(4)?;
+		let proposal_index = pallet_democracy::PublicPropCount::<Runtime>::get();
+
+		// Storage item: Preimage::StatusFor:
+		// Identity(32) + RequestStatus(1 + AccountId + Balance + Option<u32>(5))
+		handle.record_db_read::<Runtime>(
+			38 + Runtime::AccountId::max_encoded_len() + BalanceOf::<Runtime>::max_encoded_len(),
+		)?;
+		let len = <Runtime as pallet_democracy::Config>::Preimages::len(&proposal_hash)
+			.ok_or_else(|| {
+				RevertReason::custom("Failure in preimage fetch").in_field("proposalHash")
+			})?;
+
+		let call = pallet_democracy::Call::<Runtime>::propose {
+			proposal: Bounded::Lookup { hash: proposal_hash, len },
+			value: Self::u256_to_amount(value).in_field("value")?,
+		};
+		Self::dispatch(handle, call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_PROPOSED,
+			H256::from_low_u64_be(proposal_index.into()),
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("second(uint32)")]
+	fn second(handle: &mut impl PrecompileHandle, proposal_index: u32) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		Self::dispatch(
+			handle,
+			pallet_democracy::Call::<Runtime>::second { proposal: proposal_index },
+		)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_SECONDED,
+			H256::from_low_u64_be(proposal_index.into()),
+			solidity::encode_event_data(Address(handle.context().caller)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("standardVote(uint32,bool,uint256,uint8)")]
+	fn standard_vote(
+		handle: &mut impl PrecompileHandle,
+		ref_index: u32,
+		aye: bool,
+		vote_amount: U256,
+		conviction: u8,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32 * 4)?;
+
+		let vote = AccountVote::Standard {
+			vote: Vote {
+				aye,
+				conviction: Self::u8_to_conviction(conviction).in_field("conviction")?,
+			},
+			balance: Self::u256_to_amount(vote_amount).in_field("voteAmount")?,
+		};
+		Self::dispatch(handle, pallet_democracy::Call::<Runtime>::vote { ref_index, vote })?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_STANDARD_VOTE,
+			H256::from_low_u64_be(ref_index.into()),
+			solidity::encode_event_data((
+				Address(handle.context().caller),
+				aye,
+				vote_amount,
+				conviction,
+			)),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("removeVote(uint32)")]
+	fn remove_vote(handle: &mut impl PrecompileHandle, ref_index: u32) -> EvmResult {
+		Self::dispatch(handle, pallet_democracy::Call::<Runtime>::remove_vote { index: ref_index })
+	}
+
+	#[precompile::public("delegate(address,uint8,uint256)")]
+	fn delegate(
+		handle: &mut impl PrecompileHandle,
+		representative: Address,
+		conviction: u8,
+		amount: U256,
+	) -> EvmResult {
+		handle.record_log_costs_manual(2, 32)?;
+
+		let call = pallet_democracy::Call::<Runtime>::delegate {
+			to: Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(
+				representative.into(),
+			)),
+			conviction: Self::u8_to_conviction(conviction).in_field("conviction")?,
+			balance: Self::u256_to_amount(amount).in_field("amount")?,
+		};
+		Self::dispatch(handle, call)?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_DELEGATED,
+			handle.context().caller,
+			solidity::encode_event_data(representative),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unDelegate()")]
+	fn un_delegate(handle: &mut impl PrecompileHandle) -> EvmResult {
+		handle.record_log_costs_manual(2, 0)?;
+
+		Self::dispatch(handle, pallet_democracy::Call::<Runtime>::undelegate {})?;
+
+		log2(
+			handle.context().address,
+			SELECTOR_LOG_UNDELEGATED,
+			handle.context().caller,
+			Vec::new(),
+		)
+		.record(handle)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unlock(address)")]
+	fn unlock(handle: &mut impl PrecompileHandle, target: Address) -> EvmResult {
+		let call = pallet_democracy::Call::<Runtime>::unlock {
+			target: Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(
+				target.into(),
+			)),
+		};
+		Self::dispatch(handle, call)
+	}
+
+	#[precompile::public("notePreimage(bytes)")]
+	fn note_preimage(
+		handle: &mut impl PrecompileHandle,
+		encoded_proposal: BoundedBytes<GetPreimageLimit>,
+	) -> EvmResult<H256> {
+		let bytes: Vec<u8> = encoded_proposal.into();
+		let hash = sp_io::hashing::blake2_256(&bytes).into();
+
+		Self::dispatch(handle, pallet_preimage::Call::<Runtime>::note_preimage { bytes })?;
+
+		Ok(hash)
+	}
+
+	#[precompile::public("publicPropCount()")]
+	#[precompile::view]
+	fn public_prop_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// Storage item: PublicPropCount: u32(4)
+		handle.record_db_read::<Runtime>(4)?;
+
+		Ok(pallet_democracy::PublicPropCount::<Runtime>::get())
+	}
+
+	#[precompile::public("depositOf(uint32)")]
+	#[precompile::view]
+	fn deposit_of(handle: &mut impl PrecompileHandle, proposal_index: u32) -> EvmResult<U256> {
+		// Storage item: DepositOf:
+		// Twox64(8) + u32(4) + BoundedVec(4 + MaxDeposits * AccountId) + Balance
+		let max_deposits = <Runtime as pallet_democracy::Config>::MaxDeposits::get() as usize;
+		handle.record_db_read::<Runtime>(
+			16 + max_deposits * Runtime::AccountId::max_encoded_len() +
+				BalanceOf::<Runtime>::max_encoded_len(),
+		)?;
+
+		let (_, deposit) = pallet_democracy::DepositOf::<Runtime>::get(proposal_index)
+			.ok_or_else(|| RevertReason::custom("No such proposal").in_field("proposalIndex"))?;
+
+		Ok(deposit.into())
+	}
+
+	#[precompile::public("lowestUnbaked()")]
+	#[precompile::view]
+	fn lowest_unbaked(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		// Storage item: LowestUnbaked: u32(4)
+		handle.record_db_read::<Runtime>(4)?;
+
+		Ok(pallet_democracy::LowestUnbaked::<Runtime>::get())
+	}
+
+	#[precompile::public("ongoingReferendumInfo(uint32)")]
+	#[precompile::view]
+	fn ongoing_referendum_info(
+		handle: &mut impl PrecompileHandle,
+		ref_index: u32,
+	) -> EvmResult<OngoingReferendumInfo> {
+		let ReferendumInfo::Ongoing(ReferendumStatus { end, proposal, threshold, delay, tally }) =
+			Self::referendum_info(handle, ref_index)?
+		else {
+			return Err(
+				RevertReason::custom("Referendum is not ongoing").in_field("refIndex").into()
+			)
+		};
+		let Tally { ayes, nays, turnout } = tally;
+
+		Ok(OngoingReferendumInfo {
+			end: end.into(),
+			proposal_hash: proposal.hash(),
+			threshold: match threshold {
+				VoteThreshold::SuperMajorityApprove => 0,
+				VoteThreshold::SuperMajorityAgainst => 1,
+				VoteThreshold::SimpleMajority => 2,
+			},
+			delay: delay.into(),
+			ayes: ayes.into(),
+			nays: nays.into(),
+			turnout: turnout.into(),
+		})
+	}
+
+	#[precompile::public("finishedReferendumInfo(uint32)")]
+	#[precompile::view]
+	fn finished_referendum_info(
+		handle: &mut impl PrecompileHandle,
+		ref_index: u32,
+	) -> EvmResult<FinishedReferendumInfo> {
+		let ReferendumInfo::Finished { approved, end } = Self::referendum_info(handle, ref_index)?
+		else {
+			return Err(
+				RevertReason::custom("Referendum is not finished").in_field("refIndex").into()
+			)
+		};
+
+		Ok(FinishedReferendumInfo { approved, end: end.into() })
+	}
+
+	fn referendum_info(
+		handle: &mut impl PrecompileHandle,
+		ref_index: u32,
+	) -> EvmResult<ReferendumInfoOf<Runtime>> {
+		// Storage item: ReferendumInfoOf: Twox64(8) + u32(4) + ReferendumInfo
+		handle.record_db_read::<Runtime>(12 + ReferendumInfoOf::<Runtime>::max_encoded_len())?;
+
+		pallet_democracy::ReferendumInfoOf::<Runtime>::get(ref_index)
+			.ok_or_else(|| RevertReason::custom("Unknown referendum").in_field("refIndex").into())
+	}
+
+	fn dispatch<Call>(handle: &mut impl PrecompileHandle, call: Call) -> EvmResult
+	where
+		Runtime::RuntimeCall: From<Call>,
+	{
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+
+	fn u8_to_conviction(conviction: u8) -> MayRevert<Conviction> {
+		conviction
+			.try_into()
+			.map_err(|_| RevertReason::custom("Must be an integer between 0 and 6 included").into())
+	}
+}
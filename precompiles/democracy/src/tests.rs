@@ -0,0 +1,322 @@
+use crate::{
+	mock::{
+		AccountId, Alice, Bob, Democracy, ExtBuilder, MockPrecompileSet, PCall, Precompile1,
+		PrecompilesValue, Preimage, Runtime, RuntimeCall,
+	},
+	FinishedReferendumInfo, OngoingReferendumInfo, SELECTOR_LOG_DELEGATED, SELECTOR_LOG_PROPOSED,
+	SELECTOR_LOG_SECONDED, SELECTOR_LOG_STANDARD_VOTE, SELECTOR_LOG_UNDELEGATED,
+};
+use frame_support::traits::StorePreimage;
+use pallet_democracy::{ReferendumInfo, VoteThreshold, Voting};
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn precompiles() -> MockPrecompileSet<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.build()
+}
+
+/// A proposal any origin can dispatch.
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: b"remark".to_vec() })
+}
+
+fn remark_hash() -> H256 {
+	BlakeTwo256::hash_of(&remark())
+}
+
+fn note_and_propose() {
+	precompiles()
+		.prepare_test(
+			Alice,
+			Precompile1,
+			PCall::note_preimage { encoded_proposal: remark().encode().into() },
+		)
+		.execute_returns(remark_hash());
+	precompiles()
+		.prepare_test(
+			Alice,
+			Precompile1,
+			PCall::propose { proposal_hash: remark_hash(), value: 10.into() },
+		)
+		.execute_returns(());
+}
+
+fn start_referendum() -> u32 {
+	let proposal = Preimage::bound(remark()).expect("remark can be bounded");
+	Democracy::internal_start_referendum(proposal, VoteThreshold::SuperMajorityApprove, 0)
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::propose_selectors().contains(&0x7824e7d1));
+	assert!(PCall::second_selectors().contains(&0xf0f77847));
+	assert!(PCall::standard_vote_selectors().contains(&0x3da493f0));
+	assert!(PCall::remove_vote_selectors().contains(&0x79cae220));
+	assert!(PCall::delegate_selectors().contains(&0x3680a9d3));
+	assert!(PCall::un_delegate_selectors().contains(&0x1eef225c));
+	assert!(PCall::unlock_selectors().contains(&0x2f6c493c));
+	assert!(PCall::note_preimage_selectors().contains(&0xcb00f603));
+	assert!(PCall::public_prop_count_selectors().contains(&0x31305462));
+	assert!(PCall::deposit_of_selectors().contains(&0x0cd8c00a));
+	assert!(PCall::lowest_unbaked_selectors().contains(&0xd49dccf0));
+	assert!(PCall::ongoing_referendum_info_selectors().contains(&0xf033b7cd));
+	assert!(PCall::finished_referendum_info_selectors().contains(&0xc75abcce));
+}
+
+#[test]
+fn modifiers() {
+	ext().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::propose_selectors());
+		tester.test_default_modifier(PCall::second_selectors());
+		tester.test_default_modifier(PCall::standard_vote_selectors());
+		tester.test_default_modifier(PCall::remove_vote_selectors());
+		tester.test_default_modifier(PCall::delegate_selectors());
+		tester.test_default_modifier(PCall::un_delegate_selectors());
+		tester.test_default_modifier(PCall::unlock_selectors());
+		tester.test_default_modifier(PCall::note_preimage_selectors());
+		tester.test_view_modifier(PCall::public_prop_count_selectors());
+		tester.test_view_modifier(PCall::deposit_of_selectors());
+		tester.test_view_modifier(PCall::lowest_unbaked_selectors());
+		tester.test_view_modifier(PCall::ongoing_referendum_info_selectors());
+		tester.test_view_modifier(PCall::finished_referendum_info_selectors());
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["src/Democracy.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn propose_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::note_preimage { encoded_proposal: remark().encode().into() },
+			)
+			.execute_returns(remark_hash());
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::propose { proposal_hash: remark_hash(), value: 10.into() },
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_PROPOSED,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data(U256::from(10)),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::public_prop_count {})
+			.execute_returns(1u32);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::deposit_of { proposal_index: 0 })
+			.execute_returns(U256::from(10));
+	});
+}
+
+#[test]
+fn propose_reverts_without_preimage() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::propose { proposal_hash: remark_hash(), value: 10.into() },
+			)
+			.execute_reverts(|output| output == b"proposalHash: Failure in preimage fetch");
+	});
+}
+
+#[test]
+fn second_works() {
+	ext().execute_with(|| {
+		note_and_propose();
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::second { proposal_index: 0 })
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_SECONDED,
+				H256::from_low_u64_be(0),
+				solidity::encode_event_data(Address(Bob.into())),
+			))
+			.execute_returns(());
+
+		let (seconds, deposit) =
+			pallet_democracy::DepositOf::<Runtime>::get(0).expect("proposal exists");
+		assert_eq!(seconds.into_inner(), vec![AccountId::from(Alice), AccountId::from(Bob)]);
+		assert_eq!(deposit, 10);
+	});
+}
+
+#[test]
+fn standard_vote_works() {
+	ext().execute_with(|| {
+		let ref_index = start_referendum();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::standard_vote {
+					ref_index,
+					aye: true,
+					vote_amount: 100.into(),
+					conviction: 1,
+				},
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_STANDARD_VOTE,
+				H256::from_low_u64_be(ref_index.into()),
+				solidity::encode_event_data((Address(Alice.into()), true, U256::from(100), 1u8)),
+			))
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ongoing_referendum_info { ref_index })
+			.execute_returns(OngoingReferendumInfo {
+				end: 11,
+				proposal_hash: remark_hash(),
+				threshold: 0,
+				delay: 0,
+				ayes: 100.into(),
+				nays: 0.into(),
+				turnout: 100.into(),
+			});
+	});
+}
+
+#[test]
+fn standard_vote_reverts_for_invalid_conviction() {
+	ext().execute_with(|| {
+		let ref_index = start_referendum();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::standard_vote {
+					ref_index,
+					aye: true,
+					vote_amount: 100.into(),
+					conviction: 7,
+				},
+			)
+			.execute_reverts(|output| {
+				output == b"conviction: Must be an integer between 0 and 6 included"
+			});
+	});
+}
+
+#[test]
+fn remove_vote_works() {
+	ext().execute_with(|| {
+		let ref_index = start_referendum();
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::standard_vote {
+					ref_index,
+					aye: false,
+					vote_amount: 100.into(),
+					conviction: 0,
+				},
+			)
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::remove_vote { ref_index })
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ongoing_referendum_info { ref_index })
+			.execute_returns(OngoingReferendumInfo {
+				end: 11,
+				proposal_hash: remark_hash(),
+				threshold: 0,
+				delay: 0,
+				..Default::default()
+			});
+	});
+}
+
+#[test]
+fn finished_referendum_info_works() {
+	ext().execute_with(|| {
+		let ref_index = start_referendum();
+		pallet_democracy::ReferendumInfoOf::<Runtime>::insert(
+			ref_index,
+			ReferendumInfo::Finished { approved: true, end: 11 },
+		);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::finished_referendum_info { ref_index })
+			.execute_returns(FinishedReferendumInfo { approved: true, end: 11 });
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ongoing_referendum_info { ref_index })
+			.execute_reverts(|output| output == b"refIndex: Referendum is not ongoing");
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::finished_referendum_info { ref_index: 1 })
+			.execute_reverts(|output| output == b"refIndex: Unknown referendum");
+	});
+}
+
+#[test]
+fn delegate_and_undelegate_work() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::delegate {
+					representative: Address(Bob.into()),
+					conviction: 2,
+					amount: 100.into(),
+				},
+			)
+			.expect_log(log2(
+				Precompile1,
+				SELECTOR_LOG_DELEGATED,
+				H160::from(Alice),
+				solidity::encode_event_data(Address(Bob.into())),
+			))
+			.execute_returns(());
+
+		assert!(matches!(
+			pallet_democracy::VotingOf::<Runtime>::get(AccountId::from(Alice)),
+			Voting::Delegating { balance: 100, .. }
+		));
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::un_delegate {})
+			.expect_log(log2(Precompile1, SELECTOR_LOG_UNDELEGATED, H160::from(Alice), Vec::new()))
+			.execute_returns(());
+
+		assert!(matches!(
+			pallet_democracy::VotingOf::<Runtime>::get(AccountId::from(Alice)),
+			Voting::Direct { .. }
+		));
+	});
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The address of the ERC-20 contract of the native currency.
+address constant NATIVE_ERC20_ADDRESS = 0x0000000000000000000000000000000000001004;
+
+/// @dev The ERC-20 contract of the native currency.
+IERC20 constant NATIVE_ERC20_CONTRACT = IERC20(NATIVE_ERC20_ADDRESS);
+
+/// @title ERC-20 interface of the native currency and of pallet-assets assets
+/// @dev The native currency lives at 0x0000000000000000000000000000000000001004. An asset lives
+/// at its instance's prefix followed by its id: 0xFFFFFFFF for Assets, 0xFFFFFFFE for PoolAssets.
+/// @custom:address 0x0000000000000000000000000000000000001004
+interface IERC20 {
+    /// @dev Returns the name of the token.
+    /// @custom:selector 06fdde03
+    function name() external view returns (string memory);
+
+    /// @dev Returns the symbol of the token.
+    /// @custom:selector 95d89b41
+    function symbol() external view returns (string memory);
+
+    /// @dev Returns the decimals places of the token.
+    /// @custom:selector 313ce567
+    function decimals() external view returns (uint8);
+
+    /// @dev Total number of tokens in existence.
+    /// @custom:selector 18160ddd
+    function totalSupply() external view returns (uint256);
+
+    /// @dev Gets the balance of the specified address.
+    /// @param owner The address to query the balance of.
+    /// @return An uint256 representing the amount owned by the passed address.
+    /// @custom:selector 70a08231
+    function balanceOf(address owner) external view returns (uint256);
+
+    /// @dev Function to check the amount of tokens that an owner allowed to a spender.
+    /// @param owner address The address which owns the funds.
+    /// @param spender address The address which will spend the funds.
+    /// @return A uint256 specifying the amount of tokens still available for the spender.
+    /// @custom:selector dd62ed3e
+    function allowance(address owner, address spender) external view returns (uint256);
+
+    /// @dev Transfer token for a specified address.
+    /// @param to The address to transfer to.
+    /// @param value The amount to be transferred.
+    /// @return true if the transfer was succesful, revert otherwise.
+    /// @custom:selector a9059cbb
+    function transfer(address to, uint256 value) external returns (bool);
+
+    /// @dev Approve the passed address to spend the specified amount of tokens on behalf
+    /// of msg.sender, replacing any previous allowance.
+    /// @param spender The address which will spend the funds.
+    /// @param value The amount of tokens to be spent.
+    /// @return true, this cannot fail.
+    /// @custom:selector 095ea7b3
+    function approve(address spender, uint256 value) external returns (bool);
+
+    /// @dev Transfer tokens from one address to another.
+    /// @param from address The address which you want to send tokens from.
+    /// @param to address The address which you want to transfer to.
+    /// @param value uint256 the amount of tokens to be transferred.
+    /// @return true if the transfer was succesful, revert otherwise.
+    /// @custom:selector 23b872dd
+    function transferFrom(address from, address to, uint256 value) external returns (bool);
+
+    /// @dev Event emited when a transfer has been performed.
+    /// @custom:selector ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
+    /// @param from address The address sending the tokens
+    /// @param to address The address receiving the tokens.
+    /// @param value uint256 The amount of tokens transfered.
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /// @dev Event emited when an approval has been registered.
+    /// @custom:selector 8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925
+    /// @param owner address Owner of the tokens.
+    /// @param spender address Allowed spender.
+    /// @param value uint256 Amount of tokens approved.
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+}
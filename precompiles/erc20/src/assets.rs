@@ -0,0 +1,305 @@
+use super::*;
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{
+		fungibles::{approvals::Inspect as ApprovalsInspect, metadata::Inspect as MetadataInspect},
+		Get,
+	},
+};
+use pallet_evm::AddressMapping;
+use sp_runtime::traits::{Bounded, Dispatchable, StaticLookup, Zero};
+use sp_std::marker::PhantomData;
+
+type BalanceOf<Runtime, Instance> = <Runtime as pallet_assets::Config<Instance>>::Balance;
+type AssetIdOf<Runtime, Instance> = <Runtime as pallet_assets::Config<Instance>>::AssetId;
+
+/// ERC-20 precompiles of the assets of a `pallet_assets` instance.
+///
+/// The precompile of an asset lives at `Prefix` followed by the asset id, big endian, and only
+/// answers once the asset exists.
+pub struct Erc20AssetsPrecompileSet<Runtime, Prefix, Instance: 'static = ()>(
+	PhantomData<(Runtime, Prefix, Instance)>,
+);
+
+impl<Runtime, Prefix, Instance> Default for Erc20AssetsPrecompileSet<Runtime, Prefix, Instance> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime, Prefix, Instance> Erc20AssetsPrecompileSet<Runtime, Prefix, Instance>
+where
+	Runtime: pallet_assets::Config<Instance>,
+	Prefix: Get<&'static [u8]>,
+	Instance: 'static,
+	AssetIdOf<Runtime, Instance>: Into<u128> + TryFrom<u128>,
+{
+	/// Address of the precompile of `asset_id`.
+	pub fn asset_id_to_address(asset_id: AssetIdOf<Runtime, Instance>) -> H160 {
+		let prefix = Prefix::get();
+		let asset_id = asset_id.into().to_be_bytes();
+		let id_len = (20 - prefix.len()).min(asset_id.len());
+
+		let mut address = H160::zero();
+		address[..prefix.len()].copy_from_slice(prefix);
+		address[20 - id_len..].copy_from_slice(&asset_id[asset_id.len() - id_len..]);
+		address
+	}
+
+	/// Asset whose precompile lives at `address`, if `address` can be one.
+	pub fn address_to_asset_id(address: H160) -> Option<AssetIdOf<Runtime, Instance>> {
+		let id_bytes = address.as_bytes().strip_prefix(Prefix::get())?;
+		let mut asset_id = [0u8; 16];
+		let id_len = id_bytes.len().min(asset_id.len());
+		let (padding, id_bytes) = id_bytes.split_at(id_bytes.len() - id_len);
+		if padding.iter().any(|byte| *byte != 0) {
+			return None
+		}
+
+		asset_id[16 - id_len..].copy_from_slice(id_bytes);
+		u128::from_be_bytes(asset_id).try_into().ok()
+	}
+}
+
+#[precompile_utils::precompile]
+#[precompile::precompile_set]
+impl<Runtime, Prefix, Instance> Erc20AssetsPrecompileSet<Runtime, Prefix, Instance>
+where
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+	Prefix: Get<&'static [u8]>,
+	Instance: 'static,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_assets::Call<Runtime, Instance>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	<Runtime as pallet_assets::Config<Instance>>::AssetIdParameter:
+		From<AssetIdOf<Runtime, Instance>>,
+	AssetIdOf<Runtime, Instance>: Into<u128> + TryFrom<u128>,
+	BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256>,
+{
+	#[precompile::discriminant]
+	fn discriminant(address: H160, gas: u64) -> DiscriminantResult<AssetIdOf<Runtime, Instance>> {
+		let extra_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+		if gas < extra_cost {
+			return DiscriminantResult::OutOfGas
+		}
+
+		match Self::address_to_asset_id(address) {
+			Some(asset_id)
+				if pallet_assets::Pallet::<Runtime, Instance>::maybe_total_supply(
+					asset_id.clone(),
+				)
+				.is_some() =>
+			{
+				DiscriminantResult::Some(asset_id, extra_cost)
+			},
+			_ => DiscriminantResult::None(extra_cost),
+		}
+	}
+
+	#[precompile::public("totalSupply()")]
+	#[precompile::view]
+	fn total_supply(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<U256> {
+		// Storage item: Asset:
+		// Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(20)) + (3 * Balance(16)) + 15)
+		handle.record_db_read::<Runtime>(175)?;
+
+		Ok(pallet_assets::Pallet::<Runtime, Instance>::total_supply(asset_id).into())
+	}
+
+	#[precompile::public("balanceOf(address)")]
+	#[precompile::view]
+	fn balance_of(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+	) -> EvmResult<U256> {
+		// Storage item: Account:
+		// Blake2_128(16) + AssetId(16) + Blake2_128(16) + AccountId(20) + AssetAccount(19)
+		handle.record_db_read::<Runtime>(87)?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+		Ok(pallet_assets::Pallet::<Runtime, Instance>::balance(asset_id, owner).into())
+	}
+
+	#[precompile::public("allowance(address,address)")]
+	#[precompile::view]
+	fn allowance(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		spender: Address,
+	) -> EvmResult<U256> {
+		// Storage item: Approvals:
+		// (3 * Blake2_128(16)) + AssetId(16) + (2 * AccountId(20)) + Approval(32)
+		handle.record_db_read::<Runtime>(136)?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+		let spender = Runtime::AddressMapping::into_account_id(spender.into());
+		Ok(pallet_assets::Pallet::<Runtime, Instance>::allowance(asset_id, &owner, &spender).into())
+	}
+
+	#[precompile::public("approve(address,uint256)")]
+	fn approve(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		spender: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		// Storage item: Approvals:
+		// (3 * Blake2_128(16)) + AssetId(16) + (2 * AccountId(20)) + Approval(32)
+		handle.record_db_read::<Runtime>(136)?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Allowances too large for the balance type are capped, since no balance can exceed them.
+		let amount: BalanceOf<Runtime, Instance> =
+			value.try_into().unwrap_or_else(|_| Bounded::max_value());
+		let owner = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let delegate = Runtime::AddressMapping::into_account_id(spender.into());
+
+		// Assets approvals add up, so the current one is cancelled to replace it instead.
+		if !pallet_assets::Pallet::<Runtime, Instance>::allowance(
+			asset_id.clone(),
+			&owner,
+			&delegate,
+		)
+		.is_zero()
+		{
+			let call = pallet_assets::Call::<Runtime, Instance>::cancel_approval {
+				id: asset_id.clone().into(),
+				delegate: Runtime::Lookup::unlookup(delegate.clone()),
+			};
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(owner.clone()).into(), call)?;
+		}
+		if !amount.is_zero() {
+			let call = pallet_assets::Call::<Runtime, Instance>::approve_transfer {
+				id: asset_id.into(),
+				delegate: Runtime::Lookup::unlookup(delegate),
+				amount,
+			};
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(owner).into(), call)?;
+		}
+
+		log_approval(handle.context().address, handle.context().caller, spender.into(), value)
+			.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transfer(address,uint256)")]
+	fn transfer(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		to: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let amount = Self::u256_to_amount(value).in_field("value")?;
+		let caller = handle.context().caller;
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+		let target = Runtime::AddressMapping::into_account_id(to.into());
+		let call = pallet_assets::Call::<Runtime, Instance>::transfer {
+			id: asset_id.into(),
+			target: Runtime::Lookup::unlookup(target),
+			amount,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		log_transfer(handle.context().address, caller, to.into(), value).record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transferFrom(address,address,uint256)")]
+	fn transfer_from(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let amount = Self::u256_to_amount(value).in_field("value")?;
+		let caller = handle.context().caller;
+		let from: H160 = from.into();
+		let owner = Runtime::AddressMapping::into_account_id(from);
+		let destination = Runtime::AddressMapping::into_account_id(to.into());
+
+		if caller != from {
+			let call = pallet_assets::Call::<Runtime, Instance>::transfer_approved {
+				id: asset_id.into(),
+				owner: Runtime::Lookup::unlookup(owner),
+				destination: Runtime::Lookup::unlookup(destination),
+				amount,
+			};
+			let origin = Runtime::AddressMapping::into_account_id(caller);
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+		} else {
+			let call = pallet_assets::Call::<Runtime, Instance>::transfer {
+				id: asset_id.into(),
+				target: Runtime::Lookup::unlookup(destination),
+				amount,
+			};
+			RuntimeHelper::<Runtime>::try_dispatch(handle, Some(owner).into(), call)?;
+		}
+
+		log_transfer(handle.context().address, from, to.into(), value).record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("name()")]
+	#[precompile::view]
+	fn name(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedString> {
+		Self::record_metadata_read(handle)?;
+
+		Ok(pallet_assets::Pallet::<Runtime, Instance>::name(asset_id).into())
+	}
+
+	#[precompile::public("symbol()")]
+	#[precompile::view]
+	fn symbol(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<UnboundedString> {
+		Self::record_metadata_read(handle)?;
+
+		Ok(pallet_assets::Pallet::<Runtime, Instance>::symbol(asset_id).into())
+	}
+
+	#[precompile::public("decimals()")]
+	#[precompile::view]
+	fn decimals(
+		asset_id: AssetIdOf<Runtime, Instance>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<u8> {
+		Self::record_metadata_read(handle)?;
+
+		Ok(pallet_assets::Pallet::<Runtime, Instance>::decimals(asset_id))
+	}
+
+	fn record_metadata_read(handle: &mut impl PrecompileHandle) -> EvmResult {
+		// Storage item: Metadata:
+		// Blake2_128(16) + AssetId(16) + AssetMetadata(Balance(16) + (2 * StringLimit) + 2)
+		let string_limit =
+			<Runtime as pallet_assets::Config<Instance>>::StringLimit::get() as usize;
+		handle.record_db_read::<Runtime>(50 + 2 * string_limit)?;
+
+		Ok(())
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime, Instance>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+}
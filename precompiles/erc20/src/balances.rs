@@ -0,0 +1,208 @@
+use super::*;
+
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	storage::types::{StorageDoubleMap, ValueQuery},
+	traits::StorageInstance,
+	Blake2_128Concat,
+};
+use pallet_evm::AddressMapping;
+use sp_runtime::traits::{Bounded, CheckedSub, Dispatchable, StaticLookup};
+use sp_std::marker::PhantomData;
+
+type BalanceOf<Runtime> = <Runtime as pallet_balances::Config>::Balance;
+
+/// Prefix of the storage holding the allowances of the native currency.
+pub struct ApprovesPrefix;
+
+impl StorageInstance for ApprovesPrefix {
+	const STORAGE_PREFIX: &'static str = "Approves";
+
+	fn pallet_prefix() -> &'static str {
+		"Erc20Balances"
+	}
+}
+
+/// Allowances of the native currency, which `pallet_balances` doesn't keep track of.
+/// (Owner => Spender => Amount)
+pub type ApprovesStorage<Runtime> = StorageDoubleMap<
+	ApprovesPrefix,
+	Blake2_128Concat,
+	<Runtime as frame_system::Config>::AccountId,
+	Blake2_128Concat,
+	<Runtime as frame_system::Config>::AccountId,
+	BalanceOf<Runtime>,
+	ValueQuery,
+>;
+
+/// Metadata of the native currency, as exposed by its ERC-20 precompile.
+pub trait Erc20Metadata {
+	/// Name of the currency, such as "5ire".
+	fn name() -> &'static str;
+
+	/// Symbol of the currency, such as "5IRE".
+	fn symbol() -> &'static str;
+
+	/// Number of decimals of the currency.
+	fn decimals() -> u8;
+}
+
+/// ERC-20 precompile of the native currency.
+pub struct Erc20BalancesPrecompile<Runtime, Metadata>(PhantomData<(Runtime, Metadata)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Metadata> Erc20BalancesPrecompile<Runtime, Metadata>
+where
+	Runtime: pallet_balances::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_balances::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+	Metadata: Erc20Metadata,
+{
+	#[precompile::public("totalSupply()")]
+	#[precompile::view]
+	fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		// Storage item: TotalIssuance:
+		// Balance(16)
+		handle.record_db_read::<Runtime>(16)?;
+
+		Ok(pallet_balances::Pallet::<Runtime>::total_issuance().into())
+	}
+
+	#[precompile::public("balanceOf(address)")]
+	#[precompile::view]
+	fn balance_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+		// Storage item: Account:
+		// Blake2_128(16) + AccountId(20) + AccountInfo((4 * 4) + AccountData(16 * 4))
+		handle.record_db_read::<Runtime>(116)?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+		Ok(pallet_balances::Pallet::<Runtime>::usable_balance(&owner).into())
+	}
+
+	#[precompile::public("allowance(address,address)")]
+	#[precompile::view]
+	fn allowance(
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		spender: Address,
+	) -> EvmResult<U256> {
+		// Storage item: Approves:
+		// Blake2_128(16) + AccountId(20) + Blake2_128(16) + AccountId(20) + Balance(16)
+		handle.record_db_read::<Runtime>(88)?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+		let spender = Runtime::AddressMapping::into_account_id(spender.into());
+		Ok(ApprovesStorage::<Runtime>::get(owner, spender).into())
+	}
+
+	#[precompile::public("approve(address,uint256)")]
+	fn approve(
+		handle: &mut impl PrecompileHandle,
+		spender: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Allowances too large for the balance type are capped, since no balance can exceed them.
+		let amount: BalanceOf<Runtime> = value.try_into().unwrap_or_else(|_| Bounded::max_value());
+		let owner = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let spender_id = Runtime::AddressMapping::into_account_id(spender.into());
+		ApprovesStorage::<Runtime>::insert(owner, spender_id, amount);
+
+		log_approval(handle.context().address, handle.context().caller, spender.into(), value)
+			.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transfer(address,uint256)")]
+	fn transfer(handle: &mut impl PrecompileHandle, to: Address, value: U256) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let caller = handle.context().caller;
+		Self::dispatch_transfer(handle, caller, to.into(), value)?;
+
+		log_transfer(handle.context().address, caller, to.into(), value).record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transferFrom(address,address,uint256)")]
+	fn transfer_from(
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let caller = handle.context().caller;
+		let from: H160 = from.into();
+		if caller != from {
+			// Storage item: Approves:
+			// Blake2_128(16) + AccountId(20) + Blake2_128(16) + AccountId(20) + Balance(16)
+			handle.record_db_read::<Runtime>(88)?;
+			handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+			let amount = Self::u256_to_amount(value).in_field("value")?;
+			let owner = Runtime::AddressMapping::into_account_id(from);
+			let spender = Runtime::AddressMapping::into_account_id(caller);
+			ApprovesStorage::<Runtime>::try_mutate(owner, spender, |allowance| -> MayRevert {
+				*allowance = allowance
+					.checked_sub(&amount)
+					.ok_or_else(|| revert("trying to spend more than allowed"))?;
+				Ok(())
+			})?;
+		}
+
+		Self::dispatch_transfer(handle, from, to.into(), value)?;
+
+		log_transfer(handle.context().address, from, to.into(), value).record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("name()")]
+	#[precompile::view]
+	fn name(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedString> {
+		Ok(Metadata::name().into())
+	}
+
+	#[precompile::public("symbol()")]
+	#[precompile::view]
+	fn symbol(_handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedString> {
+		Ok(Metadata::symbol().into())
+	}
+
+	#[precompile::public("decimals()")]
+	#[precompile::view]
+	fn decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		Ok(Metadata::decimals())
+	}
+
+	fn dispatch_transfer(
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		value: U256,
+	) -> EvmResult {
+		let value = Self::u256_to_amount(value).in_field("value")?;
+		let origin = Runtime::AddressMapping::into_account_id(from);
+		let dest = Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(to));
+		let call = pallet_balances::Call::<Runtime>::transfer_allow_death { dest, value };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+}
@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::Log;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+
+mod assets;
+mod balances;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use assets::Erc20AssetsPrecompileSet;
+pub use balances::{ApprovesPrefix, ApprovesStorage, Erc20BalancesPrecompile, Erc20Metadata};
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+pub fn log_transfer(address: impl Into<H160>, from: H160, to: H160, value: U256) -> Log {
+	log3(address, SELECTOR_LOG_TRANSFER, from, to, solidity::encode_event_data(value))
+}
+
+pub fn log_approval(address: impl Into<H160>, owner: H160, spender: H160, value: U256) -> Log {
+	log3(address, SELECTOR_LOG_APPROVAL, owner, spender, solidity::encode_event_data(value))
+}
@@ -0,0 +1,487 @@
+use crate::{
+	log_approval, log_transfer,
+	mock::{
+		asset_address, balance, AccountId, Alice, Assets, AssetsPCall, BalancesPCall, Bob, Charlie,
+		ExtBuilder, MockPrecompileSet, NativeErc20, PrecompilesValue, Runtime, RuntimeOrigin,
+	},
+};
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::U256;
+
+fn precompiles() -> MockPrecompileSet<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.build()
+}
+
+/// Creates asset `0`, owned by Alice, with 1_000 units minted to her.
+fn create_asset() {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0u32.into(), Alice.into(), true, 1));
+	assert_ok!(Assets::force_set_metadata(
+		RuntimeOrigin::root(),
+		0u32.into(),
+		b"Test asset".to_vec(),
+		b"TEST".to_vec(),
+		12,
+		false,
+	));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(Alice.into()), 0u32.into(), Alice.into(), 1_000));
+}
+
+fn asset_balance(account: impl Into<AccountId>) -> u128 {
+	Assets::balance(0, account.into())
+}
+
+#[test]
+fn selectors() {
+	assert!(BalancesPCall::name_selectors().contains(&0x06fdde03));
+	assert!(BalancesPCall::symbol_selectors().contains(&0x95d89b41));
+	assert!(BalancesPCall::decimals_selectors().contains(&0x313ce567));
+	assert!(BalancesPCall::total_supply_selectors().contains(&0x18160ddd));
+	assert!(BalancesPCall::balance_of_selectors().contains(&0x70a08231));
+	assert!(BalancesPCall::allowance_selectors().contains(&0xdd62ed3e));
+	assert!(BalancesPCall::transfer_selectors().contains(&0xa9059cbb));
+	assert!(BalancesPCall::approve_selectors().contains(&0x095ea7b3));
+	assert!(BalancesPCall::transfer_from_selectors().contains(&0x23b872dd));
+
+	assert_eq!(
+		crate::SELECTOR_LOG_TRANSFER,
+		hex_literal::hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+	);
+	assert_eq!(
+		crate::SELECTOR_LOG_APPROVAL,
+		hex_literal::hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925")
+	);
+}
+
+#[test]
+fn modifiers() {
+	ext().execute_with(|| {
+		create_asset();
+
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, NativeErc20);
+
+		tester.test_view_modifier(BalancesPCall::name_selectors());
+		tester.test_view_modifier(BalancesPCall::symbol_selectors());
+		tester.test_view_modifier(BalancesPCall::decimals_selectors());
+		tester.test_view_modifier(BalancesPCall::total_supply_selectors());
+		tester.test_view_modifier(BalancesPCall::balance_of_selectors());
+		tester.test_view_modifier(BalancesPCall::allowance_selectors());
+		tester.test_default_modifier(BalancesPCall::transfer_selectors());
+		tester.test_default_modifier(BalancesPCall::approve_selectors());
+		tester.test_default_modifier(BalancesPCall::transfer_from_selectors());
+
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, asset_address(0));
+
+		tester.test_view_modifier(AssetsPCall::name_selectors());
+		tester.test_view_modifier(AssetsPCall::symbol_selectors());
+		tester.test_view_modifier(AssetsPCall::decimals_selectors());
+		tester.test_view_modifier(AssetsPCall::total_supply_selectors());
+		tester.test_view_modifier(AssetsPCall::balance_of_selectors());
+		tester.test_view_modifier(AssetsPCall::allowance_selectors());
+		tester.test_default_modifier(AssetsPCall::transfer_selectors());
+		tester.test_default_modifier(AssetsPCall::approve_selectors());
+		tester.test_default_modifier(AssetsPCall::transfer_from_selectors());
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["src/ERC20.sol"],
+		BalancesPCall::supports_selector,
+	);
+	check_precompile_implements_solidity_interfaces(
+		&["src/ERC20.sol"],
+		AssetsPCall::supports_selector,
+	);
+}
+
+#[test]
+fn native_metadata() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, NativeErc20, BalancesPCall::name {})
+			.execute_returns(UnboundedString::from("Mock token"));
+		precompiles()
+			.prepare_test(Alice, NativeErc20, BalancesPCall::symbol {})
+			.execute_returns(UnboundedString::from("MOCK"));
+		precompiles()
+			.prepare_test(Alice, NativeErc20, BalancesPCall::decimals {})
+			.execute_returns(18u8);
+	});
+}
+
+#[test]
+fn native_total_supply_and_balance_of() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, NativeErc20, BalancesPCall::total_supply {})
+			.execute_returns(U256::from(2_000));
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::balance_of { owner: Address(Bob.into()) },
+			)
+			.execute_returns(U256::from(1_000));
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::balance_of { owner: Address(Charlie.into()) },
+			)
+			.execute_returns(U256::zero());
+	});
+}
+
+#[test]
+fn native_transfer() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::transfer { to: Address(Charlie.into()), value: 400.into() },
+			)
+			.expect_log(log_transfer(NativeErc20, Alice.into(), Charlie.into(), 400.into()))
+			.execute_returns(true);
+
+		assert_eq!(balance(Alice), 600);
+		assert_eq!(balance(Charlie), 400);
+	});
+}
+
+#[test]
+fn native_transfer_more_than_balance_reverts() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::transfer { to: Address(Charlie.into()), value: 1_001.into() },
+			)
+			.execute_reverts(|output| {
+				core::str::from_utf8(output).unwrap().contains("FundsUnavailable")
+			});
+
+		assert_eq!(balance(Alice), 1_000);
+	});
+}
+
+#[test]
+fn native_approve_and_allowance() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::approve { spender: Address(Bob.into()), value: 300.into() },
+			)
+			.expect_log(log_approval(NativeErc20, Alice.into(), Bob.into(), 300.into()))
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::allowance {
+					owner: Address(Alice.into()),
+					spender: Address(Bob.into()),
+				},
+			)
+			.execute_returns(U256::from(300));
+
+		// A new approval replaces the previous one.
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::approve { spender: Address(Bob.into()), value: 100.into() },
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::allowance {
+					owner: Address(Alice.into()),
+					spender: Address(Bob.into()),
+				},
+			)
+			.execute_returns(U256::from(100));
+	});
+}
+
+#[test]
+fn native_transfer_from() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::approve { spender: Address(Bob.into()), value: 300.into() },
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NativeErc20,
+				BalancesPCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					value: 200.into(),
+				},
+			)
+			.expect_log(log_transfer(NativeErc20, Alice.into(), Charlie.into(), 200.into()))
+			.execute_returns(true);
+
+		assert_eq!(balance(Alice), 800);
+		assert_eq!(balance(Charlie), 200);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::allowance {
+					owner: Address(Alice.into()),
+					spender: Address(Bob.into()),
+				},
+			)
+			.execute_returns(U256::from(100));
+	});
+}
+
+#[test]
+fn native_transfer_from_more_than_allowed_reverts() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::approve { spender: Address(Bob.into()), value: 300.into() },
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				NativeErc20,
+				BalancesPCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					value: 301.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"trying to spend more than allowed");
+
+		assert_eq!(balance(Alice), 1_000);
+	});
+}
+
+#[test]
+fn native_transfer_from_self_needs_no_allowance() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Alice,
+				NativeErc20,
+				BalancesPCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					value: 400.into(),
+				},
+			)
+			.execute_returns(true);
+
+		assert_eq!(balance(Alice), 600);
+		assert_eq!(balance(Charlie), 400);
+	});
+}
+
+#[test]
+fn asset_address_round_trips() {
+	type Set = crate::Erc20AssetsPrecompileSet<
+		Runtime,
+		crate::mock::AssetsPrefix,
+		pallet_assets::Instance1,
+	>;
+
+	assert_eq!(
+		asset_address(0x1234),
+		sp_core::H160::from(hex_literal::hex!("ffffffff00000000000000000000000000001234"))
+	);
+	assert_eq!(Set::address_to_asset_id(asset_address(0x1234)), Some(0x1234));
+	assert_eq!(Set::address_to_asset_id(asset_address(u32::MAX)), Some(u32::MAX));
+	// Ids which don't fit the asset id type don't map to an asset.
+	assert_eq!(
+		Set::address_to_asset_id(sp_core::H160::from(hex_literal::hex!(
+			"ffffffff00000000000000000000000100000000"
+		))),
+		None
+	);
+}
+
+#[test]
+fn unknown_asset_is_not_a_precompile() {
+	ext().execute_with(|| {
+		create_asset();
+
+		precompiles()
+			.prepare_test(Alice, asset_address(1), AssetsPCall::total_supply {})
+			.execute_none();
+	});
+}
+
+#[test]
+fn asset_metadata_and_balances() {
+	ext().execute_with(|| {
+		create_asset();
+
+		precompiles()
+			.prepare_test(Alice, asset_address(0), AssetsPCall::name {})
+			.execute_returns(UnboundedString::from("Test asset"));
+		precompiles()
+			.prepare_test(Alice, asset_address(0), AssetsPCall::symbol {})
+			.execute_returns(UnboundedString::from("TEST"));
+		precompiles()
+			.prepare_test(Alice, asset_address(0), AssetsPCall::decimals {})
+			.execute_returns(12u8);
+		precompiles()
+			.prepare_test(Alice, asset_address(0), AssetsPCall::total_supply {})
+			.execute_returns(U256::from(1_000));
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::balance_of { owner: Address(Alice.into()) },
+			)
+			.execute_returns(U256::from(1_000));
+	});
+}
+
+#[test]
+fn asset_transfer() {
+	ext().execute_with(|| {
+		create_asset();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::transfer { to: Address(Charlie.into()), value: 400.into() },
+			)
+			.expect_log(log_transfer(asset_address(0), Alice.into(), Charlie.into(), 400.into()))
+			.execute_returns(true);
+
+		assert_eq!(asset_balance(Alice), 600);
+		assert_eq!(asset_balance(Charlie), 400);
+	});
+}
+
+#[test]
+fn asset_approve_replaces_allowance() {
+	ext().execute_with(|| {
+		create_asset();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::approve { spender: Address(Bob.into()), value: 300.into() },
+			)
+			.expect_log(log_approval(asset_address(0), Alice.into(), Bob.into(), 300.into()))
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::approve { spender: Address(Bob.into()), value: 100.into() },
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::allowance {
+					owner: Address(Alice.into()),
+					spender: Address(Bob.into()),
+				},
+			)
+			.execute_returns(U256::from(100));
+
+		// Approving zero removes the allowance.
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::approve { spender: Address(Bob.into()), value: 0.into() },
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::allowance {
+					owner: Address(Alice.into()),
+					spender: Address(Bob.into()),
+				},
+			)
+			.execute_returns(U256::zero());
+	});
+}
+
+#[test]
+fn asset_transfer_from() {
+	ext().execute_with(|| {
+		create_asset();
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				asset_address(0),
+				AssetsPCall::approve { spender: Address(Bob.into()), value: 300.into() },
+			)
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				asset_address(0),
+				AssetsPCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					value: 200.into(),
+				},
+			)
+			.expect_log(log_transfer(asset_address(0), Alice.into(), Charlie.into(), 200.into()))
+			.execute_returns(true);
+
+		assert_eq!(asset_balance(Alice), 800);
+		assert_eq!(asset_balance(Charlie), 200);
+
+		precompiles()
+			.prepare_test(
+				Bob,
+				asset_address(0),
+				AssetsPCall::transfer_from {
+					from: Address(Alice.into()),
+					to: Address(Charlie.into()),
+					value: 101.into(),
+				},
+			)
+			.execute_reverts(|output| core::str::from_utf8(output).unwrap().contains("Unapproved"));
+
+		assert_eq!(asset_balance(Alice), 800);
+	});
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The EsgScore contract's address.
+address constant ESG_SCORE_ADDRESS = 0x0000000000000000000000000000000000001002;
+
+/// @dev The EsgScore contract's instance.
+EsgScore constant ESG_SCORE_CONTRACT = EsgScore(ESG_SCORE_ADDRESS);
+
+/// @title EsgScore precompile
+/// @dev Allows contracts to read the ESG scores of accounts, and oracles to submit them.
+/// @custom:address 0x0000000000000000000000000000000000001002
+interface EsgScore {
+    /// @dev Get the effective ESG score of an account, averaged over its score history.
+    /// @param company The account to get the score of.
+    /// @return The score, between 0 and 100.
+    /// @custom:selector 133af456
+    function scoreOf(address company) external view returns (uint16);
+
+    /// @dev Check whether an account is a registered ESG oracle, sudo or not.
+    /// @param oracle The account to check.
+    /// @return True if the account is an oracle.
+    /// @custom:selector a97e5c93
+    function isOracle(address oracle) external view returns (bool);
+
+    /// @dev Submit the scores of several accounts. Can only be called by an oracle.
+    /// Scores of sudo oracles are stored right away, scores of other oracles count towards
+    /// the quorum of their account.
+    ///
+    /// @param companies List of accounts to score.
+    /// @param scores Score of each account, capped at 100. Must be as long as "companies".
+    /// @custom:selector 65d5407d
+    function submitScores(address[] memory companies, uint16[] memory scores)
+        external;
+}
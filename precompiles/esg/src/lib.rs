@@ -0,0 +1,92 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::{ConstU32, Get},
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
+
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+
+/// ESG score precompile.
+#[derive(Debug, Clone)]
+pub struct EsgPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> EsgPrecompile<Runtime>
+where
+	Runtime: pallet_esg::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_esg::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	#[precompile::public("scoreOf(address)")]
+	#[precompile::view]
+	fn score_of(handle: &mut impl PrecompileHandle, company: Address) -> EvmResult<u16> {
+		// Storage item: ESGScoreHistory:
+		// Blake2_128(16) + AccountId(20) + Vec(5) + MaxScoreHistory * ScoreRecord(2 + 4 + 20)
+		let max_history = <Runtime as pallet_esg::Config>::MaxScoreHistory::get() as usize;
+		handle.record_db_read::<Runtime>(41 + 26 * max_history)?;
+		// Storage item: Disputes:
+		// Blake2_128(16) + AccountId(20) + Dispute(20 + 16 + 32 + 26)
+		handle.record_db_read::<Runtime>(130)?;
+
+		let company = Runtime::AddressMapping::into_account_id(company.into());
+		Ok(pallet_esg::Pallet::<Runtime>::effective_score_of(company))
+	}
+
+	#[precompile::public("isOracle(address)")]
+	#[precompile::view]
+	fn is_oracle(handle: &mut impl PrecompileHandle, oracle: Address) -> EvmResult<bool> {
+		// Storage items: SudoOraclesStore and NonSudoOraclesStore:
+		// Vec(5) + MaxNumOf(Non)SudoOracles * AccountId(20)
+		let max_sudo = <Runtime as pallet_esg::Config>::MaxNumOfSudoOracles::get() as usize;
+		let max_non_sudo = <Runtime as pallet_esg::Config>::MaxNumOfNonSudoOracles::get() as usize;
+		handle.record_db_read::<Runtime>(5 + 20 * max_sudo)?;
+		handle.record_db_read::<Runtime>(5 + 20 * max_non_sudo)?;
+
+		let oracle = Runtime::AddressMapping::into_account_id(oracle.into());
+		Ok(pallet_esg::Pallet::<Runtime>::get_oracle_sudo().contains(&oracle) ||
+			pallet_esg::Pallet::<Runtime>::get_oracle_nsudo().contains(&oracle))
+	}
+
+	#[precompile::public("submitScores(address[],uint16[])")]
+	fn submit_scores(
+		handle: &mut impl PrecompileHandle,
+		companies: BoundedVec<Address, GetArrayLimit>,
+		scores: BoundedVec<u16, GetArrayLimit>,
+	) -> EvmResult {
+		let companies: Vec<_> = companies.into();
+		let scores: Vec<_> = scores.into();
+		if companies.len() != scores.len() {
+			return Err(revert("companies and scores must have the same length"));
+		}
+
+		let scores = companies
+			.into_iter()
+			.map(|company| Runtime::AddressMapping::into_account_id(company.into()))
+			.zip(scores)
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| revert("too many scores"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_esg::Call::<Runtime>::upsert_esg_scores_typed { scores };
+
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+}
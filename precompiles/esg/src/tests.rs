@@ -0,0 +1,157 @@
+use crate::mock::{
+	Alice, Bob, Charlie, Esg, ExtBuilder, MockPrecompileSet, PCall, Precompile1, PrecompilesValue,
+	Runtime, RuntimeOrigin,
+};
+use frame_support::assert_ok;
+use precompile_utils::testing::*;
+
+fn precompiles() -> MockPrecompileSet<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn register_oracle(oracle: impl Into<crate::mock::AccountId>, is_sudo: bool) {
+	assert_ok!(Esg::register_an_oracle(RuntimeOrigin::root(), oracle.into(), is_sudo));
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::score_of_selectors().contains(&0x133af456));
+	assert!(PCall::is_oracle_selectors().contains(&0xa97e5c93));
+	assert!(PCall::submit_scores_selectors().contains(&0x65d5407d));
+}
+
+#[test]
+fn modifiers() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+			tester.test_view_modifier(PCall::score_of_selectors());
+			tester.test_view_modifier(PCall::is_oracle_selectors());
+			tester.test_default_modifier(PCall::submit_scores_selectors());
+		});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(&["src/EsgScore.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn is_oracle_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000), (Bob.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			register_oracle(Alice, true);
+			register_oracle(Bob, false);
+
+			for (oracle, is_oracle) in [(Alice, true), (Bob, true), (Charlie, false)] {
+				precompiles()
+					.prepare_test(Alice, Precompile1, PCall::is_oracle { oracle: oracle.into() })
+					.expect_no_logs()
+					.execute_returns(is_oracle);
+			}
+		});
+}
+
+#[test]
+fn sudo_oracle_submits_scores() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			register_oracle(Alice, true);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::submit_scores {
+						companies: vec![Bob.into(), Charlie.into()].into(),
+						scores: vec![42, 250].into(),
+					},
+				)
+				.execute_returns(());
+
+			// scores above the maximum are capped
+			for (company, score) in [(Bob, 42u16), (Charlie, 100)] {
+				precompiles()
+					.prepare_test(Alice, Precompile1, PCall::score_of { company: company.into() })
+					.expect_no_logs()
+					.execute_returns(score);
+			}
+		});
+}
+
+#[test]
+fn non_sudo_oracle_submissions_wait_for_the_quorum() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			register_oracle(Alice, false);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::submit_scores {
+						companies: vec![Bob.into()].into(),
+						scores: vec![42].into(),
+					},
+				)
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(Alice, Precompile1, PCall::score_of { company: Bob.into() })
+				.execute_returns(0u16);
+			assert_eq!(
+				Esg::submissions_of(crate::mock::AccountId::from(Bob)),
+				vec![(Alice.into(), 42)]
+			);
+		});
+}
+
+#[test]
+fn non_oracle_cannot_submit_scores() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::submit_scores {
+					companies: vec![Bob.into()].into(),
+					scores: vec![42].into(),
+				},
+			)
+			.execute_reverts(|output| {
+				core::str::from_utf8(output).unwrap().contains("CallerNotAnOracle")
+			});
+	});
+}
+
+#[test]
+fn submit_scores_requires_as_many_scores_as_companies() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1000)])
+		.build()
+		.execute_with(|| {
+			register_oracle(Alice, true);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::submit_scores {
+						companies: vec![Bob.into(), Charlie.into()].into(),
+						scores: vec![42].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"companies and scores must have the same length"
+				});
+		});
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Staking contract's address.
+address constant STAKING_ADDRESS = 0x0000000000000000000000000000000000001003;
+
+/// @dev The Staking contract's instance.
+Staking constant STAKING_CONTRACT = Staking(STAKING_ADDRESS);
+
+/// @title Staking precompile
+/// @dev Allows accounts and contracts to bond, nominate validators and claim staking rewards.
+/// The caller is used as both the stash and the controller of its bond.
+/// @custom:address 0x0000000000000000000000000000000000001003
+interface Staking {
+    /// @dev Bond some of the caller's balance.
+    /// @param value The amount to bond.
+    /// @param payee Where rewards are paid: the zero address restakes them, the caller pays them
+    /// to its stash, and any other address receives them.
+    /// @custom:selector b78d27dc
+    function bond(uint256 value, address payee) external;
+
+    /// @dev Add some of the caller's free balance to its bond.
+    /// @param value The maximum amount to add.
+    /// @custom:selector eaca88de
+    function bondExtra(uint256 value) external;
+
+    /// @dev Schedule some of the caller's bond to be unlocked once the bonding duration has
+    /// passed.
+    /// @param value The amount to unbond.
+    /// @custom:selector 27de9e32
+    function unbond(uint256 value) external;
+
+    /// @dev Withdraw the caller's unlocked chunks.
+    /// @param numSlashingSpans The number of slashing spans of the caller.
+    /// @custom:selector 548a6706
+    function withdrawUnbonded(uint32 numSlashingSpans) external;
+
+    /// @dev Nominate validators with the caller's bond.
+    /// @param targets The validators to nominate.
+    /// @custom:selector 19f2fdad
+    function nominate(address[] memory targets) external;
+
+    /// @dev Stop validating or nominating.
+    /// @custom:selector 2b8a3ae6
+    function chill() external;
+
+    /// @dev Change where the caller's rewards are paid.
+    /// @param payee Same as in "bond".
+    /// @custom:selector 410459ad
+    function setPayee(address payee) external;
+
+    /// @dev Queue the payout of the unclaimed rewards of a validator and its nominators.
+    /// @param validator The validator whose rewards are paid.
+    /// @custom:selector 79ee54f7
+    function getRewards(address validator) external;
+
+    /// @dev Get the bond of a stash.
+    /// @param stash The stash to get the bond of.
+    /// @return total The total bonded amount, including unlocking chunks.
+    /// @return active The amount at stake.
+    /// @return unlocking The amount waiting to be unlocked.
+    /// @custom:selector fbfa941f
+    function ledger(address stash)
+        external
+        view
+        returns (uint256 total, uint256 active, uint256 unlocking);
+
+    /// @dev Get the validators nominated by an account.
+    /// @param nominator The nominator to get the targets of.
+    /// @return The nominated validators, empty if the account does not nominate.
+    /// @custom:selector f95baf26
+    function nominations(address nominator) external view returns (address[] memory);
+
+    /// @dev Get the rewards still owed to an account, as a validator and as a nominator.
+    /// @param who The account to get the rewards of.
+    /// @return The unclaimed rewards.
+    /// @custom:selector 31d7a262
+    function pendingRewards(address who) external view returns (uint256);
+
+    /// @dev Get the current and the active era.
+    /// @return currentEra The latest planned era.
+    /// @return activeEra The era being rewarded.
+    /// @return activeEraStart The start of the active era, in milliseconds since the epoch.
+    /// @custom:selector f6899aa8
+    function eraInfo()
+        external
+        view
+        returns (uint32 currentEra, uint32 activeEra, uint64 activeEraStart);
+}
@@ -0,0 +1,224 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::ConstU32,
+};
+use pallet_evm::AddressMapping;
+use pallet_staking::{BalanceOf, RewardDestination};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::{Dispatchable, Saturating, StaticLookup, Zero};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
+
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+
+/// Staking precompile.
+#[derive(Debug, Clone)]
+pub struct StakingPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> StakingPrecompile<Runtime>
+where
+	Runtime: pallet_reward::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_staking::Call<Runtime>>
+		+ From<pallet_reward::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+	#[precompile::public("bond(uint256,address)")]
+	fn bond(handle: &mut impl PrecompileHandle, value: U256, payee: Address) -> EvmResult {
+		let value = Self::u256_to_amount(value).in_field("value")?;
+		let payee = Self::reward_destination(handle.context().caller, payee);
+		let call = pallet_staking::Call::<Runtime>::bond { value, payee };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("bondExtra(uint256)")]
+	fn bond_extra(handle: &mut impl PrecompileHandle, value: U256) -> EvmResult {
+		let max_additional = Self::u256_to_amount(value).in_field("value")?;
+		let call = pallet_staking::Call::<Runtime>::bond_extra { max_additional };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("unbond(uint256)")]
+	fn unbond(handle: &mut impl PrecompileHandle, value: U256) -> EvmResult {
+		let value = Self::u256_to_amount(value).in_field("value")?;
+		let call = pallet_staking::Call::<Runtime>::unbond { value };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("withdrawUnbonded(uint32)")]
+	fn withdraw_unbonded(handle: &mut impl PrecompileHandle, num_slashing_spans: u32) -> EvmResult {
+		let call = pallet_staking::Call::<Runtime>::withdraw_unbonded { num_slashing_spans };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("nominate(address[])")]
+	fn nominate(
+		handle: &mut impl PrecompileHandle,
+		targets: BoundedVec<Address, GetArrayLimit>,
+	) -> EvmResult {
+		let targets: Vec<_> = targets.into();
+		let targets = targets
+			.into_iter()
+			.map(|target| {
+				Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(target.into()))
+			})
+			.collect();
+		let call = pallet_staking::Call::<Runtime>::nominate { targets };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("chill()")]
+	fn chill(handle: &mut impl PrecompileHandle) -> EvmResult {
+		Self::dispatch(handle, pallet_staking::Call::<Runtime>::chill {}.into())
+	}
+
+	#[precompile::public("setPayee(address)")]
+	fn set_payee(handle: &mut impl PrecompileHandle, payee: Address) -> EvmResult {
+		let payee = Self::reward_destination(handle.context().caller, payee);
+		let call = pallet_staking::Call::<Runtime>::set_payee { payee };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("getRewards(address)")]
+	fn get_rewards(handle: &mut impl PrecompileHandle, validator: Address) -> EvmResult {
+		let validator = Runtime::AddressMapping::into_account_id(validator.into());
+		let call = pallet_reward::Call::<Runtime>::get_rewards { validator };
+
+		Self::dispatch(handle, call.into())
+	}
+
+	#[precompile::public("ledger(address)")]
+	#[precompile::view]
+	fn ledger(handle: &mut impl PrecompileHandle, stash: Address) -> EvmResult<(U256, U256, U256)> {
+		// Storage item: Bonded:
+		// Twox64(8) + AccountId(20) + AccountId(20)
+		handle.record_db_read::<Runtime>(48)?;
+		// Storage item: Ledger:
+		// Blake2_128(16) + AccountId(20) + StakingLedger
+		handle.record_db_read::<Runtime>(
+			36 + pallet_staking::StakingLedger::<Runtime>::max_encoded_len(),
+		)?;
+
+		let stash = Runtime::AddressMapping::into_account_id(stash.into());
+		let ledger = pallet_staking::Pallet::<Runtime>::bonded(&stash)
+			.and_then(|controller| pallet_staking::Pallet::<Runtime>::ledger(&controller));
+
+		Ok(ledger
+			.map(|ledger| {
+				let unlocking =
+					ledger.unlocking.iter().fold(BalanceOf::<Runtime>::zero(), |total, chunk| {
+						total.saturating_add(chunk.value)
+					});
+				(ledger.total.into(), ledger.active.into(), unlocking.into())
+			})
+			.unwrap_or_default())
+	}
+
+	#[precompile::public("nominations(address)")]
+	#[precompile::view]
+	fn nominations(
+		handle: &mut impl PrecompileHandle,
+		nominator: Address,
+	) -> EvmResult<Vec<Address>> {
+		// Storage item: Nominators:
+		// Twox64(8) + AccountId(20) + Nominations
+		handle.record_db_read::<Runtime>(
+			28 + pallet_staking::Nominations::<Runtime>::max_encoded_len(),
+		)?;
+
+		let nominator = Runtime::AddressMapping::into_account_id(nominator.into());
+		Ok(pallet_staking::Pallet::<Runtime>::nominators(nominator)
+			.map(|nominations| {
+				nominations.targets.into_iter().map(|target| Address(target.into())).collect()
+			})
+			.unwrap_or_default())
+	}
+
+	#[precompile::public("pendingRewards(address)")]
+	#[precompile::view]
+	fn pending_rewards(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<U256> {
+		let read_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+
+		// Storage item: UnclaimedEras of the account.
+		handle.record_cost(read_cost)?;
+		let unclaimed_eras = pallet_reward::UnclaimedEras::<Runtime>::decode_len(&who).unwrap_or(0);
+		// Storage item: EraValidatorRewards of every unclaimed era.
+		handle.record_cost(read_cost.saturating_mul(unclaimed_eras as u64))?;
+		let mut pending = pallet_reward::Pallet::<Runtime>::pending_validator_rewards(who.clone());
+
+		for (era, validator) in
+			pallet_reward::NominatorRewardEras::<Runtime>::iter_key_prefix((who.clone(),))
+		{
+			// Storage items: NominatorRewardEras and EraNominatorRewards of the claim.
+			handle.record_cost(read_cost.saturating_mul(2))?;
+			pending = pending.saturating_add(pallet_reward::EraNominatorRewards::<Runtime>::get((
+				era,
+				validator,
+				who.clone(),
+			)));
+		}
+		Ok(Into::<u128>::into(pending).into())
+	}
+
+	#[precompile::public("eraInfo()")]
+	#[precompile::view]
+	fn era_info(handle: &mut impl PrecompileHandle) -> EvmResult<(u32, u32, u64)> {
+		// Storage item: CurrentEra:
+		// Option(1) + EraIndex(4)
+		handle.record_db_read::<Runtime>(5)?;
+		// Storage item: ActiveEra:
+		// EraIndex(4) + Option(1) + Moment(8)
+		handle.record_db_read::<Runtime>(13)?;
+
+		let current_era = pallet_staking::Pallet::<Runtime>::current_era().unwrap_or_default();
+		let (active_era, start) = pallet_staking::Pallet::<Runtime>::active_era()
+			.map(|era| (era.index, era.start.unwrap_or_default()))
+			.unwrap_or_default();
+		Ok((current_era, active_era, start))
+	}
+
+	fn dispatch(handle: &mut impl PrecompileHandle, call: Runtime::RuntimeCall) -> EvmResult {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(())
+	}
+
+	/// Rewards are restaked when `payee` is the zero address, paid to the stash when it is the
+	/// caller itself, and paid to `payee` otherwise.
+	fn reward_destination(caller: H160, payee: Address) -> RewardDestination<Runtime::AccountId> {
+		match payee.0 {
+			payee if payee.is_zero() => RewardDestination::Staked,
+			payee if payee == caller => RewardDestination::Stash,
+			payee => RewardDestination::Account(Runtime::AddressMapping::into_account_id(payee)),
+		}
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+}
@@ -0,0 +1,475 @@
+use super::*;
+
+use frame_election_provider_support::{
+	bounds::{ElectionBounds, ElectionBoundsBuilder},
+	onchain, SequentialPhragmen,
+};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64, Everything, FindAuthor, OneSessionHandler},
+	weights::Weight,
+	ConsensusEngineId, PalletId,
+};
+use pallet_evm::{
+	EnsureAddressNever, EnsureAddressRoot, IdentityAddressMapping, IsPrecompileResult, Precompile,
+	PrecompileHandle, PrecompileResult, PrecompileSet,
+};
+use pallet_session::historical as pallet_session_historical;
+use precompile_utils::{mock_account, testing::MockAccount};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+	testing::UintAuthorityId,
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill,
+};
+use sp_staking::StakerStatus;
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Evm: pallet_evm,
+		Timestamp: pallet_timestamp,
+		Authorship: pallet_authorship,
+		Staking: pallet_staking,
+		Session: pallet_session,
+		Historical: pallet_session_historical,
+		Offences: pallet_offences,
+		Esg: pallet_esg,
+		Reward: pallet_reward,
+		ImOnline: pallet_im_online,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u32 = 250;
+	pub const MaximumBlockWeight: Weight = Weight::from_parts(1024, 1);
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Block = Block;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+}
+impl pallet_balances::Config for Runtime {
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 4];
+	type MaxLocks = ConstU32<50>;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type RuntimeHoldReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ();
+}
+
+pub struct MockPrecompileSet<Runtime>(PhantomData<Runtime>);
+
+impl<R> PrecompileSet for MockPrecompileSet<R>
+where
+	StakingPrecompile<R>: Precompile,
+{
+	/// Tries to execute a precompile in the precompile set.
+	/// If the provided address is not a precompile, returns None.
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		let address = handle.code_address();
+
+		if address == Precompile1.into() {
+			return Some(StakingPrecompile::<R>::execute(handle));
+		}
+
+		None
+	}
+
+	/// Check if the given address is a precompile. Should only be called to
+	/// perform the check while not executing the precompile afterward, since
+	/// `execute` already performs a check internally.
+	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer { is_precompile: address == Precompile1.into(), extra_cost: 0 }
+	}
+}
+
+pub type PCall = StakingPrecompileCall<Runtime>;
+
+mock_account!(Precompile1, |_| MockAccount::from_u64(1));
+
+const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u64::MAX);
+	pub PrecompilesValue: MockPrecompileSet<Runtime> = MockPrecompileSet(PhantomData);
+
+	pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+	pub GasLimitPovSizeRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(MAX_POV_SIZE)
+	};
+	pub GasLimitStorageGrowthRatio: u64 = {
+		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+	};
+}
+
+impl pallet_evm::Config for Runtime {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = IdentityAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = MockPrecompileSet<Runtime>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ();
+	type OnChargeTransaction = ();
+	type BlockGasLimit = BlockGasLimit;
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type Author = ();
+	type OnCreate = ();
+	type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+	type Timestamp = Timestamp;
+	type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 5;
+}
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+mock_account!(Alice, |_| H160::repeat_byte(0xAA).into());
+mock_account!(Bob, |_| H160::repeat_byte(0xBB).into());
+mock_account!(Charlie, |_| H160::repeat_byte(0xCC).into());
+
+pub struct FindAuthorTruncated;
+impl FindAuthor<MockAccount> for FindAuthorTruncated {
+	fn find_author<'a, I>(_digests: I) -> Option<MockAccount>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		Some(Alice.into())
+	}
+}
+
+impl pallet_authorship::Config for Runtime {
+	type FindAuthor = FindAuthorTruncated;
+	type EventHandler = ();
+}
+
+sp_runtime::impl_opaque_keys! {
+	pub struct SessionKeys {
+		pub other: OtherSessionHandler,
+	}
+}
+
+pub struct OtherSessionHandler;
+impl OneSessionHandler<AccountId> for OtherSessionHandler {
+	type Key = UintAuthorityId;
+
+	fn on_genesis_session<'a, I: 'a>(_: I)
+	where
+		I: Iterator<Item = (&'a AccountId, Self::Key)>,
+		AccountId: 'a,
+	{
+	}
+
+	fn on_new_session<'a, I: 'a>(_: bool, _: I, _: I)
+	where
+		I: Iterator<Item = (&'a AccountId, Self::Key)>,
+		AccountId: 'a,
+	{
+	}
+
+	fn on_disabled(_validator_index: u32) {}
+}
+
+impl sp_runtime::BoundToRuntimeAppPublic for OtherSessionHandler {
+	type Public = UintAuthorityId;
+}
+
+parameter_types! {
+	pub const Period: u32 = 1;
+	pub const Offset: u32 = 0;
+	pub MaxOnChainElectableTargets: u16 = 1250;
+}
+
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = pallet_staking::Exposure<AccountId, Balance>;
+	type FullIdentificationOf = pallet_staking::ExposureOf<Self>;
+}
+
+impl pallet_session::Config for Runtime {
+	type WeightInfo = ();
+	type DataProvider = Staking;
+	type Keys = SessionKeys;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = pallet_staking::StashOf<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type SessionHandler = (OtherSessionHandler,);
+	type AllSessionHandler = (ImOnline,);
+	type TargetsBound = MaxOnChainElectableTargets;
+	type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Runtime, Staking>;
+}
+
+parameter_types! {
+	pub const SessionsPerEra: sp_staking::SessionIndex = 2;
+	pub const BondingDuration: sp_staking::EraIndex = 28;
+	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(40);
+	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+	type System = Runtime;
+	type Solver = SequentialPhragmen<AccountId, Perbill>;
+	type DataProvider = Staking;
+	type WeightInfo = ();
+	type MaxWinners = ConstU32<100>;
+	type Bounds = ElectionsBounds;
+}
+
+impl pallet_staking::Config for Runtime {
+	type RewardRemainder = ();
+	type RewardDistribution = Reward;
+	type CurrencyToVote = ();
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CurrencyBalance = Balance;
+	type Slash = ();
+	type Reward = ();
+	type SessionsPerEra = SessionsPerEra;
+	type BondingDuration = BondingDuration;
+	type SlashDeferDuration = ();
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type SessionInterface = Self;
+	type UnixTime = Timestamp;
+	type EraPayout = ();
+	type MaxNominatorRewardedPerValidator = ConstU32<64>;
+	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
+	type NextNewSession = Session;
+	type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+	type GenesisElectionProvider = Self::ElectionProvider;
+	type VoterList = pallet_staking::UseNominatorsAndValidatorsMap<Self>;
+	type TargetList = pallet_staking::UseValidatorsMap<Self>;
+	type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+	type MaxUnlockingChunks = ConstU32<32>;
+	type HistoryDepth = ConstU32<84>;
+	type EventListeners = ();
+	type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+	type WeightInfo = ();
+	type ESG = Esg;
+	type Reliability = ImOnline;
+}
+
+impl pallet_offences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
+	type OnOffenceHandler = Staking;
+}
+
+parameter_types! {
+	pub const RewardPalletId: PalletId = PalletId(*b"py/rewrd");
+	pub RewardRemainderAccount: AccountId = MockAccount::from_u64(99);
+}
+
+impl pallet_reward::Config for Runtime {
+	type RewardCurrency = Balances;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorSet = Historical;
+	type Validators = Historical;
+	type ValidatorId = pallet_staking::StashOf<Runtime>;
+	type Precision = ConstU32<18>;
+	type TotalMinutesPerYear = ConstU32<525600>;
+	type EraMinutes = ConstU32<2>;
+	type TotalReward = ConstU32<20564830>;
+	type RewardRemainderAccount = RewardRemainderAccount;
+	type RewardPageSize = ConstU32<1>;
+	type EmissionOrigin = frame_system::EnsureRoot<AccountId>;
+	type PalletId = RewardPalletId;
+	type WeightInfo = ();
+}
+
+impl<T> frame_system::offchain::SendTransactionTypes<T> for Runtime
+where
+	RuntimeCall: From<T>,
+{
+	type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+	type OverarchingCall = RuntimeCall;
+}
+
+parameter_types! {
+	pub const UptimeSmoothing: Perbill = Perbill::from_percent(10);
+	pub const MissedSessionPenalty: Perbill = Perbill::from_percent(25);
+	pub const AuthoringWeight: Perbill = Perbill::from_percent(50);
+}
+
+impl pallet_im_online::Config for Runtime {
+	type AuthorityId = UintAuthorityId;
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorSet = Historical;
+	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+	type ReportUnresponsiveness = Offences;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type WeightInfo = ();
+	type MaxKeys = ConstU32<10_000>;
+	type MaxPeerInHeartbeats = ConstU32<10_000>;
+	type DataProvider = Staking;
+	type TargetsBound = MaxOnChainElectableTargets;
+	type UptimeSmoothing = UptimeSmoothing;
+	type MissedSessionPenalty = MissedSessionPenalty;
+	type AuthoringWeight = AuthoringWeight;
+	type RecoverySessions = ConstU32<4>;
+	type MaxReliabilityHistory = ConstU32<4>;
+	type MaxWaitingScoresPerBlock = ConstU32<100>;
+}
+
+parameter_types! {
+	pub const EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 1, social: 1, governance: 1 };
+}
+
+impl pallet_esg::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxFileSize = ConstU32<1024000>;
+	type MaxNumOfSudoOracles = ConstU32<5>;
+	type MaxNumOfNonSudoOracles = ConstU32<5>;
+	type MaxScoreHistory = ConstU32<16>;
+	type MaxBatch = ConstU32<100>;
+	type SubmissionQuorum = ConstU32<2>;
+	type OutlierThreshold = ConstU16<20>;
+	type ScoreValidity = ConstU64<1000>;
+	type ScoreDecayPeriod = ConstU64<100>;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
+	type Currency = Balances;
+	type OracleDeposit = ConstU128<100>;
+	type OracleTerm = ConstU64<1000>;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type Slash = ();
+	type DisputeDeposit = ConstU128<100>;
+	type DisputeResolveOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub(crate) struct ExtBuilder {
+	// endowed accounts with balances
+	balances: Vec<(AccountId, Balance)>,
+	// accounts bonded and validating at genesis
+	validators: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> ExtBuilder {
+		ExtBuilder { balances: vec![], validators: vec![] }
+	}
+}
+
+impl ExtBuilder {
+	pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub(crate) fn with_validators(mut self, validators: Vec<(AccountId, Balance)>) -> Self {
+		self.validators = validators;
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.expect("Frame system builds valid default genesis config");
+
+		pallet_balances::GenesisConfig::<Runtime> { balances: self.balances }
+			.assimilate_storage(&mut t)
+			.expect("Pallet balances storage can be assimilated");
+
+		pallet_staking::GenesisConfig::<Runtime> {
+			stakers: self
+				.validators
+				.iter()
+				.map(|(validator, bond)| {
+					(
+						validator.clone(),
+						validator.clone(),
+						*bond,
+						StakerStatus::<AccountId>::Validator,
+					)
+				})
+				.collect(),
+			validator_count: self.validators.len() as u32,
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.expect("Pallet staking storage can be assimilated");
+
+		pallet_session::GenesisConfig::<Runtime> {
+			keys: self
+				.validators
+				.iter()
+				.enumerate()
+				.map(|(index, (validator, _))| {
+					(
+						validator.clone(),
+						validator.clone(),
+						SessionKeys { other: UintAuthorityId(index as u64) },
+					)
+				})
+				.collect(),
+		}
+		.assimilate_storage(&mut t)
+		.expect("Pallet session storage can be assimilated");
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
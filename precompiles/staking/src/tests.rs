@@ -0,0 +1,292 @@
+use crate::mock::{
+	AccountId, Alice, Bob, Charlie, ExtBuilder, MockPrecompileSet, PCall, Precompile1,
+	PrecompilesValue, Runtime, RuntimeOrigin, Staking,
+};
+use frame_support::{assert_ok, BoundedVec};
+use pallet_staking::{ActiveEraInfo, RewardDestination};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+fn precompiles() -> MockPrecompileSet<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 10_000), (Bob.into(), 10_000), (Charlie.into(), 10_000)])
+		.with_validators(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.build()
+}
+
+fn set_unclaimed_eras(who: AccountId, eras: Vec<u32>) {
+	pallet_reward::UnclaimedEras::<Runtime>::insert(who, BoundedVec::truncate_from(eras));
+}
+
+fn ledger(total: u128, active: u128, unlocking: u128) -> (U256, U256, U256) {
+	(total.into(), active.into(), unlocking.into())
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::bond_selectors().contains(&0xb78d27dc));
+	assert!(PCall::bond_extra_selectors().contains(&0xeaca88de));
+	assert!(PCall::unbond_selectors().contains(&0x27de9e32));
+	assert!(PCall::withdraw_unbonded_selectors().contains(&0x548a6706));
+	assert!(PCall::nominate_selectors().contains(&0x19f2fdad));
+	assert!(PCall::chill_selectors().contains(&0x2b8a3ae6));
+	assert!(PCall::set_payee_selectors().contains(&0x410459ad));
+	assert!(PCall::get_rewards_selectors().contains(&0x79ee54f7));
+	assert!(PCall::ledger_selectors().contains(&0xfbfa941f));
+	assert!(PCall::nominations_selectors().contains(&0xf95baf26));
+	assert!(PCall::pending_rewards_selectors().contains(&0x31d7a262));
+	assert!(PCall::era_info_selectors().contains(&0xf6899aa8));
+}
+
+#[test]
+fn modifiers() {
+	ext().execute_with(|| {
+		let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+		tester.test_default_modifier(PCall::bond_selectors());
+		tester.test_default_modifier(PCall::bond_extra_selectors());
+		tester.test_default_modifier(PCall::unbond_selectors());
+		tester.test_default_modifier(PCall::withdraw_unbonded_selectors());
+		tester.test_default_modifier(PCall::nominate_selectors());
+		tester.test_default_modifier(PCall::chill_selectors());
+		tester.test_default_modifier(PCall::set_payee_selectors());
+		tester.test_default_modifier(PCall::get_rewards_selectors());
+		tester.test_view_modifier(PCall::ledger_selectors());
+		tester.test_view_modifier(PCall::nominations_selectors());
+		tester.test_view_modifier(PCall::pending_rewards_selectors());
+		tester.test_view_modifier(PCall::era_info_selectors());
+	});
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+	check_precompile_implements_solidity_interfaces(
+		&["src/StakingInterface.sol"],
+		PCall::supports_selector,
+	)
+}
+
+#[test]
+fn bond_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::bond { value: 500.into(), payee: Address(H160::zero()) },
+			)
+			.execute_returns(());
+
+		assert_eq!(Staking::bonded(AccountId::from(Charlie)), Some(Charlie.into()));
+		assert_eq!(Staking::payee(AccountId::from(Charlie)), RewardDestination::Staked);
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ledger { stash: Address(Charlie.into()) })
+			.expect_no_logs()
+			.execute_returns(ledger(500, 500, 0));
+	});
+}
+
+#[test]
+fn bond_rejects_values_above_the_balance_type() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::bond { value: U256::MAX, payee: Address(H160::zero()) },
+			)
+			.execute_reverts(|output| output == b"value: Value is too large for balance type");
+	});
+}
+
+#[test]
+fn bond_extra_and_unbond_update_the_ledger() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::bond_extra { value: 100.into() })
+			.execute_returns(());
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::unbond { value: 300.into() })
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ledger { stash: Address(Alice.into()) })
+			.execute_returns(ledger(1_100, 800, 300));
+	});
+}
+
+#[test]
+fn withdraw_unbonded_releases_unlocked_chunks() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::unbond { value: 300.into() })
+			.execute_returns(());
+		pallet_staking::CurrentEra::<Runtime>::put(100);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::withdraw_unbonded { num_slashing_spans: 0 })
+			.execute_returns(());
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ledger { stash: Address(Alice.into()) })
+			.execute_returns(ledger(700, 700, 0));
+	});
+}
+
+#[test]
+fn ledger_of_unbonded_account_is_empty() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::ledger { stash: Address(Charlie.into()) })
+			.execute_returns(ledger(0, 0, 0));
+	});
+}
+
+#[test]
+fn nominate_and_chill_work() {
+	ext().execute_with(|| {
+		assert_ok!(Staking::bond(
+			RuntimeOrigin::signed(Charlie.into()),
+			500,
+			RewardDestination::Staked
+		));
+
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::nominate {
+					targets: vec![Address(Alice.into()), Address(Bob.into())].into(),
+				},
+			)
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::nominations { nominator: Address(Charlie.into()) },
+			)
+			.expect_no_logs()
+			.execute_returns(vec![Address(Alice.into()), Address(Bob.into())]);
+
+		precompiles()
+			.prepare_test(Charlie, Precompile1, PCall::chill {})
+			.execute_returns(());
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::nominations { nominator: Address(Charlie.into()) },
+			)
+			.execute_returns(Vec::<Address>::new());
+	});
+}
+
+#[test]
+fn nominate_requires_a_bond() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				Charlie,
+				Precompile1,
+				PCall::nominate { targets: vec![Address(Alice.into())].into() },
+			)
+			.execute_reverts(|output| {
+				core::str::from_utf8(output).unwrap().contains("NotController")
+			});
+	});
+}
+
+#[test]
+fn set_payee_works() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::set_payee { payee: Address(Charlie.into()) })
+			.execute_returns(());
+		assert_eq!(
+			Staking::payee(AccountId::from(Alice)),
+			RewardDestination::Account(Charlie.into())
+		);
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::set_payee { payee: Address(Alice.into()) })
+			.execute_returns(());
+		assert_eq!(Staking::payee(AccountId::from(Alice)), RewardDestination::Stash);
+	});
+}
+
+#[test]
+fn get_rewards_queues_the_validator_payout() {
+	ext().execute_with(|| {
+		set_unclaimed_eras(Alice.into(), vec![0]);
+
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::get_rewards { validator: Address(Alice.into()) })
+			.execute_returns(());
+
+		assert_eq!(pallet_reward::EraRewardsVault::<Runtime>::get(), Some(vec![Alice.into()]));
+	});
+}
+
+#[test]
+fn get_rewards_requires_unclaimed_rewards() {
+	ext().execute_with(|| {
+		precompiles()
+			.prepare_test(Bob, Precompile1, PCall::get_rewards { validator: Address(Alice.into()) })
+			.execute_reverts(|output| core::str::from_utf8(output).unwrap().contains("NoReward"));
+	});
+}
+
+#[test]
+fn pending_rewards_adds_validator_and_nominator_rewards() {
+	ext().execute_with(|| {
+		assert_ok!(Staking::bond(
+			RuntimeOrigin::signed(Charlie.into()),
+			500,
+			RewardDestination::Staked
+		));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(Charlie.into()), vec![Bob.into()]));
+		// Charlie is owed rewards both as a validator and as a nominator of Bob.
+		set_unclaimed_eras(Charlie.into(), vec![0, 1]);
+		pallet_reward::EraValidatorRewards::<Runtime>::insert(0, AccountId::from(Charlie), 30);
+		pallet_reward::EraValidatorRewards::<Runtime>::insert(1, AccountId::from(Charlie), 20);
+		set_unclaimed_eras(Bob.into(), vec![1]);
+		pallet_reward::EraNominatorRewards::<Runtime>::insert(
+			(1, AccountId::from(Bob), AccountId::from(Charlie)),
+			7,
+		);
+		pallet_reward::NominatorRewardEras::<Runtime>::insert(
+			(AccountId::from(Charlie), 1, AccountId::from(Bob)),
+			(),
+		);
+
+		precompiles()
+			.prepare_test(
+				Alice,
+				Precompile1,
+				PCall::pending_rewards { who: Address(Charlie.into()) },
+			)
+			.expect_no_logs()
+			.execute_returns(U256::from(57));
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::pending_rewards { who: Address(Alice.into()) })
+			.execute_returns(U256::zero());
+	});
+}
+
+#[test]
+fn era_info_works() {
+	ext().execute_with(|| {
+		pallet_staking::CurrentEra::<Runtime>::put(4);
+		pallet_staking::ActiveEra::<Runtime>::put(ActiveEraInfo { index: 3, start: Some(1_000) });
+
+		precompiles()
+			.prepare_test(Alice, Precompile1, PCall::era_info {})
+			.expect_no_logs()
+			.execute_returns((4u32, 3u32, 1_000u64));
+	});
+}
@@ -79,7 +79,7 @@ use pallet_ethereum::{
 };
 use pallet_evm::{Account as EVMAccount, FeeCalculator, Runner};
 mod precompiles;
-use precompiles::FrontierPrecompiles;
+pub use precompiles::{FirePrecompiles, PrecompileName};
 
 use frame_election_provider_support::bounds::ElectionBoundsBuilder;
 #[cfg(any(feature = "std", test))]
@@ -597,6 +597,7 @@ parameter_types! {
 	pub const DecimalPrecision:u32 = 18;
 	pub const TotalMinutesPerYear:u32 = 525600;
 	pub const TotalReward :u32 = 20564830;
+	pub RewardRemainderAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
 impl pallet_reward::Config for Runtime {
@@ -610,6 +611,9 @@ impl pallet_reward::Config for Runtime {
 	type TotalMinutesPerYear = TotalMinutesPerYear;
 	type EraMinutes = EraMinutes;
 	type TotalReward = TotalReward;
+	type RewardRemainderAccount = RewardRemainderAccount;
+	type RewardPageSize = ConstU32<16>;
+	type EmissionOrigin = EnsureRootOrHalfCouncil;
 	type PalletId = RewardPalletId;
 	type WeightInfo = pallet_reward::weights::SubstrateWeightInfo<Runtime>;
 }
@@ -1245,6 +1249,12 @@ parameter_types! {
 	/// We prioritize im-online heartbeats over election solution submission.
 	pub const StakingUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
 	pub const MaxAuthorities: u32 = 100;
+	pub const UptimeSmoothing: Perbill = Perbill::from_percent(10);
+	pub const MissedSessionPenalty: Perbill = Perbill::from_percent(25);
+	pub const AuthoringWeight: Perbill = Perbill::from_percent(50);
+	pub const RecoverySessions: u32 = 6;
+	pub const MaxReliabilityHistory: u32 = 100;
+	pub const MaxWaitingScoresPerBlock: u32 = 64;
 	pub const MaxKeys: u32 = 10_000;
 	pub const MaxPeerInHeartbeats: u32 = 10_000;
 }
@@ -1316,6 +1326,12 @@ impl pallet_im_online::Config for Runtime {
 	type MaxPeerInHeartbeats = MaxPeerInHeartbeats;
 	type DataProvider = Staking;
 	type TargetsBound = MaxOnChainElectableTargets;
+	type UptimeSmoothing = UptimeSmoothing;
+	type MissedSessionPenalty = MissedSessionPenalty;
+	type AuthoringWeight = AuthoringWeight;
+	type RecoverySessions = RecoverySessions;
+	type MaxReliabilityHistory = MaxReliabilityHistory;
+	type MaxWaitingScoresPerBlock = MaxWaitingScoresPerBlock;
 }
 
 impl pallet_offences::Config for Runtime {
@@ -1435,7 +1451,8 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type StringLimit = StringLimit;
 	type Freezer = ();
 	type Extra = ();
-	type CallbackHandle = ();
+	type CallbackHandle =
+		precompiles::AssetPrecompileDummyCode<precompiles::AssetsPrefix, Instance1>;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1464,7 +1481,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
-	type CallbackHandle = ();
+	type CallbackHandle =
+		precompiles::AssetPrecompileDummyCode<precompiles::PoolAssetsPrefix, Instance2>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -1592,9 +1610,23 @@ impl frame_benchmarking_pallet_pov::Config for Runtime {
 }
 
 parameter_types! {
-	pub const MaxFileSize: u32 = 1024000;
+	// a JSON upload of `EsgMaxBatch` entries and this length must fit in a block
+	pub const MaxFileSize: u32 = 512 * 1024;
 	pub const MaxNumOfSudoOracles: u32 = 5;
 	pub const MaxNumOfNonSudoOracles: u32 = 100;
+	pub const MaxScoreHistory: u32 = 32;
+	pub const EsgMaxBatch: u32 = 1000;
+	pub const EsgSubmissionQuorum: u32 = 3;
+	pub const EsgOutlierThreshold: u16 = 15;
+	pub const EsgOracleDeposit: Balance = 1000 * DOLLARS;
+	pub const EsgOracleTerm: BlockNumber = 180 * DAYS;
+	pub const EsgDisputeDeposit: Balance = 100 * DOLLARS;
+	pub const EsgScoreValidity: BlockNumber = 365 * DAYS;
+	pub const EsgScoreDecayPeriod: BlockNumber = 90 * DAYS;
+	pub const EsgScoreAveraging: pallet_esg::ScoreAveraging =
+		pallet_esg::ScoreAveraging::TimeWeighted;
+	pub const EsgPillarWeights: pallet_esg::PillarWeights =
+		pallet_esg::PillarWeights { environmental: 1, social: 1, governance: 1 };
 }
 
 impl pallet_esg::Config for Runtime {
@@ -1602,6 +1634,21 @@ impl pallet_esg::Config for Runtime {
 	type MaxFileSize = MaxFileSize;
 	type MaxNumOfSudoOracles = MaxNumOfSudoOracles;
 	type MaxNumOfNonSudoOracles = MaxNumOfNonSudoOracles;
+	type MaxScoreHistory = MaxScoreHistory;
+	type MaxBatch = EsgMaxBatch;
+	type SubmissionQuorum = EsgSubmissionQuorum;
+	type OutlierThreshold = EsgOutlierThreshold;
+	type ScoreValidity = EsgScoreValidity;
+	type ScoreDecayPeriod = EsgScoreDecayPeriod;
+	type Currency = Balances;
+	type OracleDeposit = EsgOracleDeposit;
+	type OracleTerm = EsgOracleTerm;
+	type SlashOrigin = EnsureRootOrHalfCouncil;
+	type Slash = Treasury;
+	type DisputeDeposit = EsgDisputeDeposit;
+	type DisputeResolveOrigin = EnsureRootOrHalfCouncil;
+	type ScoreAveraging = EsgScoreAveraging;
+	type PillarWeights = EsgPillarWeights;
 	type WeightInfo = pallet_esg::weights::SubstrateWeightInfo<Runtime>;
 }
 pub struct FindAuthorTruncated<F>(PhantomData<F>);
@@ -1634,7 +1681,7 @@ parameter_types! {
 	pub const ChainId: u64 = 995;
 	pub BlockGasLimit: U256 = U256::from(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT.ref_time()/ WEIGHT_PER_GAS);
 	pub const GasLimitPovSizeRatio: u64 = 4;
-	pub PrecompilesValue: FrontierPrecompiles<Runtime> = FrontierPrecompiles::<_>::new();
+	pub PrecompilesValue: FirePrecompiles<Runtime> = FirePrecompiles::<_>::new();
 	pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
 }
 
@@ -1680,7 +1727,7 @@ impl pallet_evm::Config for Runtime {
 	type AddressMapping = pallet_evm::IdentityAddressMapping;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
-	type PrecompilesType = FrontierPrecompiles<Self>;
+	type PrecompilesType = FirePrecompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
 	type ChainId = ChainId;
 	type BlockGasLimit = BlockGasLimit;
@@ -1956,6 +2003,64 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_staking_runtime_api::ValidatorScoresApi<Block, AccountId> for Runtime {
+		fn validator_scores() -> Vec<pallet_staking_runtime_api::ValidatorScores<AccountId>> {
+			Staking::api_validator_scores()
+		}
+	}
+
+	impl pallet_reward_rpc_runtime_api::RewardApi<Block, AccountId, Balance> for Runtime {
+		fn pending_validator_rewards(validator: AccountId) -> Balance {
+			Reward::pending_validator_rewards(validator)
+		}
+
+		fn pending_nominator_rewards(nominator: AccountId) -> Balance {
+			Reward::pending_nominator_rewards(nominator)
+		}
+
+		fn lifetime_rewards(who: AccountId) -> Balance {
+			Reward::total_rewards(who)
+		}
+
+		fn projected_era_reward(who: AccountId) -> Balance {
+			Reward::projected_era_reward(who)
+		}
+	}
+
+	impl pallet_esg_runtime_api::EsgApi<Block, AccountId, BlockNumber> for Runtime {
+		fn score_history(
+			account: AccountId,
+		) -> Vec<pallet_esg_runtime_api::ScoreRecord<AccountId, BlockNumber>> {
+			EsgScore::score_history_of(account).into_inner()
+		}
+
+		fn effective_score(account: AccountId) -> u16 {
+			EsgScore::effective_score_of(account)
+		}
+
+		fn score_breakdown(account: AccountId) -> Option<pallet_esg_runtime_api::EsgBreakdown> {
+			EsgScore::breakdown_of(account)
+		}
+	}
+
+	impl pallet_im_online_runtime_api::ReliabilityApi<Block, AccountId> for Runtime {
+		fn reliability_history(
+			account: AccountId,
+		) -> Vec<pallet_im_online_runtime_api::SessionRecord> {
+			ImOnline::reliability_history_of(account).into_inner()
+		}
+
+		fn reliability_score(account: AccountId) -> u16 {
+			pallet_im_online::ReliabilityScoresMap::<Runtime>::get(account)
+		}
+
+		fn reliability_state(
+			account: AccountId,
+		) -> Option<pallet_im_online_runtime_api::ReliabilityState> {
+			ImOnline::reliability_state_of(account)
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -2543,7 +2648,12 @@ pub mod migrations {
 	use super::*;
 
 	/// Unreleased migrations. Add new ones here:
-	pub type Unreleased = ();
+	pub type Unreleased = (
+		pallet_reward::migration::v2::MigrateToV2<Runtime>,
+		pallet_esg::migration::v1::MigrateToV1<Runtime>,
+		pallet_esg::migration::v2::MigrateToV2<Runtime>,
+		precompiles::WritePrecompilesDummyCode,
+	);
 }
 
 /// Executive: handles dispatch to the various modules.
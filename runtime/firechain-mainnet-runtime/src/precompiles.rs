@@ -1,54 +1,273 @@
-use pallet_evm::{
-	IsPrecompileResult, Precompile, PrecompileHandle, PrecompileResult, PrecompileSet,
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+use frame_support::{
+	parameter_types,
+	traits::{fungibles::InspectEnumerable, Get, OnRuntimeUpgrade},
+	weights::Weight,
 };
-use sp_core::H160;
-use sp_std::marker::PhantomData;
-
+use pallet_assets::{AssetsCallback, Instance1, Instance2};
+use pallet_evm::IsPrecompileResult;
+use pallet_evm_precompile_batch::BatchPrecompile;
+use pallet_evm_precompile_blake2::Blake2F;
+use pallet_evm_precompile_bls12377::{
+	Bls12377G1Add, Bls12377G1Mul, Bls12377G1MultiExp, Bls12377G2Add, Bls12377G2Mul,
+	Bls12377G2MultiExp, Bls12377Pairing,
+};
+use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+use pallet_evm_precompile_bw6761::{
+	Bw6761G1Add, Bw6761G1Mul, Bw6761G1MultiExp, Bw6761G2Add, Bw6761G2Mul, Bw6761G2MultiExp,
+	Bw6761Pairing,
+};
+use pallet_evm_precompile_collective::CollectivePrecompile;
+use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
+use pallet_evm_precompile_curve25519::{Curve25519Add, Curve25519ScalarMul};
+use pallet_evm_precompile_democracy::DemocracyPrecompile;
+use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_erc20::{
+	Erc20AssetsPrecompileSet, Erc20BalancesPrecompile, Erc20Metadata,
+};
+use pallet_evm_precompile_esg::EsgPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_registry::PrecompileRegistry;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_staking::StakingPrecompile;
+use precompile_utils::precompile_set::*;
 
-pub struct FrontierPrecompiles<R>(PhantomData<R>);
+use crate::{AccountId, Runtime};
+use core::marker::PhantomData;
+use sp_core::H160;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
 
-#[allow(clippy::new_without_default)]
-impl<R> FrontierPrecompiles<R>
-where
-	R: pallet_evm::Config,
-{
-	pub fn new() -> Self {
-		Self(Default::default())
+type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
+
+pub struct NativeErc20Metadata;
+
+impl Erc20Metadata for NativeErc20Metadata {
+	fn name() -> &'static str {
+		"5ire"
 	}
-	pub fn used_addresses() -> [H160; 7] {
-		[hash(1), hash(2), hash(3), hash(4), hash(5), hash(1024), hash(1025)]
+
+	fn symbol() -> &'static str {
+		"5IRE"
+	}
+
+	fn decimals() -> u8 {
+		18
 	}
 }
-impl<R> PrecompileSet for FrontierPrecompiles<R>
-where
-	R: pallet_evm::Config,
-{
-	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
-		match handle.code_address() {
-			// Ethereum precompiles :
-			a if a == hash(1) => Some(ECRecover::execute(handle)),
-			a if a == hash(2) => Some(Sha256::execute(handle)),
-			a if a == hash(3) => Some(Ripemd160::execute(handle)),
-			a if a == hash(4) => Some(Identity::execute(handle)),
-			a if a == hash(5) => Some(Modexp::execute(handle)),
-			// Non-Frontier specific nor Ethereum precompiles :
-			a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
-			a if a == hash(1025) => Some(ECRecoverPublicKey::execute(handle)),
-			_ => None,
+
+parameter_types! {
+	/// Address prefix of the ERC-20 precompiles of `Assets`.
+	pub AssetsPrefix: &'static [u8] = &[255u8; 4];
+	/// Address prefix of the ERC-20 precompiles of `PoolAssets`.
+	pub PoolAssetsPrefix: &'static [u8] = &[255u8, 255u8, 255u8, 254u8];
+}
+
+#[precompile_utils::precompile_name_from_address]
+type FirePrecompilesAt<R> = (
+	// Ethereum precompiles:
+	// We allow DELEGATECALL to stay compliant with Ethereum behavior.
+	PrecompileAt<AddressU64<1>, ECRecover, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<2>, Sha256, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<3>, Ripemd160, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<4>, Identity, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<5>, Modexp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<6>, Bn128Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<7>, Bn128Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<8>, Bn128Pairing, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<9>, Blake2F, EthereumPrecompilesChecks>,
+	// Non-Ethereum cryptographic precompiles:
+	PrecompileAt<AddressU64<1024>, Sha3FIPS256, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1025>, ECRecoverPublicKey, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1026>, Ed25519Verify, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1027>, Curve25519Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1028>, Curve25519ScalarMul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1029>, Bls12377G1Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1030>, Bls12377G1Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1031>, Bls12377G1MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1032>, Bls12377G2Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1033>, Bls12377G2Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1034>, Bls12377G2MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1035>, Bls12377Pairing, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1036>, Bw6761G1Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1037>, Bw6761G1Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1038>, Bw6761G1MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1039>, Bw6761G2Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1040>, Bw6761G2Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1041>, Bw6761G2MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1042>, Bw6761Pairing, EthereumPrecompilesChecks>,
+	PrecompileAt<
+		AddressU64<4096>,
+		BatchPrecompile<R>,
+		(
+			SubcallWithMaxNesting<2>,
+			// Batch is the only precompile allowed to call Batch.
+			CallableByPrecompile<OnlyFrom<AddressU64<4096>>>,
+		),
+	>,
+	PrecompileAt<
+		AddressU64<4097>,
+		PrecompileRegistry<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<AddressU64<4098>, EsgPrecompile<R>, (CallableByContract, CallableByPrecompile)>,
+	PrecompileAt<
+		AddressU64<4099>,
+		StakingPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4100>,
+		Erc20BalancesPrecompile<R, NativeErc20Metadata>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4101>,
+		CollectivePrecompile<R, crate::CouncilCollective>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4102>,
+		CollectivePrecompile<R, crate::TechnicalCollective>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4103>,
+		ConvictionVotingPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4104>,
+		DemocracyPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+);
+
+pub type FirePrecompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		// Skip precompiles if out of range.
+		PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<4200>), FirePrecompilesAt<R>>,
+		PrecompileSetStartingWith<
+			AssetsPrefix,
+			Erc20AssetsPrecompileSet<R, AssetsPrefix, Instance1>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+		PrecompileSetStartingWith<
+			PoolAssetsPrefix,
+			Erc20AssetsPrecompileSet<R, PoolAssetsPrefix, Instance2>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+	),
+>;
+
+/// Code stored at the address of each precompile, so that contracts checking the code size of
+/// an address see precompiles as contracts. It reverts if it is ever executed.
+const DUMMY_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xfd];
+
+/// Writes [`DUMMY_CODE`] at `address` if it has no code yet. Returns whether it was written.
+fn write_dummy_code(address: H160) -> bool {
+	if pallet_evm::AccountCodes::<Runtime>::contains_key(address) {
+		return false
+	}
+
+	// `create_account` bumps the sufficients of the account and writes its code and code
+	// metadata.
+	pallet_evm::Pallet::<Runtime>::create_account(address, DUMMY_CODE.to_vec());
+	log::info!(
+		target: "runtime::precompiles",
+		"Wrote dummy code at precompile address {:?}",
+		address
+	);
+	true
+}
+
+/// Addresses of the ERC-20 precompiles of the assets that exist in `Assets` and `PoolAssets`.
+fn asset_precompile_addresses() -> impl Iterator<Item = H160> {
+	pallet_assets::Pallet::<Runtime, Instance1>::asset_ids()
+		.map(Erc20AssetsPrecompileSet::<Runtime, AssetsPrefix, Instance1>::asset_id_to_address)
+		.chain(pallet_assets::Pallet::<Runtime, Instance2>::asset_ids().map(
+			Erc20AssetsPrecompileSet::<Runtime, PoolAssetsPrefix, Instance2>::asset_id_to_address,
+		))
+}
+
+/// Writes [`DUMMY_CODE`] at the address of each active precompile of [`FirePrecompiles`] that has
+/// no code yet, such as the precompiles activated when mainnet moved to [`FirePrecompiles`],
+/// including the ERC-20 precompiles of the assets that exist at the upgrade. The precompiles of
+/// the assets created afterwards get their code from [`AssetPrecompileDummyCode`].
+pub struct WritePrecompilesDummyCode;
+
+impl OnRuntimeUpgrade for WritePrecompilesDummyCode {
+	fn on_runtime_upgrade() -> Weight {
+		let precompiles = FirePrecompiles::<Runtime>::new();
+		let (mut reads, mut writes) = (0u64, 0u64);
+
+		for address in FirePrecompiles::<Runtime>::used_addresses().map(Into::into) {
+			let is_active = matches!(
+				precompiles.is_active_precompile(address, u64::MAX),
+				IsPrecompileResult::Answer { is_precompile: true, .. }
+			);
+			reads += 1;
+			if is_active && write_dummy_code(address) {
+				reads += 1;
+				writes += 3;
+			}
 		}
+
+		for address in asset_precompile_addresses() {
+			reads += 2;
+			if write_dummy_code(address) {
+				reads += 1;
+				writes += 3;
+			}
+		}
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
 	}
 
-	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
-		IsPrecompileResult::Answer {
-			is_precompile: Self::used_addresses().contains(&address),
-			extra_cost: 0,
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let precompiles = FirePrecompiles::<Runtime>::new();
+		for address in FirePrecompiles::<Runtime>::used_addresses().map(Into::into) {
+			if matches!(
+				precompiles.is_active_precompile(address, u64::MAX),
+				IsPrecompileResult::Answer { is_precompile: true, .. }
+			) {
+				ensure!(
+					pallet_evm::AccountCodes::<Runtime>::contains_key(address),
+					"active precompile has no code"
+				);
+			}
 		}
+		for address in asset_precompile_addresses() {
+			ensure!(
+				pallet_evm::AccountCodes::<Runtime>::contains_key(address),
+				"asset precompile has no code"
+			);
+		}
+
+		Ok(())
 	}
 }
 
-fn hash(a: u64) -> H160 {
-	H160::from_low_u64_be(a)
+/// Writes [`DUMMY_CODE`] at the address of the ERC-20 precompile of each asset created in the
+/// `Instance` of `pallet_assets`, whose precompiles live under `Prefix`.
+pub struct AssetPrecompileDummyCode<Prefix, Instance>(PhantomData<(Prefix, Instance)>);
+
+impl<Prefix, Instance> AssetsCallback<u32, AccountId> for AssetPrecompileDummyCode<Prefix, Instance>
+where
+	Prefix: Get<&'static [u8]>,
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance, AssetId = u32>,
+{
+	fn created(id: &u32, _owner: &AccountId) -> Result<(), ()> {
+		write_dummy_code(
+			Erc20AssetsPrecompileSet::<Runtime, Prefix, Instance>::asset_id_to_address(*id),
+		);
+		Ok(())
+	}
 }
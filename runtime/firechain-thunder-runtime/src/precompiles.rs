@@ -1,12 +1,57 @@
+use frame_support::parameter_types;
+use pallet_assets::{Instance1, Instance2};
 use pallet_evm_precompile_batch::BatchPrecompile;
+use pallet_evm_precompile_blake2::Blake2F;
+use pallet_evm_precompile_bls12377::{
+	Bls12377G1Add, Bls12377G1Mul, Bls12377G1MultiExp, Bls12377G2Add, Bls12377G2Mul,
+	Bls12377G2MultiExp, Bls12377Pairing,
+};
+use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+use pallet_evm_precompile_bw6761::{
+	Bw6761G1Add, Bw6761G1Mul, Bw6761G1MultiExp, Bw6761G2Add, Bw6761G2Mul, Bw6761G2MultiExp,
+	Bw6761Pairing,
+};
+use pallet_evm_precompile_collective::CollectivePrecompile;
+use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
+use pallet_evm_precompile_curve25519::{Curve25519Add, Curve25519ScalarMul};
+use pallet_evm_precompile_democracy::DemocracyPrecompile;
+use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_erc20::{
+	Erc20AssetsPrecompileSet, Erc20BalancesPrecompile, Erc20Metadata,
+};
+use pallet_evm_precompile_esg::EsgPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_registry::PrecompileRegistry;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_staking::StakingPrecompile;
 use precompile_utils::precompile_set::*;
 
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
 
+pub struct NativeErc20Metadata;
+
+impl Erc20Metadata for NativeErc20Metadata {
+	fn name() -> &'static str {
+		"5ire"
+	}
+
+	fn symbol() -> &'static str {
+		"5IRE"
+	}
+
+	fn decimals() -> u8 {
+		18
+	}
+}
+
+parameter_types! {
+	/// Address prefix of the ERC-20 precompiles of `Assets`.
+	pub AssetsPrefix: &'static [u8] = &[255u8; 4];
+	/// Address prefix of the ERC-20 precompiles of `PoolAssets`.
+	pub PoolAssetsPrefix: &'static [u8] = &[255u8, 255u8, 255u8, 254u8];
+}
+
 #[precompile_utils::precompile_name_from_address]
 type FirePrecompilesAt<R> = (
 	// Ethereum precompiles:
@@ -16,8 +61,30 @@ type FirePrecompilesAt<R> = (
 	PrecompileAt<AddressU64<3>, Ripemd160, EthereumPrecompilesChecks>,
 	PrecompileAt<AddressU64<4>, Identity, EthereumPrecompilesChecks>,
 	PrecompileAt<AddressU64<5>, Modexp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<6>, Bn128Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<7>, Bn128Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<8>, Bn128Pairing, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<9>, Blake2F, EthereumPrecompilesChecks>,
+	// Non-Ethereum cryptographic precompiles:
 	PrecompileAt<AddressU64<1024>, Sha3FIPS256, EthereumPrecompilesChecks>,
 	PrecompileAt<AddressU64<1025>, ECRecoverPublicKey, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1026>, Ed25519Verify, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1027>, Curve25519Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1028>, Curve25519ScalarMul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1029>, Bls12377G1Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1030>, Bls12377G1Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1031>, Bls12377G1MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1032>, Bls12377G2Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1033>, Bls12377G2Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1034>, Bls12377G2MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1035>, Bls12377Pairing, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1036>, Bw6761G1Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1037>, Bw6761G1Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1038>, Bw6761G1MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1039>, Bw6761G2Add, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1040>, Bw6761G2Mul, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1041>, Bw6761G2MultiExp, EthereumPrecompilesChecks>,
+	PrecompileAt<AddressU64<1042>, Bw6761Pairing, EthereumPrecompilesChecks>,
 	PrecompileAt<
 		AddressU64<4096>,
 		BatchPrecompile<R>,
@@ -32,6 +99,37 @@ type FirePrecompilesAt<R> = (
 		PrecompileRegistry<R>,
 		(CallableByContract, CallableByPrecompile),
 	>,
+	PrecompileAt<AddressU64<4098>, EsgPrecompile<R>, (CallableByContract, CallableByPrecompile)>,
+	PrecompileAt<
+		AddressU64<4099>,
+		StakingPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4100>,
+		Erc20BalancesPrecompile<R, NativeErc20Metadata>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4101>,
+		CollectivePrecompile<R, crate::CouncilCollective>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4102>,
+		CollectivePrecompile<R, crate::TechnicalCollective>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4103>,
+		ConvictionVotingPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
+	PrecompileAt<
+		AddressU64<4104>,
+		DemocracyPrecompile<R>,
+		(CallableByContract, CallableByPrecompile),
+	>,
 );
 
 pub type FirePrecompiles<R> = PrecompileSetBuilder<
@@ -39,5 +137,15 @@ pub type FirePrecompiles<R> = PrecompileSetBuilder<
 	(
 		// Skip precompiles if out of range.
 		PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<4200>), FirePrecompilesAt<R>>,
+		PrecompileSetStartingWith<
+			AssetsPrefix,
+			Erc20AssetsPrecompileSet<R, AssetsPrefix, Instance1>,
+			(CallableByContract, CallableByPrecompile),
+		>,
+		PrecompileSetStartingWith<
+			PoolAssetsPrefix,
+			Erc20AssetsPrecompileSet<R, PoolAssetsPrefix, Instance2>,
+			(CallableByContract, CallableByPrecompile),
+		>,
 	),
 >;